@@ -0,0 +1,37 @@
+/* C API of the basu eventbus. */
+#ifndef BASU_H
+#define BASU_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define BASU_OK 0
+#define BASU_ERR_INVALID_ARGUMENT -1
+#define BASU_ERR_EVENT_TYPE_NOT_FOUND -2
+#define BASU_ERR_HANDLER_NOT_FOUND -3
+#define BASU_ERR_HANDLER -4
+#define BASU_ERR_INTERNAL -5
+
+typedef struct BasuBus BasuBus;
+
+/* Handler callback receiving the payload of an event, returning 0 on success. */
+typedef int32_t (*basu_callback)(const uint8_t *data, size_t len, void *user_data);
+
+BasuBus *basu_bus_new(void);
+void basu_bus_free(BasuBus *bus);
+
+int32_t basu_subscribe(const BasuBus *bus, const char *event_type, basu_callback callback,
+                       void *user_data, uint64_t *out_handler_id);
+int32_t basu_unsubscribe(const BasuBus *bus, uint64_t handler_id);
+int32_t basu_publish(const BasuBus *bus, const char *event_type, const uint8_t *data,
+                     size_t len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* BASU_H */
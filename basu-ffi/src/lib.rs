@@ -0,0 +1,231 @@
+//! # basu-ffi
+//!
+//! C API of the basu eventbus, so non-Rust host applications (C, C++, Python through cffi)
+//! can embed the bus. Events are opaque byte payloads and handlers are C callbacks with a
+//! user data pointer. The declarations are in `include/basu.h`.
+//!
+//! Every function returns `BASU_OK` or a negative `BASU_ERR_*` code.
+#![deny(missing_docs)]
+
+#[cfg(test)]
+mod tests;
+
+use basu::{
+    async_trait,
+    error::{BasuError, PublishError},
+    event::Event,
+    EventBus, Handle, HandlerId,
+};
+
+use std::{
+    collections::HashMap,
+    ffi::{c_char, c_void, CStr},
+    slice,
+    sync::Mutex,
+};
+use tokio::runtime::{Builder, Runtime};
+
+/// The call succeeded.
+pub const BASU_OK: i32 = 0;
+/// A pointer argument is null or a string isn't valid UTF-8.
+pub const BASU_ERR_INVALID_ARGUMENT: i32 = -1;
+/// The event type has no subscribed handler.
+pub const BASU_ERR_EVENT_TYPE_NOT_FOUND: i32 = -2;
+/// The handler id is unknown.
+pub const BASU_ERR_HANDLER_NOT_FOUND: i32 = -3;
+/// A handler callback returned a non-zero status.
+pub const BASU_ERR_HANDLER: i32 = -4;
+/// Any other bus error.
+pub const BASU_ERR_INTERNAL: i32 = -5;
+
+/// Handler callback receiving the payload of an event, returning `0` on success.
+pub type BasuCallback = extern "C" fn(data: *const u8, len: usize, user_data: *mut c_void) -> i32;
+
+/// Event bus handle created by `basu_bus_new`.
+pub struct BasuBus {
+    runtime: Runtime,
+    event_bus: EventBus<Vec<u8>>,
+    handlers: Mutex<HashMap<u64, (String, HandlerId)>>,
+    next_id: Mutex<u64>,
+}
+
+/// User data pointer handed back to a callback.
+struct UserData(*mut c_void);
+
+// The caller of `basu_subscribe` guarantees the callback may be invoked with its user data
+// from any thread.
+unsafe impl Send for UserData {}
+unsafe impl Sync for UserData {}
+
+/// Handler calling a C callback.
+struct Callback {
+    callback: BasuCallback,
+    user_data: UserData,
+}
+
+#[async_trait]
+impl Handle<Vec<u8>> for Callback {
+    async fn handle(&self, event: &Event<Vec<u8>>) -> Result<(), BasuError> {
+        let data = event.get_data();
+        match (self.callback)(data.as_ptr(), data.len(), self.user_data.0) {
+            0 => Ok(()),
+            status => Err(anyhow::anyhow!("callback returned {}", status).into()),
+        }
+    }
+}
+
+fn status(result: Result<(), BasuError>) -> i32 {
+    match result {
+        Ok(()) => BASU_OK,
+        Err(BasuError::EventTypeNotFOUND) => BASU_ERR_EVENT_TYPE_NOT_FOUND,
+        Err(BasuError::Publish(err)) => publish_status(Err(err)),
+        Err(_) => BASU_ERR_INTERNAL,
+    }
+}
+
+fn publish_status(result: Result<(), PublishError>) -> i32 {
+    match result {
+        Ok(()) => BASU_OK,
+        Err(PublishError::EventTypeNotFound | PublishError::NoSubscribers) => {
+            BASU_ERR_EVENT_TYPE_NOT_FOUND
+        }
+        Err(PublishError::PartialFailure { .. } | PublishError::Timeout { .. }) => BASU_ERR_HANDLER,
+        Err(_) => BASU_ERR_INTERNAL,
+    }
+}
+
+/// read a C string argument, `None` when it is null or not UTF-8.
+unsafe fn str_arg<'a>(s: *const c_char) -> Option<&'a str> {
+    if s.is_null() {
+        return None;
+    }
+    CStr::from_ptr(s).to_str().ok()
+}
+
+/// Create a new event bus, `NULL` when its runtime can't be started.
+/// Free it with `basu_bus_free`.
+#[no_mangle]
+pub extern "C" fn basu_bus_new() -> *mut BasuBus {
+    let Ok(runtime) = Builder::new_current_thread().enable_time().build() else {
+        return std::ptr::null_mut();
+    };
+
+    Box::into_raw(Box::new(BasuBus {
+        runtime,
+        event_bus: EventBus::new(),
+        handlers: Default::default(),
+        next_id: Mutex::new(0),
+    }))
+}
+
+/// Free an event bus created by `basu_bus_new`, `NULL` is ignored.
+///
+/// # Safety
+///
+/// `bus` must be `NULL` or a bus returned by `basu_bus_new` which isn't used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn basu_bus_free(bus: *mut BasuBus) {
+    if !bus.is_null() {
+        drop(Box::from_raw(bus));
+    }
+}
+
+/// Subscribe `callback` to an event type, writing the id of the subscription to
+/// `out_handler_id`. The callback is invoked with `user_data` on every publish, from the
+/// publishing thread.
+///
+/// # Safety
+///
+/// `bus` must be a live bus, `event_type` a NUL-terminated string and `out_handler_id` a
+/// writable pointer. `callback` must be safe to call with `user_data` from any thread until
+/// it is unsubscribed or the bus is freed, and must not publish on the same bus.
+#[no_mangle]
+pub unsafe extern "C" fn basu_subscribe(
+    bus: *const BasuBus,
+    event_type: *const c_char,
+    callback: BasuCallback,
+    user_data: *mut c_void,
+    out_handler_id: *mut u64,
+) -> i32 {
+    let (Some(bus), Some(event_type)) = (bus.as_ref(), str_arg(event_type)) else {
+        return BASU_ERR_INVALID_ARGUMENT;
+    };
+    if out_handler_id.is_null() {
+        return BASU_ERR_INVALID_ARGUMENT;
+    }
+
+    let handler = Callback {
+        callback,
+        user_data: UserData(user_data),
+    };
+    let handler_id = bus
+        .runtime
+        .block_on(bus.event_bus.subscribe(event_type, Box::new(handler)));
+
+    let Ok(mut next_id) = bus.next_id.lock() else {
+        return BASU_ERR_INTERNAL;
+    };
+    let Ok(mut handlers) = bus.handlers.lock() else {
+        return BASU_ERR_INTERNAL;
+    };
+    *next_id += 1;
+    handlers.insert(*next_id, (event_type.to_owned(), handler_id));
+    *out_handler_id = *next_id;
+
+    BASU_OK
+}
+
+/// Unsubscribe the handler with the id written by `basu_subscribe`, waiting for its
+/// in-flight invocations to finish.
+///
+/// # Safety
+///
+/// `bus` must be a live bus.
+#[no_mangle]
+pub unsafe extern "C" fn basu_unsubscribe(bus: *const BasuBus, handler_id: u64) -> i32 {
+    let Some(bus) = bus.as_ref() else {
+        return BASU_ERR_INVALID_ARGUMENT;
+    };
+
+    let subscription = match bus.handlers.lock() {
+        Ok(mut handlers) => handlers.remove(&handler_id),
+        Err(_) => return BASU_ERR_INTERNAL,
+    };
+    let Some((event_type, handler_id)) = subscription else {
+        return BASU_ERR_HANDLER_NOT_FOUND;
+    };
+
+    status(
+        bus.runtime
+            .block_on(bus.event_bus.unsubscribe(&event_type, &handler_id)),
+    )
+}
+
+/// Publish `len` bytes at `data` to the handlers of an event type, returning once every
+/// handler ran. `data` may be `NULL` when `len` is `0`.
+///
+/// # Safety
+///
+/// `bus` must be a live bus, `event_type` a NUL-terminated string and `data` readable for
+/// `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn basu_publish(
+    bus: *const BasuBus,
+    event_type: *const c_char,
+    data: *const u8,
+    len: usize,
+) -> i32 {
+    let (Some(bus), Some(event_type)) = (bus.as_ref(), str_arg(event_type)) else {
+        return BASU_ERR_INVALID_ARGUMENT;
+    };
+    let payload = match (data.is_null(), len) {
+        (_, 0) => Vec::new(),
+        (true, _) => return BASU_ERR_INVALID_ARGUMENT,
+        (false, len) => slice::from_raw_parts(data, len).to_vec(),
+    };
+
+    publish_status(
+        bus.runtime
+            .block_on(bus.event_bus.publish(event_type, &Event::new(payload))),
+    )
+}
@@ -0,0 +1,76 @@
+use crate::*;
+
+use std::{
+    ffi::{c_void, CString},
+    ptr,
+    sync::Mutex,
+};
+
+extern "C" fn record(data: *const u8, len: usize, user_data: *mut c_void) -> i32 {
+    let received = unsafe { &*(user_data as *const Mutex<Vec<Vec<u8>>>) };
+    let payload = unsafe { slice::from_raw_parts(data, len) };
+    received.lock().unwrap().push(payload.to_vec());
+    0
+}
+
+extern "C" fn fail(_data: *const u8, _len: usize, _user_data: *mut c_void) -> i32 {
+    7
+}
+
+#[test]
+fn subscribe_publish_unsubscribe() {
+    let received: Mutex<Vec<Vec<u8>>> = Mutex::new(Vec::new());
+    let event_type = CString::new("echo").unwrap();
+
+    unsafe {
+        let bus = basu_bus_new();
+        assert!(!bus.is_null());
+
+        let mut handler_id = 0;
+        let user_data = &received as *const _ as *mut c_void;
+        assert_eq!(
+            basu_subscribe(bus, event_type.as_ptr(), record, user_data, &mut handler_id),
+            BASU_OK
+        );
+
+        let payload = b"hello";
+        assert_eq!(
+            basu_publish(bus, event_type.as_ptr(), payload.as_ptr(), payload.len()),
+            BASU_OK
+        );
+        assert_eq!(*received.lock().unwrap(), vec![payload.to_vec()]);
+
+        assert_eq!(basu_unsubscribe(bus, handler_id), BASU_OK);
+        assert_eq!(
+            basu_unsubscribe(bus, handler_id),
+            BASU_ERR_HANDLER_NOT_FOUND
+        );
+
+        let missing = CString::new("missing").unwrap();
+        assert_eq!(
+            basu_publish(bus, missing.as_ptr(), ptr::null(), 0),
+            BASU_ERR_EVENT_TYPE_NOT_FOUND
+        );
+        assert_eq!(
+            basu_publish(bus, ptr::null(), ptr::null(), 0),
+            BASU_ERR_INVALID_ARGUMENT
+        );
+
+        assert_eq!(
+            basu_subscribe(
+                bus,
+                event_type.as_ptr(),
+                fail,
+                ptr::null_mut(),
+                &mut handler_id
+            ),
+            BASU_OK
+        );
+        assert_eq!(
+            basu_publish(bus, event_type.as_ptr(), ptr::null(), 0),
+            BASU_ERR_HANDLER
+        );
+
+        basu_bus_free(bus);
+    }
+}
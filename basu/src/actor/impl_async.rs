@@ -0,0 +1,166 @@
+use super::{SupervisorEvent, SupervisorPolicy};
+use crate::{async_trait, error::BasuError, event::Event, EventBus, Handle, Handler, HandlerId};
+
+use futures::FutureExt;
+use std::panic::AssertUnwindSafe;
+use tokio::{sync::mpsc, task::JoinHandle};
+
+/// Implement for actor which owns its state and processes events one at a time
+#[async_trait]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub trait Actor<T>: Send + 'static {
+    /// Handle event which is delivered to the actor's mailbox
+    async fn handle(&mut self, event: Event<T>) -> Result<(), BasuError>;
+}
+
+/// Reference to an actor running on its own task.
+/// Events reach the actor through mailbox handlers subscribed on an `EventBus`.
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub struct ActorRef<T> {
+    sender: mpsc::UnboundedSender<Event<T>>,
+    task: JoinHandle<Result<(), BasuError>>,
+}
+
+/// Handler which forwards events into an actor's mailbox.
+struct Mailbox<T> {
+    sender: mpsc::UnboundedSender<Event<T>>,
+}
+
+#[async_trait]
+impl<T: Clone + Send + Sync> Handle<T> for Mailbox<T> {
+    async fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        self.sender
+            .send(event.clone())
+            .map_err(|_| BasuError::MailboxClosed)
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> ActorRef<T> {
+    /// Spawn an actor on its own task with a private mailbox.
+    /// The actor stops when its `handle` returns an error or every mailbox is dropped.
+    ///
+    /// ```no_run
+    /// struct Counter {
+    ///     count: usize,
+    /// }
+    ///
+    /// #[async_trait]
+    /// impl Actor<MyEventData> for Counter {
+    ///     async fn handle(&mut self, event: Event<MyEventData>) -> Result<(), BasuError> {
+    ///         self.count += 1;
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let actor_ref = ActorRef::spawn(Counter { count: 0 });
+    /// let handler_id = event_bus.subscribe("my_event", actor_ref.mailbox()).await;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub fn spawn<A: Actor<T>>(mut actor: A) -> Self {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let task = tokio::spawn(async move {
+            while let Some(event) = receiver.recv().await {
+                actor.handle(event).await?;
+            }
+
+            Ok(())
+        });
+
+        Self { sender, task }
+    }
+
+    /// Spawn a supervised actor on its own task with a private mailbox.
+    /// When the actor panics, a fresh one is built from `factory` after the policy's backoff;
+    /// once the restart limit is exceeded the actor stops with `BasuError::ActorEscalated`.
+    /// The event being handled during a panic is dropped.
+    ///
+    /// ```no_run
+    /// let policy = SupervisorPolicy::new(3)
+    ///     .with_backoff(Duration::from_millis(100), Duration::from_secs(5))
+    ///     .on_event(|event| println!("supervisor: {:?}", event));
+    /// let actor_ref = ActorRef::spawn_supervised(|| Counter { count: 0 }, policy);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub fn spawn_supervised<A, F>(factory: F, policy: SupervisorPolicy) -> Self
+    where
+        A: Actor<T>,
+        F: Fn() -> A + Send + 'static,
+    {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let task = tokio::spawn(async move {
+            let mut actor = factory();
+            let mut restarts = 0;
+            while let Some(event) = receiver.recv().await {
+                match AssertUnwindSafe(actor.handle(event)).catch_unwind().await {
+                    Ok(result) => result?,
+                    Err(_) => {
+                        restarts += 1;
+                        if policy.should_escalate(restarts) {
+                            policy.emit(SupervisorEvent::Escalated { restarts });
+                            return Err(BasuError::ActorEscalated { restarts });
+                        }
+
+                        tokio::time::sleep(policy.backoff(restarts)).await;
+                        actor = factory();
+                        policy.emit(SupervisorEvent::Restarted { restarts });
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        Self { sender, task }
+    }
+
+    /// Create a handler which feeds the actor's mailbox, ready to be subscribed.
+    /// Unsubscribing the mailbox stops new events, events already in the mailbox are still
+    /// handled; use `join` to wait for them.
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub fn mailbox(&self) -> Handler<T> {
+        Box::new(Mailbox {
+            sender: self.sender.clone(),
+        })
+    }
+
+    /// Wait for the actor to stop and return the error it stopped with, if any.
+    ///
+    /// **Note:** The actor only stops once its mailboxes are unsubscribed from every `EventBus`.
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn join(self) -> Result<(), BasuError> {
+        drop(self.sender);
+        self.task.await.map_err(anyhow::Error::from)?
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> EventBus<T> {
+    /// Spawn an actor and subscribe its mailbox to each of the given event types.
+    /// It returns the actor with the handler ids of its mailboxes, in the order of the event
+    /// types, unsubscribe them before `join`-ing the actor.
+    ///
+    /// ```no_run
+    /// let event_types = ["order_created", "order_paid"];
+    /// let (actor_ref, handler_ids) = event_bus
+    ///     .spawn_actor(&event_types, OrderActor::default())
+    ///     .await;
+    ///
+    /// for (event_type, handler_id) in event_types.iter().zip(&handler_ids) {
+    ///     event_bus.unsubscribe(event_type, handler_id).await?;
+    /// }
+    /// actor_ref.join().await?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn spawn_actor<A: Actor<T>>(
+        &self,
+        event_types: &[&str],
+        actor: A,
+    ) -> (ActorRef<T>, Vec<HandlerId>) {
+        let actor_ref = ActorRef::spawn(actor);
+        let mut handler_ids = Vec::with_capacity(event_types.len());
+        for event_type in event_types {
+            handler_ids.push(self.subscribe(event_type, actor_ref.mailbox()).await);
+        }
+
+        (actor_ref, handler_ids)
+    }
+}
@@ -0,0 +1,176 @@
+use super::{SupervisorEvent, SupervisorPolicy};
+use crate::{error::BasuError, event::Event, EventBus, Handle, Handler, HandlerId};
+
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::mpsc,
+    thread::{self, JoinHandle},
+};
+
+/// Implement for actor which owns its state and processes events one at a time
+#[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+pub trait Actor<T>: Send + 'static {
+    /// Handle event which is delivered to the actor's mailbox
+    fn handle(&mut self, event: Event<T>) -> Result<(), BasuError>;
+}
+
+/// Reference to an actor running on its own thread.
+/// Events reach the actor through mailbox handlers subscribed on an `EventBus`.
+#[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+pub struct ActorRef<T> {
+    sender: mpsc::Sender<Event<T>>,
+    thread: JoinHandle<Result<(), BasuError>>,
+}
+
+/// Handler which forwards events into an actor's mailbox.
+struct Mailbox<T> {
+    sender: mpsc::Sender<Event<T>>,
+}
+
+impl<T: Clone + Send + Sync> Handle<T> for Mailbox<T> {
+    fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        self.sender
+            .send(event.clone())
+            .map_err(|_| BasuError::MailboxClosed)
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> ActorRef<T> {
+    /// Spawn an actor on its own thread with a private mailbox.
+    /// The actor stops when its `handle` returns an error or every mailbox is dropped.
+    ///
+    /// ```no_run
+    /// struct Counter {
+    ///     count: usize,
+    /// }
+    ///
+    /// impl Actor<MyEventData> for Counter {
+    ///     fn handle(&mut self, event: Event<MyEventData>) -> Result<(), BasuError> {
+    ///         self.count += 1;
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let actor_ref = ActorRef::spawn(Counter { count: 0 });
+    /// let handler_id = event_bus.subscribe("my_event", actor_ref.mailbox())?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn spawn<A: Actor<T>>(mut actor: A) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let thread = thread::spawn(move || {
+            for event in receiver {
+                actor.handle(event)?;
+            }
+
+            Ok(())
+        });
+
+        Self { sender, thread }
+    }
+
+    /// Spawn a supervised actor on its own thread with a private mailbox.
+    /// When the actor panics, a fresh one is built from `factory` after the policy's backoff;
+    /// once the restart limit is exceeded the actor stops with `BasuError::ActorEscalated`.
+    /// The event being handled during a panic is dropped.
+    ///
+    /// ```no_run
+    /// let policy = SupervisorPolicy::new(3)
+    ///     .with_backoff(Duration::from_millis(100), Duration::from_secs(5))
+    ///     .on_event(|event| println!("supervisor: {:?}", event));
+    /// let actor_ref = ActorRef::spawn_supervised(|| Counter { count: 0 }, policy);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn spawn_supervised<A, F>(factory: F, policy: SupervisorPolicy) -> Self
+    where
+        A: Actor<T>,
+        F: Fn() -> A + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel();
+        let thread = thread::spawn(move || {
+            let mut actor = factory();
+            let mut restarts = 0;
+            for event in receiver {
+                match panic::catch_unwind(AssertUnwindSafe(|| actor.handle(event))) {
+                    Ok(result) => result?,
+                    Err(_) => {
+                        restarts += 1;
+                        if policy.should_escalate(restarts) {
+                            policy.emit(SupervisorEvent::Escalated { restarts });
+                            return Err(BasuError::ActorEscalated { restarts });
+                        }
+
+                        thread::sleep(policy.backoff(restarts));
+                        actor = factory();
+                        policy.emit(SupervisorEvent::Restarted { restarts });
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        Self { sender, thread }
+    }
+
+    /// Create a handler which feeds the actor's mailbox, ready to be subscribed.
+    /// Unsubscribing the mailbox stops new events, events already in the mailbox are still
+    /// handled; use `join` to wait for them.
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn mailbox(&self) -> Handler<T> {
+        Box::new(Mailbox {
+            sender: self.sender.clone(),
+        })
+    }
+
+    /// Wait for the actor to stop and return the error it stopped with, if any.
+    ///
+    /// **Note:** The actor only stops once its mailboxes are unsubscribed from every `EventBus`.
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn join(self) -> Result<(), BasuError> {
+        drop(self.sender);
+        self.thread
+            .join()
+            .map_err(|_| anyhow::anyhow!("actor thread panicked"))?
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> EventBus<T> {
+    /// Spawn an actor and subscribe its mailbox to each of the given event types.
+    /// It returns the actor with the handler ids of its mailboxes, in the order of the event
+    /// types, unsubscribe them before `join`-ing the actor.
+    /// When a mailbox can't be subscribed, the mailboxes already subscribed are unsubscribed
+    /// and the actor is stopped.
+    ///
+    /// ```no_run
+    /// let event_types = ["order_created", "order_paid"];
+    /// let (actor_ref, handler_ids) = event_bus.spawn_actor(&event_types, OrderActor::default())?;
+    ///
+    /// for (event_type, handler_id) in event_types.iter().zip(&handler_ids) {
+    ///     event_bus.unsubscribe(event_type, handler_id)?;
+    /// }
+    /// actor_ref.join()?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn spawn_actor<A: Actor<T>>(
+        &self,
+        event_types: &[&str],
+        actor: A,
+    ) -> Result<(ActorRef<T>, Vec<HandlerId>), BasuError> {
+        let actor_ref = ActorRef::spawn(actor);
+        let mut handler_ids = Vec::with_capacity(event_types.len());
+        for event_type in event_types {
+            match self.subscribe(event_type, actor_ref.mailbox()) {
+                Ok(handler_id) => handler_ids.push(handler_id),
+                Err(err) => {
+                    for (event_type, handler_id) in event_types.iter().zip(&handler_ids) {
+                        let _ = self.unsubscribe(event_type, handler_id);
+                    }
+                    let _ = actor_ref.join();
+                    return Err(err);
+                }
+            }
+        }
+
+        Ok((actor_ref, handler_ids))
+    }
+}
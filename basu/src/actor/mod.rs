@@ -0,0 +1,11 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+mod supervisor;
+
+#[cfg(feature = "async")]
+pub use impl_async::{Actor, ActorRef};
+#[cfg(feature = "sync")]
+pub use impl_sync::{Actor, ActorRef};
+pub use supervisor::{SupervisorEvent, SupervisorPolicy};
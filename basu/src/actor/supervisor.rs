@@ -0,0 +1,66 @@
+use std::time::Duration;
+
+/// Notification emitted by a supervised actor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SupervisorEvent {
+    /// The actor panicked and was restarted with a fresh state.
+    Restarted {
+        /// number of restarts so far
+        restarts: usize,
+    },
+    /// The actor panicked more often than the policy allows and was stopped.
+    Escalated {
+        /// number of restarts so far
+        restarts: usize,
+    },
+}
+
+/// Restart policy for supervised actors.
+pub struct SupervisorPolicy {
+    max_restarts: usize,
+    backoff: Duration,
+    max_backoff: Duration,
+    on_event: Option<Box<dyn Fn(SupervisorEvent) + Send + Sync>>,
+}
+
+impl SupervisorPolicy {
+    /// create a new `SupervisorPolicy` which restarts a panicked actor up to `max_restarts` times.
+    pub fn new(max_restarts: usize) -> Self {
+        Self {
+            max_restarts,
+            backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(30),
+            on_event: None,
+        }
+    }
+
+    /// set the delay before the first restart, doubled on each further restart up to `max`.
+    pub fn with_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// set a callback which is notified on every restart and escalation.
+    pub fn on_event(mut self, callback: impl Fn(SupervisorEvent) + Send + Sync + 'static) -> Self {
+        self.on_event = Some(Box::new(callback));
+        self
+    }
+
+    pub(crate) fn should_escalate(&self, restarts: usize) -> bool {
+        restarts > self.max_restarts
+    }
+
+    pub(crate) fn backoff(&self, restarts: usize) -> Duration {
+        let factor = 1u32.checked_shl(restarts.saturating_sub(1) as u32);
+        factor
+            .and_then(|factor| self.backoff.checked_mul(factor))
+            .map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff))
+    }
+
+    pub(crate) fn emit(&self, event: SupervisorEvent) {
+        if let Some(on_event) = &self.on_event {
+            on_event(event);
+        }
+    }
+}
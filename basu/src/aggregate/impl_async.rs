@@ -0,0 +1,118 @@
+use super::{Accepted, Aggregator, AggregatorState, Flush};
+use crate::{async_trait, error::BasuError, event::Event, EventBus, Handle, HandlerId};
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Weak},
+    time::Instant,
+};
+use tokio::sync::mpsc;
+
+/// Handler which feeds one aggregated event type into the shared aggregator.
+struct Aggregate<T, S> {
+    event_type: String,
+    state: Arc<AggregatorState<T, S>>,
+    flush: mpsc::UnboundedSender<Flush<S>>,
+}
+
+#[async_trait]
+impl<T: Send + Sync + 'static, S: Send + Sync + 'static> Handle<T> for Aggregate<T, S> {
+    async fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        match self.state.accept(&self.event_type, event) {
+            Accepted::Completed(summary) => {
+                let _ = self.flush.send(Flush::Emit(summary));
+            }
+            Accepted::Started(key, id) => {
+                if let Some(timeout) = self.state.aggregator.timeout {
+                    let _ = self
+                        .flush
+                        .send(Flush::Expire(key, id, Instant::now() + timeout));
+                }
+            }
+            Accepted::Pending => {}
+        }
+
+        Ok(())
+    }
+}
+
+async fn emit<S: Send + Sync + 'static>(output: &Weak<EventBus<S>>, output_type: &str, summary: S) {
+    if let Some(output) = output.upgrade() {
+        let _ = output.publish(output_type, &Event::new(summary)).await;
+    }
+}
+
+/// Publish the summaries of an aggregate in the order its groups complete or expire,
+/// until every handler of the aggregate is dropped.
+/// Every group has the same timeout, so expiries arrive in deadline order.
+async fn flush<T, S: Send + Sync + 'static>(
+    state: Arc<AggregatorState<T, S>>,
+    output: Weak<EventBus<S>>,
+    output_type: String,
+    mut flushes: mpsc::UnboundedReceiver<Flush<S>>,
+) {
+    let mut expiries: VecDeque<(String, u64, Instant)> = VecDeque::new();
+    loop {
+        let now = Instant::now();
+        while let Some((key, id, _)) = expiries.front().filter(|(_, _, at)| *at <= now) {
+            if let Some(summary) = state.expire(key, *id) {
+                emit(&output, &output_type, summary).await;
+            }
+            expiries.pop_front();
+        }
+
+        let received = match expiries.front() {
+            Some((_, _, at)) => tokio::time::timeout_at((*at).into(), flushes.recv()).await,
+            None => Ok(flushes.recv().await),
+        };
+        match received {
+            Ok(Some(Flush::Emit(summary))) => emit(&output, &output_type, summary).await,
+            Ok(Some(Flush::Expire(key, id, at))) => expiries.push_back((key, id, at)),
+            Ok(None) => break,
+            Err(_) => {}
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> EventBus<T> {
+    /// Subscribe an `Aggregator` to the given event types, completed groups are published
+    /// to `output_type` on the `output` bus.
+    /// Summaries are published in order from one task per aggregate, so `output` may be
+    /// this bus as well, their publish errors are dropped. The task stops once every
+    /// handler of the aggregate is unsubscribed.
+    ///
+    /// ```no_run
+    /// let handler_ids = event_bus
+    ///     .aggregate(&["payment", "shipping"], aggregator, &summary_bus, "order_completed")
+    ///     .await;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn aggregate<S: Send + Sync + 'static>(
+        &self,
+        event_types: &[&str],
+        aggregator: Aggregator<T, S>,
+        output: &Arc<EventBus<S>>,
+        output_type: &str,
+    ) -> Vec<HandlerId> {
+        let state = Arc::new(AggregatorState::new(aggregator, event_types));
+        let (sender, receiver) = mpsc::unbounded_channel();
+        tokio::spawn(flush(
+            state.clone(),
+            Arc::downgrade(output),
+            output_type.to_owned(),
+            receiver,
+        ));
+
+        let mut handler_ids = Vec::with_capacity(event_types.len());
+        for event_type in event_types {
+            let handler = Aggregate {
+                event_type: event_type.to_string(),
+                state: state.clone(),
+                flush: sender.clone(),
+            };
+            handler_ids.push(self.subscribe(event_type, Box::new(handler)).await);
+        }
+
+        handler_ids
+    }
+}
@@ -0,0 +1,123 @@
+use super::{Accepted, Aggregator, AggregatorState, Flush};
+use crate::{error::BasuError, event::Event, EventBus, Handle, HandlerId};
+
+use std::{
+    collections::VecDeque,
+    sync::{
+        mpsc::{self, RecvTimeoutError, Sender},
+        Arc, Weak,
+    },
+    thread,
+    time::Instant,
+};
+
+/// Handler which feeds one aggregated event type into the shared aggregator.
+struct Aggregate<T, S> {
+    event_type: String,
+    state: Arc<AggregatorState<T, S>>,
+    flush: Sender<Flush<S>>,
+}
+
+impl<T: Send + Sync + 'static, S: Send + Sync + 'static> Handle<T> for Aggregate<T, S> {
+    fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        match self.state.accept(&self.event_type, event) {
+            Accepted::Completed(summary) => {
+                let _ = self.flush.send(Flush::Emit(summary));
+            }
+            Accepted::Started(key, id) => {
+                if let Some(timeout) = self.state.aggregator.timeout {
+                    let _ = self
+                        .flush
+                        .send(Flush::Expire(key, id, Instant::now() + timeout));
+                }
+            }
+            Accepted::Pending => {}
+        }
+
+        Ok(())
+    }
+}
+
+/// Publish the summaries of an aggregate in the order its groups complete or expire,
+/// until every handler of the aggregate is dropped.
+/// Every group has the same timeout, so expiries arrive in deadline order.
+fn flush<T, S: Send + Sync + 'static>(
+    state: Arc<AggregatorState<T, S>>,
+    output: Weak<EventBus<S>>,
+    output_type: String,
+    flushes: mpsc::Receiver<Flush<S>>,
+) {
+    let emit = |summary| {
+        if let Some(output) = output.upgrade() {
+            let _ = output.publish(&output_type, &Event::new(summary));
+        }
+    };
+
+    let mut expiries: VecDeque<(String, u64, Instant)> = VecDeque::new();
+    loop {
+        let now = Instant::now();
+        while let Some((key, id, _)) = expiries.front().filter(|(_, _, at)| *at <= now) {
+            if let Some(summary) = state.expire(key, *id) {
+                emit(summary);
+            }
+            expiries.pop_front();
+        }
+
+        let received = match expiries.front() {
+            Some((_, _, at)) => flushes.recv_timeout(at.saturating_duration_since(now)),
+            None => flushes.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match received {
+            Ok(Flush::Emit(summary)) => emit(summary),
+            Ok(Flush::Expire(key, id, at)) => expiries.push_back((key, id, at)),
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> EventBus<T> {
+    /// Subscribe an `Aggregator` to the given event types, completed groups are published
+    /// to `output_type` on the `output` bus.
+    /// Summaries are published in order from one thread per aggregate, so `output` may be
+    /// this bus as well, their publish errors are dropped. The thread stops once every
+    /// handler of the aggregate is unsubscribed.
+    ///
+    /// ```no_run
+    /// let handler_ids = event_bus.aggregate(
+    ///     &["payment", "shipping"],
+    ///     aggregator,
+    ///     &summary_bus,
+    ///     "order_completed",
+    /// )?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn aggregate<S: Send + Sync + 'static>(
+        &self,
+        event_types: &[&str],
+        aggregator: Aggregator<T, S>,
+        output: &Arc<EventBus<S>>,
+        output_type: &str,
+    ) -> Result<Vec<HandlerId>, BasuError> {
+        let state = Arc::new(AggregatorState::new(aggregator, event_types));
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn({
+            let state = state.clone();
+            let output = Arc::downgrade(output);
+            let output_type = output_type.to_owned();
+            move || flush(state, output, output_type, receiver)
+        });
+
+        let mut handler_ids = Vec::with_capacity(event_types.len());
+        for event_type in event_types {
+            let handler = Aggregate {
+                event_type: event_type.to_string(),
+                state: state.clone(),
+                flush: sender.clone(),
+            };
+            handler_ids.push(self.subscribe(event_type, Box::new(handler))?);
+        }
+
+        Ok(handler_ids)
+    }
+}
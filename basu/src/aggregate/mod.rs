@@ -0,0 +1,161 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+
+use crate::event::Event;
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, PoisonError,
+    },
+    time::{Duration, Instant},
+};
+
+/// Condition which completes an aggregation group.
+pub enum Completion<S> {
+    /// an event arrived from every aggregated event type
+    AllTopics,
+    /// the given number of events arrived
+    Count(usize),
+    /// the predicate holds for the accumulated state
+    When(Box<dyn Fn(&S) -> bool + Send + Sync>),
+}
+
+type KeyFn<T> = Box<dyn Fn(&Event<T>) -> String + Send + Sync>;
+type InitFn<S> = Box<dyn Fn() -> S + Send + Sync>;
+type FoldFn<T, S> = Box<dyn Fn(&mut S, &str, &Event<T>) + Send + Sync>;
+
+/// Fan-in utility which accumulates events of several event types into a state per
+/// correlation key and emits the state as a summary event once the group completes.
+///
+/// ```no_run
+/// let aggregator = Aggregator::new(
+///     |event: &Event<OrderPart>| event.data.order_id.to_string(),
+///     OrderSummary::default,
+///     |summary: &mut OrderSummary, event_type: &str, event: &Event<OrderPart>| {
+///         summary.add(event_type, &event.data)
+///     },
+/// )
+/// .complete_when(Completion::AllTopics)
+/// .with_timeout(Duration::from_secs(30));
+/// ```
+pub struct Aggregator<T, S> {
+    key: KeyFn<T>,
+    init: InitFn<S>,
+    fold: FoldFn<T, S>,
+    completion: Completion<S>,
+    timeout: Option<Duration>,
+}
+
+impl<T, S> Aggregator<T, S> {
+    /// create a new `Aggregator` from the correlation `key` of an event, the `init`ial state
+    /// of a group and the `fold` accumulating an event into the state.
+    /// Groups complete once an event arrived from every aggregated event type.
+    pub fn new(
+        key: impl Fn(&Event<T>) -> String + Send + Sync + 'static,
+        init: impl Fn() -> S + Send + Sync + 'static,
+        fold: impl Fn(&mut S, &str, &Event<T>) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            key: Box::new(key),
+            init: Box::new(init),
+            fold: Box::new(fold),
+            completion: Completion::AllTopics,
+            timeout: None,
+        }
+    }
+
+    /// set the condition which completes a group.
+    pub fn complete_when(mut self, completion: Completion<S>) -> Self {
+        self.completion = completion;
+        self
+    }
+
+    /// emit a group's state as it is when the group isn't complete `timeout` after its first event.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+}
+
+struct Group<S> {
+    id: u64,
+    state: S,
+    topics: HashSet<String>,
+    count: usize,
+}
+
+enum Accepted<S> {
+    Completed(S),
+    Started(String, u64),
+    Pending,
+}
+
+/// Work handed from the handlers of an aggregate to its flush thread or task.
+enum Flush<S> {
+    /// publish a completed group
+    Emit(S),
+    /// expire the group `id` of `key` at the given instant unless it completed before
+    Expire(String, u64, Instant),
+}
+
+/// Aggregator shared by the handlers subscribed to each aggregated event type.
+struct AggregatorState<T, S> {
+    aggregator: Aggregator<T, S>,
+    topics: HashSet<String>,
+    groups: Mutex<HashMap<String, Group<S>>>,
+    next_id: AtomicU64,
+}
+
+impl<T, S> AggregatorState<T, S> {
+    fn new(aggregator: Aggregator<T, S>, event_types: &[&str]) -> Self {
+        Self {
+            aggregator,
+            topics: event_types.iter().map(|t| t.to_string()).collect(),
+            groups: Default::default(),
+            next_id: AtomicU64::new(0),
+        }
+    }
+
+    fn accept(&self, event_type: &str, event: &Event<T>) -> Accepted<S> {
+        let key = (self.aggregator.key)(event);
+        let mut groups = self.groups.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let started = !groups.contains_key(&key);
+        let group = groups.entry(key.clone()).or_insert_with(|| Group {
+            id: self.next_id.fetch_add(1, Ordering::Relaxed),
+            state: (self.aggregator.init)(),
+            topics: HashSet::new(),
+            count: 0,
+        });
+        (self.aggregator.fold)(&mut group.state, event_type, event);
+        group.topics.insert(event_type.to_owned());
+        group.count += 1;
+
+        let completed = match &self.aggregator.completion {
+            Completion::AllTopics => self.topics.is_subset(&group.topics),
+            Completion::Count(count) => group.count >= *count,
+            Completion::When(predicate) => predicate(&group.state),
+        };
+
+        if completed {
+            let group = groups.remove(&key).expect("group was just updated");
+            Accepted::Completed(group.state)
+        } else if started {
+            Accepted::Started(key, group.id)
+        } else {
+            Accepted::Pending
+        }
+    }
+
+    fn expire(&self, key: &str, id: u64) -> Option<S> {
+        let mut groups = self.groups.lock().unwrap_or_else(PoisonError::into_inner);
+        match groups.get(key) {
+            Some(group) if group.id == id => groups.remove(key).map(|group| group.state),
+            _ => None,
+        }
+    }
+}
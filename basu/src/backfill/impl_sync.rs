@@ -0,0 +1,38 @@
+use super::{BackfillOptions, BackfillProgress};
+use crate::{error::PublishError, event::Event, EventBus};
+
+use std::{thread, time::Instant};
+
+impl<T: Sync> EventBus<T> {
+    /// Publish historical events through the normal handler pipeline, e.g. to build a new
+    /// projection from a CSV or JSONL dump. Events are published one after another in the
+    /// order of `source`, events of a type nobody subscribed to are skipped.
+    ///
+    /// ```no_run
+    /// let source = read_dump("orders.jsonl")?
+    ///     .map(|record| (record.event_type, Event::new(record.data)));
+    ///
+    /// let progress = event_bus.backfill(source, BackfillOptions::new().with_rate_limit(500))?;
+    /// println!("imported {} events in {:?}", progress.published, progress.elapsed);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn backfill(
+        &self,
+        source: impl IntoIterator<Item = (String, Event<T>)>,
+        mut options: BackfillOptions,
+    ) -> Result<BackfillProgress, PublishError> {
+        let started = Instant::now();
+        let mut progress = BackfillProgress::default();
+        for (index, (event_type, event)) in source.into_iter().enumerate() {
+            if let Some(due) = options.due(started, index) {
+                thread::sleep(due.saturating_duration_since(Instant::now()));
+            }
+
+            let result = self.publish(&event_type, &event);
+            options.record(&mut progress, started, result)?;
+        }
+        progress.elapsed = started.elapsed();
+
+        Ok(progress)
+    }
+}
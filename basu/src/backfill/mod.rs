@@ -0,0 +1,107 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+
+use crate::error::PublishError;
+
+use std::time::{Duration, Instant};
+
+type ProgressFn = Box<dyn FnMut(&BackfillProgress) + Send>;
+
+/// Progress of a backfill, given to the progress callback and returned once it finished.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BackfillProgress {
+    /// number of events handled by every handler
+    pub published: usize,
+    /// number of events without any subscribed handler
+    pub skipped: usize,
+    /// number of events which failed to publish
+    pub failed: usize,
+    /// time since the backfill started
+    pub elapsed: Duration,
+}
+
+impl BackfillProgress {
+    /// number of events taken from the source so far.
+    pub fn total(&self) -> usize {
+        self.published + self.skipped + self.failed
+    }
+}
+
+/// Settings of `EventBus::backfill`.
+///
+/// ```no_run
+/// let options = BackfillOptions::new()
+///     .with_rate_limit(500)
+///     .with_progress(10_000, |progress| println!("imported {}", progress.total()));
+/// ```
+#[derive(Default)]
+pub struct BackfillOptions {
+    rate_limit: Option<u32>,
+    progress_every: usize,
+    on_progress: Option<ProgressFn>,
+    stop_on_error: bool,
+}
+
+impl BackfillOptions {
+    /// create `BackfillOptions` publishing as fast as the handlers allow and counting
+    /// failed events without stopping.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// publish at most `per_second` events.
+    pub fn with_rate_limit(mut self, per_second: u32) -> Self {
+        self.rate_limit = Some(per_second.max(1));
+        self
+    }
+
+    /// call `on_progress` every `every` events taken from the source.
+    pub fn with_progress(
+        mut self,
+        every: usize,
+        on_progress: impl FnMut(&BackfillProgress) + Send + 'static,
+    ) -> Self {
+        self.progress_every = every.max(1);
+        self.on_progress = Some(Box::new(on_progress));
+        self
+    }
+
+    /// stop at the first event which fails to publish and return its error.
+    pub fn with_stop_on_error(mut self) -> Self {
+        self.stop_on_error = true;
+        self
+    }
+
+    /// when the event at `index` may be published, if it has to wait for the rate limit.
+    fn due(&self, started: Instant, index: usize) -> Option<Instant> {
+        self.rate_limit
+            .map(|per_second| started + Duration::from_secs_f64(index as f64 / per_second as f64))
+    }
+
+    /// count the outcome of a publish, returning the error if the backfill has to stop.
+    fn record(
+        &mut self,
+        progress: &mut BackfillProgress,
+        started: Instant,
+        result: Result<(), PublishError>,
+    ) -> Result<(), PublishError> {
+        match result {
+            Ok(()) => progress.published += 1,
+            Err(PublishError::EventTypeNotFound | PublishError::NoSubscribers) => {
+                progress.skipped += 1
+            }
+            Err(error) if self.stop_on_error => return Err(error),
+            Err(_) => progress.failed += 1,
+        }
+        progress.elapsed = started.elapsed();
+
+        if let Some(on_progress) = self.on_progress.as_mut() {
+            if progress.total().is_multiple_of(self.progress_every) {
+                on_progress(progress);
+            }
+        }
+        Ok(())
+    }
+}
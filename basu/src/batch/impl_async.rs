@@ -0,0 +1,77 @@
+use super::BatchConfig;
+use crate::{error::BasuError, event::Event, EventBus};
+
+use std::sync::Arc;
+use tokio::{sync::mpsc, task::JoinHandle, time::Instant};
+
+/// Publisher which coalesces events of one event type into batched dispatches.
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub struct Batcher<T> {
+    sender: mpsc::UnboundedSender<Event<T>>,
+    task: JoinHandle<Result<(), BasuError>>,
+}
+
+impl<T: Send + Sync + 'static> Batcher<T> {
+    /// Buffer an event for the next batched dispatch.
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub fn publish(&self, event: Event<T>) -> Result<(), BasuError> {
+        self.sender
+            .send(event)
+            .map_err(|_| BasuError::MailboxClosed)
+    }
+
+    /// Dispatch the remaining buffered events and stop the batcher.
+    /// It returns the error of the dispatch which stopped the batcher early, if any.
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn close(self) -> Result<(), BasuError> {
+        drop(self.sender);
+        self.task.await.map_err(anyhow::Error::from)?
+    }
+}
+
+impl<T: Send + Sync + 'static> EventBus<T> {
+    /// Create a `Batcher` which coalesces publishes to an event type within a small window
+    /// into one `publish_batch` call, reducing per-event overhead for chatty producers.
+    /// A dispatch error stops the batcher, later publishes then fail with
+    /// `BasuError::MailboxClosed` and `close` returns the error.
+    ///
+    /// ```no_run
+    /// let event_bus = Arc::new(EventBus::<Sample>::new());
+    /// let batcher = event_bus.batcher(
+    ///     "metrics",
+    ///     BatchConfig {
+    ///         max_size: 100,
+    ///         max_delay: Duration::from_millis(5),
+    ///     },
+    /// );
+    ///
+    /// batcher.publish(Event::new(sample))?;
+    /// batcher.close().await?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub fn batcher(self: &Arc<Self>, event_type: &str, config: BatchConfig) -> Batcher<T> {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let event_bus = self.clone();
+        let event_type = event_type.to_owned();
+        let max_size = config.max_size.max(1);
+
+        let task = tokio::spawn(async move {
+            while let Some(event) = receiver.recv().await {
+                let deadline = Instant::now() + config.max_delay;
+                let mut batch = vec![event];
+                while batch.len() < max_size {
+                    match tokio::time::timeout_at(deadline, receiver.recv()).await {
+                        Ok(Some(event)) => batch.push(event),
+                        Ok(None) | Err(_) => break,
+                    }
+                }
+
+                event_bus.publish_batch(&event_type, &batch).await?;
+            }
+
+            Ok(())
+        });
+
+        Batcher { sender, task }
+    }
+}
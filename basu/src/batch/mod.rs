@@ -0,0 +1,29 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+
+#[cfg(feature = "async")]
+pub use impl_async::Batcher;
+#[cfg(feature = "sync")]
+pub use impl_sync::Batcher;
+
+use std::time::Duration;
+
+/// Coalescing window of a `Batcher`.
+#[derive(Debug, Clone, Copy)]
+pub struct BatchConfig {
+    /// dispatch as soon as this many events are buffered
+    pub max_size: usize,
+    /// dispatch at the latest this long after the first buffered event
+    pub max_delay: Duration,
+}
+
+impl Default for BatchConfig {
+    fn default() -> Self {
+        Self {
+            max_size: 64,
+            max_delay: Duration::from_millis(10),
+        }
+    }
+}
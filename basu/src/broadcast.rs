@@ -0,0 +1,82 @@
+use crate::{async_trait, error::BasuError, event::Event, EventBus, Handle, HandlerId};
+
+use std::sync::Arc;
+use tokio::{
+    sync::broadcast::{error::RecvError, Receiver, Sender},
+    task::JoinHandle,
+};
+
+/// Handler sending the events of a topic into a broadcast channel.
+struct Mirror<T> {
+    sender: Sender<Arc<Event<T>>>,
+}
+
+#[async_trait]
+impl<T: Clone + Send + Sync + 'static> Handle<T> for Mirror<T> {
+    async fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        // a channel without receivers drops the event
+        let _ = self.sender.send(Arc::new(event.clone()));
+
+        Ok(())
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> EventBus<T> {
+    /// Mirror the events published to `event_type` onto a broadcast channel, so code already
+    /// using `tokio::sync::broadcast` receives them. It returns the id of the mirroring
+    /// handler, unsubscribe it to stop mirroring.
+    ///
+    /// ```no_run
+    /// let (sender, mut receiver) = broadcast::channel(64);
+    /// event_bus.mirror_to_broadcast("order", sender).await;
+    ///
+    /// let order = receiver.recv().await?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn mirror_to_broadcast(
+        &self,
+        event_type: &str,
+        sender: Sender<Arc<Event<T>>>,
+    ) -> HandlerId {
+        self.subscribe(event_type, Box::new(Mirror { sender }))
+            .await
+    }
+}
+
+impl<T: Send + Sync + 'static> EventBus<T> {
+    /// Publish the messages of a broadcast channel to `event_type` from a spawned task,
+    /// which ends once every sender of the channel or the bus is dropped. Messages missed
+    /// because the task lagged behind are skipped.
+    ///
+    /// ```no_run
+    /// let event_bus = Arc::new(EventBus::<Order>::new());
+    /// let forwarding = event_bus.forward_broadcast("order", sender.subscribe());
+    /// ```
+    ///
+    /// **Note:** Don't mirror the same event type onto the channel, every event would be
+    /// published again forever.
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub fn forward_broadcast(
+        self: &Arc<Self>,
+        event_type: &str,
+        mut receiver: Receiver<Arc<Event<T>>>,
+    ) -> JoinHandle<()> {
+        let event_bus = Arc::downgrade(self);
+        let event_type = event_type.to_owned();
+
+        tokio::spawn(async move {
+            loop {
+                let event = match receiver.recv().await {
+                    Ok(event) => event,
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                };
+                let Some(event_bus) = event_bus.upgrade() else {
+                    break;
+                };
+                // failed handlers are the publisher's concern, the channel has none
+                let _ = event_bus.publish(&event_type, &event).await;
+            }
+        })
+    }
+}
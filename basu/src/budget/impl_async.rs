@@ -0,0 +1,93 @@
+use super::{Budget, SlotId};
+use crate::{async_trait, error::BasuError, event::Event, EventBus, Handle, Handler, HandlerId};
+
+use std::{
+    num::NonZeroU64,
+    sync::{Arc, Weak},
+};
+
+/// Handler which unsubscribes itself once its budget is used up.
+struct Limited<T> {
+    handler: Handler<T>,
+    budget: Budget,
+    event_type: Arc<str>,
+    handler_id: SlotId,
+    event_bus: Weak<EventBus<T>>,
+}
+
+impl<T: Send + Sync + 'static> Limited<T> {
+    /// remove the handler from a spawned task, a publish holds the bus while invoking it.
+    fn detach(event_bus: Weak<EventBus<T>>, event_type: Arc<str>, handler_id: HandlerId) {
+        tokio::spawn(async move {
+            if let Some(event_bus) = event_bus.upgrade() {
+                let _ = event_bus.unsubscribe(&event_type, &handler_id).await;
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync + 'static> Handle<T> for Limited<T> {
+    async fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        let last = match self.budget.take() {
+            Some(last) => last,
+            None => return Ok(()),
+        };
+        let result = self.handler.handle(event).await;
+
+        if let (true, Some(handler_id)) = (last, self.handler_id.get()) {
+            Self::detach(
+                self.event_bus.clone(),
+                self.event_type.clone(),
+                handler_id.clone(),
+            );
+        }
+
+        result
+    }
+}
+
+impl<T: Send + Sync + 'static> EventBus<T> {
+    /// Subscribe a handler which is invoked at most `max_invocations` times and then
+    /// unsubscribes itself, e.g. for trial listeners or bounded sampling. It returns the
+    /// `HandlerId` with a `Budget` telling how many invocations are left.
+    ///
+    /// ```no_run
+    /// let event_bus = Arc::new(EventBus::<MyEventData>::new());
+    /// let (handler_id, budget) = event_bus
+    ///     .subscribe_limited("my_event", NonZeroU64::new(100).unwrap(), Box::new(Sampler))
+    ///     .await;
+    ///
+    /// println!("{} samples left", budget.remaining());
+    /// ```
+    ///
+    /// **Note:** The handler is removed from a spawned task after its last invocation,
+    /// it is skipped by publishes racing with the removal.
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn subscribe_limited(
+        self: &Arc<Self>,
+        event_type: &str,
+        max_invocations: NonZeroU64,
+        handler: Handler<T>,
+    ) -> (HandlerId, Budget) {
+        let budget = Budget::new(max_invocations);
+        let slot = SlotId::default();
+        let event_type: Arc<str> = Arc::from(event_type);
+        let limited = Limited {
+            handler,
+            budget: budget.clone(),
+            event_type: event_type.clone(),
+            handler_id: slot.clone(),
+            event_bus: Arc::downgrade(self),
+        };
+
+        let handler_id = self.subscribe(&event_type, Box::new(limited)).await;
+        let _ = slot.set(handler_id.clone());
+        // the budget may have run out before the id was known to the handler
+        if budget.is_exhausted() {
+            let _ = self.unsubscribe(&event_type, &handler_id).await;
+        }
+
+        (handler_id, budget)
+    }
+}
@@ -0,0 +1,49 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+
+use crate::HandlerId;
+
+use std::{
+    num::NonZeroU64,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, OnceLock,
+    },
+};
+
+/// Invocations left to a handler subscribed with `subscribe_limited`.
+#[derive(Debug, Clone)]
+pub struct Budget {
+    remaining: Arc<AtomicU64>,
+}
+
+impl Budget {
+    fn new(max_invocations: NonZeroU64) -> Self {
+        Self {
+            remaining: Arc::new(AtomicU64::new(max_invocations.get())),
+        }
+    }
+
+    /// number of invocations left before the handler unsubscribes.
+    pub fn remaining(&self) -> u64 {
+        self.remaining.load(Ordering::SeqCst)
+    }
+
+    /// whether the handler has used up its invocations.
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// take one invocation, returning whether it was the last one, or `None` once exhausted.
+    fn take(&self) -> Option<bool> {
+        self.remaining
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .ok()
+            .map(|n| n == 1)
+    }
+}
+
+/// Handler id of a limited subscription, set once `subscribe` returned it.
+type SlotId = Arc<OnceLock<HandlerId>>;
@@ -0,0 +1,19 @@
+use super::Buffered;
+use crate::error::PublishError;
+
+impl<T: Send + Sync> Buffered<'_, T> {
+    /// Publish the buffered events in the order they were buffered.
+    /// Every event is published even if an earlier one fails, the first error is returned.
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn commit(self) -> Result<(), PublishError> {
+        let mut result = Ok(());
+        for (event_type, event) in &self.events {
+            let published = self.event_bus.publish(event_type, event);
+            if result.is_ok() {
+                result = published;
+            }
+        }
+
+        result
+    }
+}
@@ -0,0 +1,62 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+
+use crate::{event::Event, EventBus};
+
+/// Publishes held back until `commit`, returned by `EventBus::buffered`.
+/// Dropping it without committing discards the buffered events.
+#[must_use = "buffered events are discarded unless committed"]
+pub struct Buffered<'a, T> {
+    event_bus: &'a EventBus<T>,
+    events: Vec<(String, Event<T>)>,
+}
+
+impl<'a, T> Buffered<'a, T> {
+    fn new(event_bus: &'a EventBus<T>) -> Self {
+        Self {
+            event_bus,
+            events: Vec::new(),
+        }
+    }
+
+    /// Buffer an event, it isn't dispatched before `commit`.
+    pub fn publish(&mut self, event_type: &str, event: Event<T>) {
+        self.events.push((event_type.to_owned(), event));
+    }
+
+    /// return the number of buffered events.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// whether no event is buffered.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Drop the buffered events without dispatching them, same as dropping the scope.
+    pub fn discard(self) {}
+}
+
+impl<T> EventBus<T> {
+    /// Start a scope which holds publishes in memory until it is committed.
+    /// Code paths which may roll back publish through it, so no event is emitted for work that
+    /// never happened.
+    ///
+    /// ```no_run
+    /// let mut buffered = event_bus.buffered();
+    /// buffered.publish("order.created", Event::new(order));
+    /// if let Err(err) = save(&order) {
+    ///     // the event is discarded
+    ///     return Err(err);
+    /// }
+    /// buffered.commit()?;
+    /// ```
+    ///
+    /// **Note:** `commit` is async with the `async` feature.
+    pub fn buffered(&self) -> Buffered<'_, T> {
+        Buffered::new(self)
+    }
+}
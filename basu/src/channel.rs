@@ -0,0 +1,45 @@
+use crate::{error::BasuError, event::Event, EventBus, Handle, HandlerId};
+
+use std::sync::mpsc::{self, Receiver, Sender};
+
+/// Handler forwarding clones of the events into the `Receiver` of `subscribe_channel`.
+struct Forward<T> {
+    sender: Sender<Event<T>>,
+}
+
+impl<T: Clone + Send> Handle<T> for Forward<T> {
+    fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        // a dropped receiver no longer wants events
+        let _ = self.sender.send(event.clone());
+
+        Ok(())
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> EventBus<T> {
+    /// Subscribe a channel receiving the events published to `event_type`, so a consumer
+    /// can pull them on its own thread instead of running inside `publish`. It returns
+    /// the id of the handler feeding the channel with its `Receiver`, which disconnects
+    /// once the handler is unsubscribed.
+    ///
+    /// ```no_run
+    /// let (handler_id, orders) = event_bus.subscribe_channel("order")?;
+    /// thread::spawn(move || {
+    ///     for order in orders {
+    ///         // ...
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// **Note:** The channel is unbounded, events pile up while the consumer lags behind.
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn subscribe_channel(
+        &self,
+        event_type: &str,
+    ) -> Result<(HandlerId, Receiver<Event<T>>), BasuError> {
+        let (sender, receiver) = mpsc::channel();
+        let handler_id = self.subscribe(event_type, Box::new(Forward { sender }))?;
+
+        Ok((handler_id, receiver))
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{EventBus, HandlerId};
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+/// When the circuit of a handler opens and how long it stays open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitBreaker {
+    /// consecutive failures which open the circuit
+    pub threshold: usize,
+    /// time the handler is skipped once its circuit opened
+    pub cool_down: Duration,
+}
+
+impl CircuitBreaker {
+    /// create a new `CircuitBreaker`
+    pub fn new(threshold: usize, cool_down: Duration) -> Self {
+        Self {
+            threshold: threshold.max(1),
+            cool_down,
+        }
+    }
+}
+
+/// State change of the circuit of a handler.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CircuitEvent {
+    /// The handler failed too often in a row and is skipped until the cool-down elapsed.
+    /// It opens again if the first invocation after the cool-down fails.
+    Opened {
+        /// id of the handler
+        handler_id: HandlerId,
+        /// consecutive failures of the handler
+        failures: usize,
+    },
+    /// The handler succeeded after its cool-down and receives events again.
+    Closed {
+        /// id of the handler
+        handler_id: HandlerId,
+    },
+}
+
+/// Callback notified when the circuit of a handler opens or closes.
+pub type CircuitHook = Arc<dyn Fn(&CircuitEvent) + Send + Sync>;
+
+/// Circuit of a handler which failed since its last success.
+#[derive(Debug, Default)]
+pub(crate) struct Circuit {
+    failures: usize,
+    open_until: Option<Instant>,
+}
+
+/// Circuits of the handlers which failed since their last success.
+pub(crate) type Circuits = Mutex<HashMap<HandlerId, Circuit>>;
+
+impl<T> EventBus<T> {
+    /// Skip handlers which failed `breaker.threshold` times in a row for `breaker.cool_down`,
+    /// so one bad subscriber doesn't degrade every publish. Skipped handlers are reported
+    /// in `DispatchReport::skipped` rather than as delivered. After the cool-down the next
+    /// event is delivered again, the circuit closes if the handler succeeds and opens again
+    /// if it fails.
+    ///
+    /// ```no_run
+    /// let event_bus = EventBus::<MyEventData>::new()
+    ///     .with_circuit_breaker(CircuitBreaker::new(5, Duration::from_secs(30)))
+    ///     .with_circuit_hook(|event| log::warn!("{:?}", event));
+    /// ```
+    pub fn with_circuit_breaker(mut self, breaker: CircuitBreaker) -> Self {
+        self.circuit_breaker = Some(breaker);
+        self
+    }
+
+    /// add a hook which is notified whenever the circuit of a handler opens or closes.
+    ///
+    /// **Note:** Hooks run on the dispatching task or thread while the bus is locked, so they
+    /// must not publish to the same bus.
+    pub fn with_circuit_hook(
+        mut self,
+        hook: impl Fn(&CircuitEvent) + Send + Sync + 'static,
+    ) -> Self {
+        self.circuit_hooks.push(Arc::new(hook));
+        self
+    }
+
+    /// whether the circuit of a handler is open, i.e. the handler is skipped.
+    pub fn is_circuit_open(&self, handler_id: &HandlerId) -> bool {
+        self.circuits
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(handler_id)
+            .and_then(|circuit| circuit.open_until)
+            .is_some_and(|open_until| Instant::now() < open_until)
+    }
+
+    /// watch the invocations of a dispatch, if a circuit breaker is set.
+    pub(crate) fn circuit_watch(&self) -> Option<CircuitWatch<'_>> {
+        Some(CircuitWatch {
+            breaker: self.circuit_breaker?,
+            circuits: &self.circuits,
+            hooks: &self.circuit_hooks,
+        })
+    }
+
+    pub(crate) fn forget_circuit(&self, handler_id: &HandlerId) {
+        self.circuits
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(handler_id);
+    }
+}
+
+/// Opens and closes circuits according to the outcomes of a single dispatch.
+pub(crate) struct CircuitWatch<'a> {
+    breaker: CircuitBreaker,
+    circuits: &'a Circuits,
+    hooks: &'a [CircuitHook],
+}
+
+impl CircuitWatch<'_> {
+    /// whether a handler receives the event being dispatched.
+    pub(crate) fn allows(&self, handler_id: &HandlerId) -> bool {
+        self.circuits
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(handler_id)
+            .and_then(|circuit| circuit.open_until)
+            .is_none_or(|open_until| open_until <= Instant::now())
+    }
+
+    /// record the outcome of a handler invocation, opening or closing its circuit.
+    pub(crate) fn record(&self, handler_id: &HandlerId, succeeded: bool) {
+        let mut circuits = self.circuits.lock().unwrap_or_else(PoisonError::into_inner);
+        let event = if succeeded {
+            circuits
+                .remove(handler_id)
+                .and_then(|circuit| circuit.open_until)
+                .map(|_| CircuitEvent::Closed {
+                    handler_id: handler_id.clone(),
+                })
+        } else {
+            let circuit = circuits.entry(handler_id.clone()).or_default();
+            circuit.failures += 1;
+            (circuit.failures >= self.breaker.threshold).then(|| {
+                circuit.open_until = Some(Instant::now() + self.breaker.cool_down);
+                CircuitEvent::Opened {
+                    handler_id: handler_id.clone(),
+                    failures: circuit.failures,
+                }
+            })
+        };
+        drop(circuits);
+
+        if let Some(event) = event {
+            for hook in self.hooks {
+                hook(&event);
+            }
+        }
+    }
+}
@@ -0,0 +1,331 @@
+use super::{CanaryRouter, CanaryWeight, Predicate, RetryPolicy, ShadowCallback, ShadowOutcome};
+use crate::{async_trait, error::BasuError, event::Event, Handle, Handler};
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+/// Combinators layering behavior onto a boxed handler
+///
+/// ```no_run
+/// let handler: Handler<MyEventData> = Box::new(MyEventHandler);
+/// let handler = handler
+///     .filtered(|event| event.data.important)
+///     .with_timeout(Duration::from_secs(1))
+///     .with_retry(RetryPolicy::new(3, Duration::from_millis(100)));
+///
+/// let handler_id = event_bus.subscribe("my_event", handler).await;
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub trait HandlerExt<T> {
+    /// Retry the handler according to `policy` while it returns an error, waiting on a tokio
+    /// timer between attempts.
+    fn with_retry(self, policy: RetryPolicy) -> Handler<T>;
+
+    /// Fail with `BasuError::HandlerTimeout` when the handler takes longer than `timeout`.
+    fn with_timeout(self, timeout: Duration) -> Handler<T>;
+
+    /// Only pass events matching `predicate` to the handler.
+    fn filtered<P>(self, predicate: P) -> Handler<T>
+    where
+        P: Fn(&Event<T>) -> bool + Send + Sync + 'static;
+
+    /// Pass the event to `other` when the handler returns an error.
+    fn fallback(self, other: Handler<T>) -> Handler<T>;
+
+    /// Pass the event to both the handler and `other`.
+    fn tee(self, other: Handler<T>) -> Handler<T>;
+
+    /// Run `shadow` on the same events as the handler and pass both outcomes to `on_outcome`,
+    /// for de-risking a handler rewrite. Only the handler's result is returned, the shadow's
+    /// is recorded and dropped.
+    ///
+    /// ```no_run
+    /// let handler: Handler<Order> = Box::new(OrderHandlerV1);
+    /// let handler = handler.shadow(Box::new(OrderHandlerV2), |outcome| {
+    ///     if outcome.diverged() {
+    ///         log::warn!("order handler v2 diverged: {:?}", outcome);
+    ///     }
+    /// });
+    /// ```
+    fn shadow<F>(self, shadow: Handler<T>, on_outcome: F) -> Handler<T>
+    where
+        F: Fn(&ShadowOutcome) + Send + Sync + 'static;
+
+    /// Route the share of events set by `weight` to `canary` and the rest to the handler,
+    /// for rolling out a new handler version gradually.
+    ///
+    /// ```no_run
+    /// let weight = CanaryWeight::new(5);
+    /// let handler: Handler<Order> = Box::new(OrderHandlerV1);
+    /// let handler = handler.canary(Box::new(OrderHandlerV2), &weight);
+    ///
+    /// // later on
+    /// weight.set(50);
+    /// ```
+    fn canary(self, canary: Handler<T>, weight: &CanaryWeight) -> Handler<T>;
+
+    /// Like `canary`, but events with the same `key` always take the same route
+    /// for a given weight.
+    fn canary_by_key<K>(self, canary: Handler<T>, weight: &CanaryWeight, key: K) -> Handler<T>
+    where
+        K: Fn(&Event<T>) -> String + Send + Sync + 'static;
+
+    /// Run the handler on a dedicated blocking thread of the runtime and fail with
+    /// `BasuError::HandlerResourceExceeded` when an invocation takes longer than
+    /// `max_wall_time`, for untrusted plugin handlers.
+    /// A running handler can't be killed, so the overrunning invocation is abandoned and the
+    /// handler is disabled, later events fail right away. A panicking handler is reported
+    /// as an error instead of unwinding into the dispatch.
+    ///
+    /// **Note:** The limit is wall-clock time, not CPU time, and memory ceilings aren't
+    /// enforced, Rust has no per-thread CPU or memory accounting. An abandoned invocation
+    /// keeps running on its thread until it returns, even after the handler is unsubscribed
+    /// and `LifecycleEvent::Detached` is emitted.
+    fn sandboxed(self, max_wall_time: Duration) -> Handler<T>
+    where
+        T: Clone + Send;
+}
+
+impl<T: Sync + 'static> HandlerExt<T> for Handler<T> {
+    fn with_retry(self, policy: RetryPolicy) -> Handler<T> {
+        Box::new(Retry {
+            handler: self,
+            policy,
+        })
+    }
+
+    fn with_timeout(self, timeout: Duration) -> Handler<T> {
+        Box::new(Timeout {
+            handler: self,
+            timeout,
+        })
+    }
+
+    fn filtered<P>(self, predicate: P) -> Handler<T>
+    where
+        P: Fn(&Event<T>) -> bool + Send + Sync + 'static,
+    {
+        Box::new(Filtered {
+            handler: self,
+            predicate: Box::new(predicate),
+        })
+    }
+
+    fn fallback(self, other: Handler<T>) -> Handler<T> {
+        Box::new(Fallback {
+            handler: self,
+            other,
+        })
+    }
+
+    fn tee(self, other: Handler<T>) -> Handler<T> {
+        Box::new(Tee {
+            handler: self,
+            other,
+        })
+    }
+
+    fn shadow<F>(self, shadow: Handler<T>, on_outcome: F) -> Handler<T>
+    where
+        F: Fn(&ShadowOutcome) + Send + Sync + 'static,
+    {
+        Box::new(Shadow {
+            handler: self,
+            shadow,
+            on_outcome: Arc::new(on_outcome),
+        })
+    }
+
+    fn canary(self, canary: Handler<T>, weight: &CanaryWeight) -> Handler<T> {
+        Box::new(Canary {
+            handler: self,
+            canary,
+            router: CanaryRouter::new(weight, None),
+        })
+    }
+
+    fn canary_by_key<K>(self, canary: Handler<T>, weight: &CanaryWeight, key: K) -> Handler<T>
+    where
+        K: Fn(&Event<T>) -> String + Send + Sync + 'static,
+    {
+        Box::new(Canary {
+            handler: self,
+            canary,
+            router: CanaryRouter::new(weight, Some(Box::new(key))),
+        })
+    }
+
+    fn sandboxed(self, max_wall_time: Duration) -> Handler<T>
+    where
+        T: Clone + Send,
+    {
+        Box::new(Sandboxed {
+            handler: Arc::from(self),
+            max_wall_time,
+            disabled: Arc::new(AtomicBool::new(false)),
+        })
+    }
+}
+
+struct Retry<T> {
+    handler: Handler<T>,
+    policy: RetryPolicy,
+}
+
+#[async_trait]
+impl<T: Sync> Handle<T> for Retry<T> {
+    async fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        let mut attempt: u32 = 1;
+        loop {
+            match self.handler.handle(event).await {
+                Err(_) if (attempt as usize) < self.policy.max_attempts => {
+                    tokio::time::sleep(self.policy.delay(attempt)).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+struct Timeout<T> {
+    handler: Handler<T>,
+    timeout: Duration,
+}
+
+#[async_trait]
+impl<T: Sync> Handle<T> for Timeout<T> {
+    async fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        tokio::time::timeout(self.timeout, self.handler.handle(event))
+            .await
+            .map_err(|_| BasuError::HandlerTimeout)?
+    }
+}
+
+struct Filtered<T> {
+    handler: Handler<T>,
+    predicate: Predicate<T>,
+}
+
+#[async_trait]
+impl<T: Sync> Handle<T> for Filtered<T> {
+    async fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        if (self.predicate)(event) {
+            self.handler.handle(event).await
+        } else {
+            Ok(())
+        }
+    }
+}
+
+struct Fallback<T> {
+    handler: Handler<T>,
+    other: Handler<T>,
+}
+
+#[async_trait]
+impl<T: Sync> Handle<T> for Fallback<T> {
+    async fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        match self.handler.handle(event).await {
+            Ok(()) => Ok(()),
+            Err(_) => self.other.handle(event).await,
+        }
+    }
+}
+
+struct Tee<T> {
+    handler: Handler<T>,
+    other: Handler<T>,
+}
+
+#[async_trait]
+impl<T: Sync> Handle<T> for Tee<T> {
+    async fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        let (result, other_result) =
+            futures::join!(self.handler.handle(event), self.other.handle(event));
+        result.and(other_result)
+    }
+}
+
+struct Shadow<T> {
+    handler: Handler<T>,
+    shadow: Handler<T>,
+    on_outcome: ShadowCallback,
+}
+
+#[async_trait]
+impl<T: Sync> Handle<T> for Shadow<T> {
+    async fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        let ((result, elapsed), (shadow_result, shadow_elapsed)) =
+            futures::join!(timed(&self.handler, event), timed(&self.shadow, event));
+
+        (self.on_outcome)(&ShadowOutcome::new(
+            &result,
+            elapsed,
+            &shadow_result,
+            shadow_elapsed,
+        ));
+        result
+    }
+}
+
+struct Canary<T> {
+    handler: Handler<T>,
+    canary: Handler<T>,
+    router: CanaryRouter<T>,
+}
+
+#[async_trait]
+impl<T: Sync> Handle<T> for Canary<T> {
+    async fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        if self.router.to_canary(event) {
+            self.canary.handle(event).await
+        } else {
+            self.handler.handle(event).await
+        }
+    }
+}
+
+struct Sandboxed<T> {
+    handler: Arc<dyn Handle<T>>,
+    max_wall_time: Duration,
+    disabled: Arc<AtomicBool>,
+}
+
+#[async_trait]
+impl<T: Clone + Send + Sync + 'static> Handle<T> for Sandboxed<T> {
+    async fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        if self.disabled.load(Ordering::Relaxed) {
+            return Err(BasuError::HandlerResourceExceeded);
+        }
+
+        let handler = self.handler.clone();
+        let event = event.clone();
+        let runtime = tokio::runtime::Handle::current();
+        let invocation =
+            tokio::task::spawn_blocking(move || runtime.block_on(handler.handle(&event)));
+
+        match tokio::time::timeout(self.max_wall_time, invocation).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(err)) => Err(anyhow::anyhow!("sandboxed handler failed: {}", err).into()),
+            Err(_) => {
+                self.disabled.store(true, Ordering::Relaxed);
+                Err(BasuError::HandlerResourceExceeded)
+            }
+        }
+    }
+}
+
+async fn timed<T: Sync>(
+    handler: &Handler<T>,
+    event: &Event<T>,
+) -> (Result<(), BasuError>, Duration) {
+    let started = Instant::now();
+    let result = handler.handle(event).await;
+    (result, started.elapsed())
+}
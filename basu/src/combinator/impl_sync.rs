@@ -0,0 +1,329 @@
+use super::{CanaryRouter, CanaryWeight, Predicate, RetryPolicy, ShadowCallback, ShadowOutcome};
+use crate::{error::BasuError, event::Event, Handle, Handler};
+
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Combinators layering behavior onto a boxed handler
+///
+/// ```no_run
+/// let handler: Handler<MyEventData> = Box::new(MyEventHandler);
+/// let handler = handler
+///     .filtered(|event| event.data.important)
+///     .with_timeout(Duration::from_secs(1))
+///     .with_retry(RetryPolicy::new(3, Duration::from_millis(100)));
+///
+/// let handler_id = event_bus.subscribe("my_event", handler)?;
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+pub trait HandlerExt<T> {
+    /// Retry the handler according to `policy` while it returns an error, blocking the
+    /// dispatch thread during the backoff.
+    fn with_retry(self, policy: RetryPolicy) -> Handler<T>;
+
+    /// Fail with `BasuError::HandlerTimeout` when the handler takes longer than `timeout`.
+    /// A sync handler can't be interrupted, so the overrun is reported once it returns.
+    fn with_timeout(self, timeout: Duration) -> Handler<T>;
+
+    /// Only pass events matching `predicate` to the handler.
+    fn filtered<P>(self, predicate: P) -> Handler<T>
+    where
+        P: Fn(&Event<T>) -> bool + Send + Sync + 'static;
+
+    /// Pass the event to `other` when the handler returns an error.
+    fn fallback(self, other: Handler<T>) -> Handler<T>;
+
+    /// Pass the event to both the handler and `other`.
+    fn tee(self, other: Handler<T>) -> Handler<T>;
+
+    /// Run `shadow` on the same events as the handler and pass both outcomes to `on_outcome`,
+    /// for de-risking a handler rewrite. Only the handler's result is returned, the shadow's
+    /// is recorded and dropped. The shadow runs after the handler on the dispatch thread.
+    ///
+    /// ```no_run
+    /// let handler: Handler<Order> = Box::new(OrderHandlerV1);
+    /// let handler = handler.shadow(Box::new(OrderHandlerV2), |outcome| {
+    ///     if outcome.diverged() {
+    ///         log::warn!("order handler v2 diverged: {:?}", outcome);
+    ///     }
+    /// });
+    /// ```
+    fn shadow<F>(self, shadow: Handler<T>, on_outcome: F) -> Handler<T>
+    where
+        F: Fn(&ShadowOutcome) + Send + Sync + 'static;
+
+    /// Route the share of events set by `weight` to `canary` and the rest to the handler,
+    /// for rolling out a new handler version gradually.
+    ///
+    /// ```no_run
+    /// let weight = CanaryWeight::new(5);
+    /// let handler: Handler<Order> = Box::new(OrderHandlerV1);
+    /// let handler = handler.canary(Box::new(OrderHandlerV2), &weight);
+    ///
+    /// // later on
+    /// weight.set(50);
+    /// ```
+    fn canary(self, canary: Handler<T>, weight: &CanaryWeight) -> Handler<T>;
+
+    /// Like `canary`, but events with the same `key` always take the same route
+    /// for a given weight.
+    fn canary_by_key<K>(self, canary: Handler<T>, weight: &CanaryWeight, key: K) -> Handler<T>
+    where
+        K: Fn(&Event<T>) -> String + Send + Sync + 'static;
+
+    /// Run the handler on a dedicated thread and fail with `BasuError::HandlerResourceExceeded`
+    /// when an invocation takes longer than `max_wall_time`, for untrusted plugin handlers.
+    /// A running handler can't be killed, so the overrunning invocation is abandoned and the
+    /// handler is disabled, later events fail right away. A panicking handler is reported
+    /// as an error instead of unwinding into the dispatch.
+    ///
+    /// **Note:** The limit is wall-clock time, not CPU time, and memory ceilings aren't
+    /// enforced, Rust has no per-thread CPU or memory accounting. An abandoned invocation
+    /// keeps running on its thread until it returns, even after the handler is unsubscribed
+    /// and `LifecycleEvent::Detached` is emitted.
+    fn sandboxed(self, max_wall_time: Duration) -> Handler<T>
+    where
+        T: Clone + Send;
+}
+
+impl<T: 'static> HandlerExt<T> for Handler<T> {
+    fn with_retry(self, policy: RetryPolicy) -> Handler<T> {
+        Box::new(Retry {
+            handler: self,
+            policy,
+        })
+    }
+
+    fn with_timeout(self, timeout: Duration) -> Handler<T> {
+        Box::new(Timeout {
+            handler: self,
+            timeout,
+        })
+    }
+
+    fn filtered<P>(self, predicate: P) -> Handler<T>
+    where
+        P: Fn(&Event<T>) -> bool + Send + Sync + 'static,
+    {
+        Box::new(Filtered {
+            handler: self,
+            predicate: Box::new(predicate),
+        })
+    }
+
+    fn fallback(self, other: Handler<T>) -> Handler<T> {
+        Box::new(Fallback {
+            handler: self,
+            other,
+        })
+    }
+
+    fn tee(self, other: Handler<T>) -> Handler<T> {
+        Box::new(Tee {
+            handler: self,
+            other,
+        })
+    }
+
+    fn shadow<F>(self, shadow: Handler<T>, on_outcome: F) -> Handler<T>
+    where
+        F: Fn(&ShadowOutcome) + Send + Sync + 'static,
+    {
+        Box::new(Shadow {
+            handler: self,
+            shadow,
+            on_outcome: Arc::new(on_outcome),
+        })
+    }
+
+    fn canary(self, canary: Handler<T>, weight: &CanaryWeight) -> Handler<T> {
+        Box::new(Canary {
+            handler: self,
+            canary,
+            router: CanaryRouter::new(weight, None),
+        })
+    }
+
+    fn canary_by_key<K>(self, canary: Handler<T>, weight: &CanaryWeight, key: K) -> Handler<T>
+    where
+        K: Fn(&Event<T>) -> String + Send + Sync + 'static,
+    {
+        Box::new(Canary {
+            handler: self,
+            canary,
+            router: CanaryRouter::new(weight, Some(Box::new(key))),
+        })
+    }
+
+    fn sandboxed(self, max_wall_time: Duration) -> Handler<T>
+    where
+        T: Clone + Send,
+    {
+        Box::new(Sandboxed {
+            handler: Arc::from(self),
+            max_wall_time,
+            disabled: Arc::new(AtomicBool::new(false)),
+        })
+    }
+}
+
+struct Retry<T> {
+    handler: Handler<T>,
+    policy: RetryPolicy,
+}
+
+impl<T> Handle<T> for Retry<T> {
+    fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        let mut attempt: u32 = 1;
+        loop {
+            match self.handler.handle(event) {
+                Err(_) if (attempt as usize) < self.policy.max_attempts => {
+                    thread::sleep(self.policy.delay(attempt));
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+struct Timeout<T> {
+    handler: Handler<T>,
+    timeout: Duration,
+}
+
+impl<T> Handle<T> for Timeout<T> {
+    fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        let started = Instant::now();
+        let result = self.handler.handle(event);
+        if started.elapsed() > self.timeout {
+            return Err(BasuError::HandlerTimeout);
+        }
+
+        result
+    }
+}
+
+struct Filtered<T> {
+    handler: Handler<T>,
+    predicate: Predicate<T>,
+}
+
+impl<T> Handle<T> for Filtered<T> {
+    fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        if (self.predicate)(event) {
+            self.handler.handle(event)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+struct Fallback<T> {
+    handler: Handler<T>,
+    other: Handler<T>,
+}
+
+impl<T> Handle<T> for Fallback<T> {
+    fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        self.handler
+            .handle(event)
+            .or_else(|_| self.other.handle(event))
+    }
+}
+
+struct Tee<T> {
+    handler: Handler<T>,
+    other: Handler<T>,
+}
+
+impl<T> Handle<T> for Tee<T> {
+    fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        let result = self.handler.handle(event);
+        let other_result = self.other.handle(event);
+        result.and(other_result)
+    }
+}
+
+struct Shadow<T> {
+    handler: Handler<T>,
+    shadow: Handler<T>,
+    on_outcome: ShadowCallback,
+}
+
+impl<T> Handle<T> for Shadow<T> {
+    fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        let started = Instant::now();
+        let result = self.handler.handle(event);
+        let elapsed = started.elapsed();
+
+        let started = Instant::now();
+        let shadow_result = self.shadow.handle(event);
+        let shadow_elapsed = started.elapsed();
+
+        (self.on_outcome)(&ShadowOutcome::new(
+            &result,
+            elapsed,
+            &shadow_result,
+            shadow_elapsed,
+        ));
+        result
+    }
+}
+
+struct Canary<T> {
+    handler: Handler<T>,
+    canary: Handler<T>,
+    router: CanaryRouter<T>,
+}
+
+impl<T> Handle<T> for Canary<T> {
+    fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        if self.router.to_canary(event) {
+            self.canary.handle(event)
+        } else {
+            self.handler.handle(event)
+        }
+    }
+}
+
+struct Sandboxed<T> {
+    handler: Arc<dyn Handle<T>>,
+    max_wall_time: Duration,
+    disabled: Arc<AtomicBool>,
+}
+
+impl<T: Clone + Send + 'static> Handle<T> for Sandboxed<T> {
+    fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        if self.disabled.load(Ordering::Relaxed) {
+            return Err(BasuError::HandlerResourceExceeded);
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        let handler = self.handler.clone();
+        let event = event.clone();
+        thread::Builder::new()
+            .name("basu-sandbox".to_owned())
+            .spawn(move || {
+                let result = panic::catch_unwind(AssertUnwindSafe(|| handler.handle(&event)));
+                let _ = sender.send(result);
+            })
+            .map_err(anyhow::Error::from)?;
+
+        match receiver.recv_timeout(self.max_wall_time) {
+            Ok(Ok(result)) => result,
+            Ok(Err(_)) => Err(anyhow::anyhow!("sandboxed handler panicked").into()),
+            Err(_) => {
+                self.disabled.store(true, Ordering::Relaxed);
+                Err(BasuError::HandlerResourceExceeded)
+            }
+        }
+    }
+}
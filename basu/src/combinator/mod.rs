@@ -0,0 +1,192 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+
+#[cfg(feature = "async")]
+pub use impl_async::HandlerExt;
+#[cfg(feature = "sync")]
+pub use impl_sync::HandlerExt;
+
+use crate::{error::BasuError, event::Event};
+
+use std::{
+    collections::hash_map::{DefaultHasher, RandomState},
+    hash::{BuildHasher, Hash, Hasher},
+    sync::{
+        atomic::{AtomicU64, AtomicU8, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+/// Predicate deciding whether a filtered handler receives an event.
+pub type Predicate<T> = Box<dyn Fn(&Event<T>) -> bool + Send + Sync>;
+
+/// Correlation key routing all events with the same key to the same canary side.
+pub type CanaryKey<T> = Box<dyn Fn(&Event<T>) -> String + Send + Sync>;
+
+/// Percentage of events routed to a canary handler, adjustable at runtime.
+#[derive(Debug, Clone, Default)]
+pub struct CanaryWeight(Arc<AtomicU8>);
+
+impl CanaryWeight {
+    /// create a new `CanaryWeight` routing `percent` of the events to the canary.
+    pub fn new(percent: u8) -> Self {
+        Self(Arc::new(AtomicU8::new(percent.min(100))))
+    }
+
+    /// change the percentage of events routed to the canary, capped at 100.
+    pub fn set(&self, percent: u8) {
+        self.0.store(percent.min(100), Ordering::Relaxed);
+    }
+
+    /// percentage of events routed to the canary.
+    pub fn get(&self) -> u8 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Routing decision of a canary handler.
+struct CanaryRouter<T> {
+    weight: CanaryWeight,
+    key: Option<CanaryKey<T>>,
+    count: AtomicU64,
+}
+
+impl<T> CanaryRouter<T> {
+    fn new(weight: &CanaryWeight, key: Option<CanaryKey<T>>) -> Self {
+        Self {
+            weight: weight.clone(),
+            key,
+            count: AtomicU64::new(0),
+        }
+    }
+
+    /// whether the event goes to the canary, events are spread evenly over 100 buckets
+    /// by arrival order, or by the hash of their key when sticky.
+    fn to_canary(&self, event: &Event<T>) -> bool {
+        let bucket = match &self.key {
+            Some(key) => {
+                let mut hasher = DefaultHasher::new();
+                key(event).hash(&mut hasher);
+                hasher.finish()
+            }
+            None => self.count.fetch_add(1, Ordering::Relaxed),
+        };
+        bucket % 100 < u64::from(self.weight.get())
+    }
+}
+
+/// Callback receiving the outcomes of a handler and its shadow.
+pub type ShadowCallback = Arc<dyn Fn(&ShadowOutcome) + Send + Sync>;
+
+/// Outcomes of a primary handler and its shadow for one event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShadowOutcome {
+    /// result of the primary handler, with the error rendered as a string
+    pub primary: Result<(), String>,
+    /// result of the shadow handler, with the error rendered as a string
+    pub shadow: Result<(), String>,
+    /// time taken by the primary handler
+    pub primary_elapsed: Duration,
+    /// time taken by the shadow handler
+    pub shadow_elapsed: Duration,
+}
+
+impl ShadowOutcome {
+    fn new(
+        primary: &Result<(), BasuError>,
+        primary_elapsed: Duration,
+        shadow: &Result<(), BasuError>,
+        shadow_elapsed: Duration,
+    ) -> Self {
+        let render = |result: &Result<(), BasuError>| match result {
+            Ok(()) => Ok(()),
+            Err(err) => Err(err.to_string()),
+        };
+
+        Self {
+            primary: render(primary),
+            shadow: render(shadow),
+            primary_elapsed,
+            shadow_elapsed,
+        }
+    }
+
+    /// whether the shadow's result differs from the primary's.
+    pub fn diverged(&self) -> bool {
+        self.primary != self.shadow
+    }
+}
+
+/// Retry policy of a handler wrapped with `HandlerExt::with_retry`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// number of attempts including the first one
+    pub max_attempts: usize,
+    /// delay before the first retry
+    pub backoff: Duration,
+    /// factor applied to the delay after each retry, `1` keeps it constant
+    pub multiplier: u32,
+    /// upper bound of the delay, before jitter
+    pub max_backoff: Option<Duration>,
+    /// upper bound of a random delay added to each backoff, so handlers failing together
+    /// don't retry in lockstep
+    pub jitter: Duration,
+}
+
+impl RetryPolicy {
+    /// create a new `RetryPolicy` waiting `backoff` between two attempts.
+    pub fn new(max_attempts: usize, backoff: Duration) -> Self {
+        Self {
+            max_attempts,
+            backoff,
+            multiplier: 1,
+            max_backoff: None,
+            jitter: Duration::ZERO,
+        }
+    }
+
+    /// create a `RetryPolicy` whose delay starts at `backoff` and doubles after each retry.
+    ///
+    /// ```no_run
+    /// let policy = RetryPolicy::exponential(5, Duration::from_millis(50))
+    ///     .with_max_backoff(Duration::from_secs(2))
+    ///     .with_jitter(Duration::from_millis(20));
+    /// ```
+    pub fn exponential(max_attempts: usize, backoff: Duration) -> Self {
+        Self {
+            multiplier: 2,
+            ..Self::new(max_attempts, backoff)
+        }
+    }
+
+    /// cap the delay between two attempts, before jitter.
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = Some(max_backoff);
+        self
+    }
+
+    /// add a random delay of up to `jitter` to each backoff.
+    pub fn with_jitter(mut self, jitter: Duration) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// return the delay before the `retry`-th retry, starting at `1`.
+    pub fn delay(&self, retry: u32) -> Duration {
+        let factor = self.multiplier.saturating_pow(retry.saturating_sub(1));
+        let backoff = self.backoff.saturating_mul(factor);
+        let backoff = self
+            .max_backoff
+            .map_or(backoff, |max_backoff| backoff.min(max_backoff));
+
+        let jitter = self.jitter.as_nanos().min(u64::MAX as u128) as u64;
+        if jitter == 0 {
+            return backoff;
+        }
+        let random = RandomState::new().build_hasher().finish();
+        backoff.saturating_add(Duration::from_nanos(random % (jitter + 1)))
+    }
+}
@@ -0,0 +1,96 @@
+use super::{CommandBus, CommandHandler};
+use crate::{async_trait, error::BasuError};
+
+use std::{collections::hash_map::Entry, sync::Arc};
+
+/// Implement for command handler
+#[async_trait]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub trait HandleCommand<T, R>: Send + Sync {
+    /// Handle command which is dispatched from `CommandBus`
+    async fn handle(&self, command: T) -> Result<R, BasuError>;
+}
+
+impl<T, R> CommandBus<T, R> {
+    /// Register the handler of a command type.
+    /// Each command type accepts exactly one handler, registering a second one fails with
+    /// `BasuError::HandlerAlreadyRegistered`.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// struct CreateOrder {
+    ///     // Define your command structure here
+    /// }
+    ///
+    /// struct CreateOrderHandler;
+    ///
+    /// #[async_trait]
+    /// impl HandleCommand<CreateOrder, u64> for CreateOrderHandler {
+    ///     async fn handle(&self, command: CreateOrder) -> Result<u64, BasuError> {
+    ///         // Handle the command here
+    ///         // ...
+    ///         Ok(42)
+    ///     }
+    /// }
+    ///
+    /// let command_bus = CommandBus::<CreateOrder, u64>::new();
+    /// command_bus.register("create_order", Box::new(CreateOrderHandler)).await?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn register(
+        &self,
+        command_type: &str,
+        handler: CommandHandler<T, R>,
+    ) -> Result<(), BasuError> {
+        let mut command_handler_map = self.command_handler_map.lock().await;
+
+        match command_handler_map.entry(command_type.to_owned()) {
+            Entry::Occupied(_) => Err(BasuError::HandlerAlreadyRegistered),
+            Entry::Vacant(entry) => {
+                entry.insert(Arc::from(handler));
+                Ok(())
+            }
+        }
+    }
+
+    /// Unregister the handler of a command type.
+    ///
+    /// ```no_run
+    /// command_bus.unregister("create_order").await?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn unregister(&self, command_type: &str) -> Result<(), BasuError> {
+        let mut command_handler_map = self.command_handler_map.lock().await;
+
+        match command_handler_map.remove(command_type) {
+            Some(_) => Ok(()),
+            None => Err(BasuError::CommandTypeNotFound),
+        }
+    }
+
+    /// Dispatch a command to its handler and return the handler's result.
+    ///
+    /// ```no_run
+    /// let order_id = command_bus.dispatch("create_order", CreateOrder { /* ... */ }).await?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn dispatch(&self, command_type: &str, command: T) -> Result<R, BasuError> {
+        let handler = {
+            let command_handler_map = self.command_handler_map.lock().await;
+            command_handler_map
+                .get(command_type)
+                .cloned()
+                .ok_or(BasuError::CommandTypeNotFound)?
+        };
+
+        handler.handle(command).await
+    }
+
+    /// List all registered command types.
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn list(&self) -> Vec<String> {
+        let command_handler_map = self.command_handler_map.lock().await;
+
+        command_handler_map.keys().cloned().collect()
+    }
+}
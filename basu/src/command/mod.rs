@@ -0,0 +1,38 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+
+#[cfg(feature = "async")]
+pub use impl_async::HandleCommand;
+#[cfg(feature = "sync")]
+pub use impl_sync::HandleCommand;
+
+use crate::Mutex;
+
+use std::{collections::HashMap, sync::Arc};
+
+/// Command hanlder
+pub type CommandHandler<T, R> = Box<dyn HandleCommand<T, R>>;
+/// Command hanlder map
+pub type CommandHandlerMap<T, R> = Arc<Mutex<HashMap<String, Arc<dyn HandleCommand<T, R>>>>>;
+
+/// A `CommandBus` which routes each command type to exactly one handler.
+pub struct CommandBus<T, R> {
+    command_handler_map: CommandHandlerMap<T, R>,
+}
+
+impl<T, R> CommandBus<T, R> {
+    /// create a new `CommandBus`
+    pub fn new() -> Self {
+        Self {
+            command_handler_map: Default::default(),
+        }
+    }
+}
+
+impl<T, R> Default for CommandBus<T, R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
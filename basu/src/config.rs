@@ -0,0 +1,416 @@
+#[cfg(feature = "sync")]
+use crate::dispatch::{DispatchStrategy, Dispatcher};
+use crate::{
+    error::{BasuError, ErrorPolicy},
+    EventBus,
+};
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, PoisonError},
+    time::Duration,
+};
+
+/// Live settings of an `EventBus`, changed atomically through `EventBus::reconfigure`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "sync", derive(Default))]
+pub struct BusConfig {
+    #[cfg(feature = "async")]
+    fanout_chunk_size: usize,
+    #[cfg(feature = "async")]
+    handler_timeout: Option<Duration>,
+    #[cfg(feature = "sync")]
+    dispatch_strategy: DispatchStrategy,
+    #[cfg(feature = "sync")]
+    topic_dispatch_strategies: HashMap<String, DispatchStrategy>,
+    error_policy: ErrorPolicy,
+    slow_handler_threshold: Option<Duration>,
+    topic_slow_handler_thresholds: HashMap<String, Duration>,
+}
+
+#[cfg(feature = "async")]
+impl Default for BusConfig {
+    fn default() -> Self {
+        Self {
+            fanout_chunk_size: crate::impl_async::DEFAULT_FANOUT_CHUNK_SIZE,
+            handler_timeout: None,
+            error_policy: ErrorPolicy::default(),
+            slow_handler_threshold: None,
+            topic_slow_handler_thresholds: HashMap::new(),
+        }
+    }
+}
+
+impl BusConfig {
+    /// return how many handlers a publish invokes concurrently before yielding to the runtime.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub fn fanout_chunk_size(&self) -> usize {
+        self.fanout_chunk_size
+    }
+
+    /// set how many handlers a publish invokes concurrently before yielding to the runtime.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub fn set_fanout_chunk_size(&mut self, chunk_size: usize) {
+        self.fanout_chunk_size = chunk_size.max(1);
+    }
+
+    /// return how long a handler invocation may take before it fails with
+    /// `BasuError::HandlerTimeout`, if limited.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub fn handler_timeout(&self) -> Option<Duration> {
+        self.handler_timeout
+    }
+
+    /// set how long a handler invocation may take, `None` lets handlers run as long as they
+    /// need.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub fn set_handler_timeout(&mut self, timeout: Option<Duration>) {
+        self.handler_timeout = timeout;
+    }
+
+    /// return the dispatch strategy of event types without their own strategy.
+    #[cfg(feature = "sync")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn dispatch_strategy(&self) -> &DispatchStrategy {
+        &self.dispatch_strategy
+    }
+
+    /// set the dispatch strategy of event types without their own strategy.
+    #[cfg(feature = "sync")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn set_dispatch_strategy(&mut self, strategy: DispatchStrategy) {
+        self.dispatch_strategy = strategy;
+    }
+
+    /// return the dispatch strategy of a single event type, if it has one.
+    #[cfg(feature = "sync")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn topic_dispatch_strategy(&self, event_type: &str) -> Option<&DispatchStrategy> {
+        self.topic_dispatch_strategies.get(event_type)
+    }
+
+    #[cfg(feature = "sync")]
+    pub(crate) fn topic_dispatch_strategies(
+        &self,
+    ) -> impl Iterator<Item = (&String, &DispatchStrategy)> {
+        self.topic_dispatch_strategies.iter()
+    }
+
+    /// set the dispatch strategy of a single event type, `None` falls back to the bus strategy.
+    #[cfg(feature = "sync")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn set_topic_dispatch_strategy(
+        &mut self,
+        event_type: &str,
+        strategy: Option<DispatchStrategy>,
+    ) {
+        match strategy {
+            Some(strategy) => {
+                self.topic_dispatch_strategies
+                    .insert(event_type.to_owned(), strategy);
+            }
+            None => {
+                self.topic_dispatch_strategies.remove(event_type);
+            }
+        }
+    }
+
+    /// return how publishes deal with handler errors.
+    pub fn error_policy(&self) -> ErrorPolicy {
+        self.error_policy
+    }
+
+    /// set how publishes deal with handler errors.
+    pub fn set_error_policy(&mut self, policy: ErrorPolicy) {
+        self.error_policy = policy;
+    }
+
+    /// return how long a handler of the event type may take before it's reported as slow,
+    /// the threshold of the event type if it has one, otherwise the one of the bus.
+    pub fn slow_handler_threshold(&self, event_type: &str) -> Option<Duration> {
+        self.topic_slow_handler_thresholds
+            .get(event_type)
+            .copied()
+            .or(self.slow_handler_threshold)
+    }
+
+    /// whether a handler of some event type can be reported as slow.
+    pub(crate) fn has_slow_handler_threshold(&self) -> bool {
+        self.slow_handler_threshold.is_some() || !self.topic_slow_handler_thresholds.is_empty()
+    }
+
+    /// event types with a setting of their own, with the name of the setting.
+    pub(crate) fn topic_settings(&self) -> impl Iterator<Item = (&String, &'static str)> {
+        let slow_handler_thresholds = self
+            .topic_slow_handler_thresholds
+            .keys()
+            .map(|event_type| (event_type, "slow_handler_threshold"));
+        #[cfg(feature = "sync")]
+        let slow_handler_thresholds = slow_handler_thresholds.chain(
+            self.topic_dispatch_strategies
+                .keys()
+                .map(|event_type| (event_type, "dispatch_strategy")),
+        );
+        slow_handler_thresholds
+    }
+
+    /// set how long handlers may take before they're reported as slow, `None` disables it.
+    pub fn set_slow_handler_threshold(&mut self, threshold: Option<Duration>) {
+        self.slow_handler_threshold = threshold;
+    }
+
+    /// set the slow handler threshold of a single event type, `None` falls back to the
+    /// threshold of the bus.
+    pub fn set_topic_slow_handler_threshold(
+        &mut self,
+        event_type: &str,
+        threshold: Option<Duration>,
+    ) {
+        match threshold {
+            Some(threshold) => {
+                self.topic_slow_handler_thresholds
+                    .insert(event_type.to_owned(), threshold);
+            }
+            None => {
+                self.topic_slow_handler_thresholds.remove(event_type);
+            }
+        }
+    }
+}
+
+impl BusConfig {
+    /// set a setting from its textual form, as used by config files.
+    ///
+    /// | key | value |
+    /// |-----|-------|
+    /// | `fanout_chunk_size` (async) | number of handlers |
+    /// | `handler_timeout_ms` (async) | milliseconds, empty disables it |
+    /// | `dispatch_strategy` (sync) | textual form of a `DispatchStrategy` |
+    /// | `dispatch_strategy.<event type>` (sync) | textual form of a `DispatchStrategy` |
+    /// | `error_policy` | `fail_fast`, `collect_all` or `ignore` |
+    /// | `slow_handler_threshold_ms` | milliseconds, empty disables it |
+    /// | `slow_handler_threshold_ms.<event type>` | milliseconds, empty falls back to the bus |
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), BasuError> {
+        #[cfg(feature = "async")]
+        if key == "fanout_chunk_size" {
+            let chunk_size = value.parse().map_err(|_| {
+                BasuError::InvalidConfig(format!("invalid fanout_chunk_size `{}`", value))
+            })?;
+            self.set_fanout_chunk_size(chunk_size);
+            return Ok(());
+        }
+
+        #[cfg(feature = "async")]
+        if key == "handler_timeout_ms" {
+            let timeout = match value {
+                "" => None,
+                value => Some(Duration::from_millis(value.parse().map_err(|_| {
+                    BasuError::InvalidConfig(format!("invalid handler_timeout_ms `{}`", value))
+                })?)),
+            };
+            self.set_handler_timeout(timeout);
+            return Ok(());
+        }
+
+        #[cfg(feature = "sync")]
+        if key == "dispatch_strategy" {
+            self.set_dispatch_strategy(value.parse()?);
+            return Ok(());
+        }
+
+        #[cfg(feature = "sync")]
+        if let Some(event_type) = key.strip_prefix("dispatch_strategy.") {
+            self.set_topic_dispatch_strategy(event_type, Some(value.parse()?));
+            return Ok(());
+        }
+
+        if key == "error_policy" {
+            self.set_error_policy(value.parse()?);
+            return Ok(());
+        }
+
+        let threshold = || -> Result<Option<Duration>, BasuError> {
+            if value.is_empty() {
+                return Ok(None);
+            }
+            value
+                .parse()
+                .map(|ms| Some(Duration::from_millis(ms)))
+                .map_err(|_| {
+                    BasuError::InvalidConfig(format!(
+                        "invalid slow_handler_threshold_ms `{}`",
+                        value
+                    ))
+                })
+        };
+
+        if key == "slow_handler_threshold_ms" {
+            self.set_slow_handler_threshold(threshold()?);
+            return Ok(());
+        }
+
+        if let Some(event_type) = key.strip_prefix("slow_handler_threshold_ms.") {
+            self.set_topic_slow_handler_threshold(event_type, threshold()?);
+            return Ok(());
+        }
+
+        Err(BasuError::InvalidConfig(format!("unknown key `{}`", key)))
+    }
+
+    /// return every setting in its textual form, keyed like `set`.
+    pub fn entries(&self) -> BTreeMap<String, String> {
+        let mut entries = BTreeMap::new();
+
+        #[cfg(feature = "async")]
+        {
+            entries.insert(
+                "fanout_chunk_size".to_owned(),
+                self.fanout_chunk_size.to_string(),
+            );
+            if let Some(timeout) = self.handler_timeout {
+                entries.insert(
+                    "handler_timeout_ms".to_owned(),
+                    timeout.as_millis().to_string(),
+                );
+            }
+        }
+
+        #[cfg(feature = "sync")]
+        {
+            entries.insert(
+                "dispatch_strategy".to_owned(),
+                self.dispatch_strategy.to_string(),
+            );
+            for (event_type, strategy) in &self.topic_dispatch_strategies {
+                entries.insert(
+                    format!("dispatch_strategy.{}", event_type),
+                    strategy.to_string(),
+                );
+            }
+        }
+
+        entries.insert("error_policy".to_owned(), self.error_policy.to_string());
+        if let Some(threshold) = self.slow_handler_threshold {
+            entries.insert(
+                "slow_handler_threshold_ms".to_owned(),
+                threshold.as_millis().to_string(),
+            );
+        }
+        for (event_type, threshold) in &self.topic_slow_handler_thresholds {
+            entries.insert(
+                format!("slow_handler_threshold_ms.{}", event_type),
+                threshold.as_millis().to_string(),
+            );
+        }
+
+        entries
+    }
+}
+
+/// Settings in effect, built from a `BusConfig`.
+#[derive(Default)]
+pub(crate) struct Settings {
+    pub(crate) config: BusConfig,
+    #[cfg(feature = "sync")]
+    pub(crate) dispatcher: Dispatcher,
+    #[cfg(feature = "sync")]
+    pub(crate) topic_dispatchers: HashMap<String, Dispatcher>,
+}
+
+impl Settings {
+    /// build the settings of `config`, reusing the dispatchers of `current` whose strategy
+    /// didn't change, so only a changed strategy can fail to build its thread pool.
+    #[cfg_attr(not(feature = "sync"), allow(unused_variables))]
+    fn build(config: BusConfig, current: &Settings) -> Result<Self, BasuError> {
+        Ok(Self {
+            #[cfg(feature = "sync")]
+            dispatcher: if config.dispatch_strategy == current.config.dispatch_strategy {
+                current.dispatcher.clone()
+            } else {
+                Dispatcher::new(&config.dispatch_strategy)?
+            },
+            #[cfg(feature = "sync")]
+            topic_dispatchers: config
+                .topic_dispatch_strategies
+                .iter()
+                .map(|(event_type, strategy)| {
+                    let dispatcher = match current.topic_dispatchers.get(event_type) {
+                        Some(dispatcher)
+                            if current.config.topic_dispatch_strategy(event_type)
+                                == Some(strategy) =>
+                        {
+                            dispatcher.clone()
+                        }
+                        _ => Dispatcher::new(strategy)?,
+                    };
+                    Ok((event_type.clone(), dispatcher))
+                })
+                .collect::<Result<_, BasuError>>()?,
+            config,
+        })
+    }
+}
+
+impl<T> EventBus<T> {
+    /// set how publishes deal with handler errors, every error is collected by default.
+    ///
+    /// ```no_run
+    /// let event_bus = EventBus::<MyEventData>::new().with_error_policy(ErrorPolicy::FailFast);
+    /// ```
+    pub fn with_error_policy(self, policy: ErrorPolicy) -> Self {
+        self.configured(|config| config.set_error_policy(policy))
+    }
+
+    /// return a snapshot of the live settings.
+    pub fn config(&self) -> BusConfig {
+        self.settings().config.clone()
+    }
+
+    /// Apply changes to the live settings atomically, the next publish sees all of them
+    /// while publishes in flight finish with the previous settings.
+    /// Nothing changes when the new settings can't be applied, e.g. when the thread pool
+    /// of a dispatch strategy can't be built.
+    ///
+    /// ```no_run
+    /// event_bus.reconfigure(|config| {
+    ///     config.set_fanout_chunk_size(64);
+    /// })?;
+    /// ```
+    pub fn reconfigure(&self, f: impl FnOnce(&mut BusConfig)) -> Result<(), BasuError> {
+        let mut settings = self
+            .settings
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        let mut config = settings.config.clone();
+        f(&mut config);
+        if config != settings.config {
+            *settings = Arc::new(Settings::build(config, &settings)?);
+        }
+
+        Ok(())
+    }
+
+    /// apply changes to the settings of a bus under construction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the thread pool of a changed dispatch strategy can't be built, builders
+    /// which don't set a strategy never panic.
+    pub(crate) fn configured(self, f: impl FnOnce(&mut BusConfig)) -> Self {
+        self.reconfigure(f)
+            .expect("failed to build dispatch thread pool");
+        self
+    }
+
+    pub(crate) fn settings(&self) -> Arc<Settings> {
+        self.settings
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
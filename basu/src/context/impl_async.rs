@@ -0,0 +1,60 @@
+use super::Context;
+use crate::error::BasuError;
+
+use std::{any::Any, cell::RefCell, future::Future};
+
+tokio::task_local! {
+    /// Context of the handler or scope being polled.
+    static CONTEXT: RefCell<Context>;
+}
+
+impl Context {
+    /// Run `f` with this context as the current one.
+    /// Publishes inside `f` carry it to their handlers.
+    ///
+    /// ```no_run
+    /// Context::new()
+    ///     .with(Locale("fr"))?
+    ///     .scope(event_bus.publish("order.created", &event))
+    ///     .await?;
+    /// ```
+    ///
+    /// **Note:** Tasks spawned inside `f` start without a context, capture it with
+    /// `context::current()` and run them in their own scope.
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn scope<F: Future>(self, f: F) -> F::Output {
+        CONTEXT.scope(RefCell::new(self), f).await
+    }
+}
+
+/// return a copy of the current context, empty outside any scope.
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub fn current() -> Context {
+    CONTEXT
+        .try_with(|context| context.borrow().clone())
+        .unwrap_or_default()
+}
+
+/// return a clone of the value of type `V` in the current context, if any.
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub fn get<V: Any + Clone>() -> Option<V> {
+    CONTEXT
+        .try_with(|context| context.borrow().get::<V>().cloned())
+        .ok()
+        .flatten()
+}
+
+/// Set a value in the current context, seen by the handlers of the events published
+/// afterwards in the same handler or scope.
+/// It fails with `BasuError::NoContext` outside a handler or scope.
+///
+/// ```no_run
+/// context::set(Experiment::new("checkout-v2"))?;
+/// event_bus.publish("checkout.started", &event).await?;
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub fn set<V: Any + Send + Sync>(value: V) -> Result<(), BasuError> {
+    CONTEXT
+        .try_with(|context| context.borrow_mut().insert(value))
+        .map_err(|_| BasuError::NoContext)?
+}
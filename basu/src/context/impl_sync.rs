@@ -0,0 +1,70 @@
+use super::Context;
+use crate::error::BasuError;
+
+use std::{any::Any, cell::RefCell};
+
+thread_local! {
+    /// Context of the handler or scope running on this thread.
+    static CONTEXT: RefCell<Option<Context>> = const { RefCell::new(None) };
+}
+
+/// Restores the previous context of the thread when dropped.
+struct Restore(Option<Context>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        CONTEXT.with(|context| *context.borrow_mut() = self.0.take());
+    }
+}
+
+impl Context {
+    /// Run `f` with this context as the current one.
+    /// Publishes inside `f` carry it to their handlers.
+    ///
+    /// ```no_run
+    /// Context::new()
+    ///     .with(Locale("fr"))?
+    ///     .scope(|| event_bus.publish("order.created", &event))?;
+    /// ```
+    ///
+    /// **Note:** Threads spawned inside `f` start without a context, capture it with
+    /// `context::current()` and run them in their own scope.
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn scope<R>(self, f: impl FnOnce() -> R) -> R {
+        let _restore = Restore(CONTEXT.with(|context| context.borrow_mut().replace(self)));
+        f()
+    }
+}
+
+/// return a copy of the current context, empty outside any scope.
+#[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+pub fn current() -> Context {
+    CONTEXT.with(|context| context.borrow().clone().unwrap_or_default())
+}
+
+/// return a clone of the value of type `V` in the current context, if any.
+#[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+pub fn get<V: Any + Clone>() -> Option<V> {
+    CONTEXT.with(|context| {
+        context
+            .borrow()
+            .as_ref()
+            .and_then(|context| context.get::<V>().cloned())
+    })
+}
+
+/// Set a value in the current context, seen by the handlers of the events published
+/// afterwards in the same handler or scope.
+/// It fails with `BasuError::NoContext` outside a handler or scope.
+///
+/// ```no_run
+/// context::set(Experiment::new("checkout-v2"))?;
+/// event_bus.publish("checkout.started", &event)?;
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+pub fn set<V: Any + Send + Sync>(value: V) -> Result<(), BasuError> {
+    CONTEXT.with(|context| match context.borrow_mut().as_mut() {
+        Some(context) => context.insert(value),
+        None => Err(BasuError::NoContext),
+    })
+}
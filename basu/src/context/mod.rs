@@ -0,0 +1,85 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+
+#[cfg(feature = "async")]
+pub use impl_async::{current, get, set};
+#[cfg(feature = "sync")]
+pub use impl_sync::{current, get, set};
+
+use crate::error::BasuError;
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt,
+    sync::Arc,
+};
+
+/// Maximum number of values a `Context` holds, it is meant for small cross-cutting data
+/// like a locale or experiment assignments rather than payloads.
+pub const MAX_CONTEXT_VALUES: usize = 16;
+
+/// Values carried from a publish to its handlers and to the events they publish in turn,
+/// at most one value per type.
+///
+/// Each handler runs with its own copy of the publisher's context, values a handler sets are
+/// seen by the handlers of the events it publishes but not by its siblings.
+#[derive(Clone, Default)]
+pub struct Context {
+    values: Arc<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>,
+}
+
+impl Context {
+    /// create an empty context.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a value to the context, replacing the value of the same type.
+    /// It fails with `BasuError::ContextFull` when the context already holds
+    /// `MAX_CONTEXT_VALUES` values.
+    pub fn with<V: Any + Send + Sync>(mut self, value: V) -> Result<Self, BasuError> {
+        self.insert(value)?;
+        Ok(self)
+    }
+
+    /// return the value of type `V`, if any.
+    pub fn get<V: Any>(&self) -> Option<&V> {
+        self.values
+            .get(&TypeId::of::<V>())
+            .and_then(|value| value.downcast_ref())
+    }
+
+    /// return the number of values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// whether the context holds no value.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// add or replace the value of type `V` within the size limit.
+    fn insert<V: Any + Send + Sync>(&mut self, value: V) -> Result<(), BasuError> {
+        let type_id = TypeId::of::<V>();
+        if !self.values.contains_key(&type_id) && self.values.len() >= MAX_CONTEXT_VALUES {
+            return Err(BasuError::ContextFull {
+                limit: MAX_CONTEXT_VALUES,
+            });
+        }
+
+        Arc::make_mut(&mut self.values).insert(type_id, Arc::new(value));
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Context {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Context")
+            .field("len", &self.values.len())
+            .finish()
+    }
+}
@@ -0,0 +1,305 @@
+use crate::{error::BasuError, report::Dispatched, slow::SlowWatch, EventBus, Handler, HandlerId};
+
+use rayon::{prelude::*, ThreadPool, ThreadPoolBuilder};
+use std::{
+    fmt, panic,
+    str::FromStr,
+    sync::{Arc, Mutex, PoisonError},
+    thread::{self, ScopedJoinHandle},
+    time::Instant,
+};
+
+/// How handler invocations are scheduled onto a rayon pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RayonScheduling {
+    /// Split the handlers with `par_iter`.
+    #[default]
+    Join,
+    /// Spawn one task per handler with `spawn`, newest tasks first.
+    Spawn,
+    /// Spawn one task per handler with `spawn_fifo`, oldest tasks first.
+    SpawnFifo,
+}
+
+/// Configuration of a dedicated rayon pool running handlers.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RayonConfig {
+    /// number of threads, `0` lets rayon pick one per CPU
+    pub num_threads: usize,
+    /// prefix of the thread names, threads are named `{prefix}-{index}`
+    pub thread_name: Option<String>,
+    /// how handler invocations are scheduled onto the pool
+    pub scheduling: RayonScheduling,
+}
+
+/// Strategy which decides where handlers run when an event is published.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum DispatchStrategy {
+    /// Run handlers one after another on the publishing thread.
+    Inline,
+    /// Run handlers in parallel on the global rayon pool.
+    #[default]
+    Rayon,
+    /// Spawn a fresh thread for each handler on every publish.
+    ThreadPerPublish,
+    /// Run handlers in parallel on a dedicated rayon pool with the given number of threads.
+    Pool(usize),
+    /// Run handlers on a dedicated rayon pool built from a `RayonConfig`, so dispatch threads
+    /// are identifiable in profilers and don't interleave with other rayon work.
+    Dedicated(RayonConfig),
+}
+
+impl fmt::Display for RayonScheduling {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Join => "join",
+            Self::Spawn => "spawn",
+            Self::SpawnFifo => "spawn_fifo",
+        })
+    }
+}
+
+impl FromStr for RayonScheduling {
+    type Err = BasuError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "join" => Ok(Self::Join),
+            "spawn" => Ok(Self::Spawn),
+            "spawn_fifo" => Ok(Self::SpawnFifo),
+            _ => Err(BasuError::InvalidConfig(format!(
+                "unknown rayon scheduling `{}`",
+                s
+            ))),
+        }
+    }
+}
+
+/// Textual form used by config files: `inline`, `rayon`, `thread_per_publish`, `pool:<threads>`
+/// or `dedicated:<threads>[:<scheduling>[:<thread name>]]`.
+impl fmt::Display for DispatchStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Inline => f.write_str("inline"),
+            Self::Rayon => f.write_str("rayon"),
+            Self::ThreadPerPublish => f.write_str("thread_per_publish"),
+            Self::Pool(num_threads) => write!(f, "pool:{}", num_threads),
+            Self::Dedicated(config) => {
+                write!(f, "dedicated:{}:{}", config.num_threads, config.scheduling)?;
+                match &config.thread_name {
+                    Some(thread_name) => write!(f, ":{}", thread_name),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for DispatchStrategy {
+    type Err = BasuError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || BasuError::InvalidConfig(format!("unknown dispatch strategy `{}`", s));
+        let num_threads = |n: &str| n.parse::<usize>().map_err(|_| invalid());
+
+        match s.split_once(':') {
+            None => match s {
+                "inline" => Ok(Self::Inline),
+                "rayon" => Ok(Self::Rayon),
+                "thread_per_publish" => Ok(Self::ThreadPerPublish),
+                _ => Err(invalid()),
+            },
+            Some(("pool", n)) => Ok(Self::Pool(num_threads(n)?)),
+            Some(("dedicated", config)) => {
+                let mut parts = config.splitn(3, ':');
+                Ok(Self::Dedicated(RayonConfig {
+                    num_threads: num_threads(parts.next().unwrap_or_default())?,
+                    scheduling: parts
+                        .next()
+                        .map(str::parse)
+                        .transpose()?
+                        .unwrap_or_default(),
+                    thread_name: parts.next().map(str::to_owned),
+                }))
+            }
+            Some(_) => Err(invalid()),
+        }
+    }
+}
+
+/// Dispatcher built from a `DispatchStrategy`.
+#[derive(Clone, Default)]
+pub(crate) enum Dispatcher {
+    Inline,
+    #[default]
+    Rayon,
+    ThreadPerPublish,
+    Pool(Arc<ThreadPool>, RayonScheduling),
+}
+
+impl Dispatcher {
+    pub(crate) fn new(strategy: &DispatchStrategy) -> Result<Self, BasuError> {
+        Ok(match strategy {
+            DispatchStrategy::Inline => Self::Inline,
+            DispatchStrategy::Rayon => Self::Rayon,
+            DispatchStrategy::ThreadPerPublish => Self::ThreadPerPublish,
+            DispatchStrategy::Pool(num_threads) => {
+                Self::new(&DispatchStrategy::Dedicated(RayonConfig {
+                    num_threads: *num_threads,
+                    ..Default::default()
+                }))?
+            }
+            DispatchStrategy::Dedicated(config) => {
+                let mut builder = ThreadPoolBuilder::new().num_threads(config.num_threads);
+                if let Some(prefix) = config.thread_name.clone() {
+                    builder = builder.thread_name(move |index| format!("{}-{}", prefix, index));
+                }
+                let pool = builder.build().map_err(anyhow::Error::from)?;
+                Self::Pool(Arc::new(pool), config.scheduling)
+            }
+        })
+    }
+
+    /// start the threads of the pool, so the first dispatch doesn't pay for spawning them.
+    pub(crate) fn warmup(&self) {
+        match self {
+            Self::Rayon => {
+                rayon::broadcast(|_| ());
+            }
+            Self::Pool(pool, _) => {
+                pool.broadcast(|_| ());
+            }
+            Self::Inline | Self::ThreadPerPublish => {}
+        }
+    }
+
+    /// Invoke `f` with the id of every handler and the handler according to the strategy and
+    /// return the failures and skips, `f` returns `None` for a handler it skipped.
+    /// Every handler runs even when others fail.
+    pub(crate) fn dispatch<'a, T, F>(
+        &self,
+        handlers: &[(&'a HandlerId, &'a Handler<T>)],
+        watch: Option<&SlowWatch<'_>>,
+        f: F,
+    ) -> Dispatched
+    where
+        F: Fn(&HandlerId, &Handler<T>) -> Option<Result<(), BasuError>> + Sync,
+    {
+        let invoke = |&(id, h): &(&'a HandlerId, &'a Handler<T>)| {
+            let outcome = match watch {
+                Some(watch) => {
+                    let started = Instant::now();
+                    let outcome = f(id, h);
+                    if outcome.is_some() {
+                        watch.observe(id, started.elapsed());
+                    }
+                    outcome
+                }
+                None => f(id, h),
+            };
+            (id, outcome)
+        };
+
+        match self {
+            Self::Inline => handlers.iter().map(invoke).collect(),
+            Self::Rayon => handlers
+                .par_iter()
+                .map(invoke)
+                .collect::<Vec<_>>()
+                .into_iter()
+                .collect(),
+            Self::ThreadPerPublish => thread::scope(|scope| {
+                let threads: Vec<ScopedJoinHandle<'_, _>> = handlers
+                    .iter()
+                    .map(|entry| scope.spawn(move || invoke(entry)))
+                    .collect();
+
+                threads
+                    .into_iter()
+                    .map(|thread| {
+                        thread
+                            .join()
+                            .unwrap_or_else(|payload| panic::resume_unwind(payload))
+                    })
+                    .collect()
+            }),
+            Self::Pool(pool, RayonScheduling::Join) => pool
+                .install(|| handlers.par_iter().map(invoke).collect::<Vec<_>>())
+                .into_iter()
+                .collect(),
+            Self::Pool(pool, scheduling) => {
+                let dispatched = Mutex::new(Dispatched::default());
+                let run = |entry| {
+                    let (id, outcome) = invoke(entry);
+                    dispatched
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .record(id, outcome);
+                };
+
+                if *scheduling == RayonScheduling::SpawnFifo {
+                    pool.scope_fifo(|scope| {
+                        for entry in handlers {
+                            scope.spawn_fifo(move |_| run(entry));
+                        }
+                    });
+                } else {
+                    pool.scope(|scope| {
+                        for entry in handlers {
+                            scope.spawn(move |_| run(entry));
+                        }
+                    });
+                }
+
+                dispatched
+                    .into_inner()
+                    .unwrap_or_else(PoisonError::into_inner)
+            }
+        }
+    }
+}
+
+impl<T> EventBus<T> {
+    /// set the dispatch strategy used by every event type without its own strategy.
+    ///
+    /// ```no_run
+    /// let event_bus = EventBus::<MyEventData>::new().with_dispatch_strategy(DispatchStrategy::Inline);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the thread pool of `DispatchStrategy::Pool` can't be built.
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn with_dispatch_strategy(self, strategy: DispatchStrategy) -> Self {
+        self.configured(|config| config.set_dispatch_strategy(strategy))
+    }
+
+    /// set the dispatch strategy of a single event type, e.g. to keep GUI handlers on the
+    /// publishing thread while the rest of the bus uses rayon.
+    ///
+    /// ```no_run
+    /// let event_bus = EventBus::<MyEventData>::new()
+    ///     .with_topic_dispatch_strategy("redraw", DispatchStrategy::Inline);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the thread pool of `DispatchStrategy::Pool` can't be built.
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn with_topic_dispatch_strategy(
+        self,
+        event_type: &str,
+        strategy: DispatchStrategy,
+    ) -> Self {
+        self.configured(|config| config.set_topic_dispatch_strategy(event_type, Some(strategy)))
+    }
+
+    pub(crate) fn dispatcher(&self, event_type: &str) -> Dispatcher {
+        let settings = self.settings();
+        settings
+            .topic_dispatchers
+            .get(event_type)
+            .unwrap_or(&settings.dispatcher)
+            .clone()
+    }
+}
@@ -1,3 +1,7 @@
+use crate::report::HandlerFailure;
+
+use std::{fmt, str::FromStr};
+
 /// Errors which can occur when interacting with `EventBus`.
 #[derive(thiserror::Error, Debug)]
 pub enum BasuError {
@@ -9,7 +13,169 @@ pub enum BasuError {
     #[error("event type not found")]
     EventTypeNotFOUND,
 
+    /// Command type has no registered handler in `CommandBus`.
+    #[error("command type not found")]
+    CommandTypeNotFound,
+
+    /// Query type has no registered handler in `QueryBus`.
+    #[error("query type not found")]
+    QueryTypeNotFound,
+
+    /// Command or query type already has a registered handler.
+    #[error("handler already registered")]
+    HandlerAlreadyRegistered,
+
+    /// Actor mailbox is closed because the actor has stopped.
+    #[error("actor mailbox is closed")]
+    MailboxClosed,
+
+    /// Supervised actor panicked more often than its policy allows.
+    #[error("actor escalated after {restarts} restarts")]
+    ActorEscalated {
+        /// number of restarts before escalation
+        restarts: usize,
+    },
+
+    /// `Handler` took longer than its timeout.
+    #[error("handler timed out")]
+    HandlerTimeout,
+
+    /// Sandboxed `Handler` exceeded its resource limit and was disabled.
+    #[error("handler exceeded its resource limit")]
+    HandlerResourceExceeded,
+
+    /// `Context` already holds as many values as its size limit allows.
+    #[error("context is full, it holds at most {limit} values")]
+    ContextFull {
+        /// maximum number of values
+        limit: usize,
+    },
+
+    /// Context value was set outside a handler or `Context::scope`.
+    #[error("no context outside a handler or context scope")]
+    NoContext,
+
+    /// Context of a handler holds no value of the type a `State` argument extracts.
+    #[error("no `{0}` state in the handler context")]
+    MissingState(&'static str),
+
+    /// Setting of a `BusConfig` is unknown or has an invalid value.
+    #[error("invalid config: {0}")]
+    InvalidConfig(String),
+
+    /// Publishing an event failed.
+    #[error(transparent)]
+    Publish(#[from] PublishError),
+
     /// Error occurs when `Handler` processing event.
     #[error(transparent)]
     HandlerError(#[from] anyhow::Error),
 }
+
+/// Errors which can occur when publishing an event, separate from `BasuError` so callers
+/// only match on outcomes a publish can have.
+///
+/// There is no queue-full or shutting-down error: publishes dispatch to the handlers
+/// directly instead of through a queue, and the bus has no shutdown state.
+#[derive(thiserror::Error, Debug)]
+pub enum PublishError {
+    /// Sync mutex lock is poisoned.
+    #[cfg(feature = "sync")]
+    #[error("Mutex is poisoned")]
+    MutexPoisoned,
+
+    /// Event type was never subscribed to.
+    #[error("event type not found")]
+    EventTypeNotFound,
+
+    /// Event type has no subscribed handler left.
+    #[error("event type has no subscribers")]
+    NoSubscribers,
+
+    /// Tenant published more events than its rate limit allows.
+    #[error("tenant `{tenant}` exceeded its rate limit")]
+    RateLimited {
+        /// tenant whose event was rejected
+        tenant: String,
+    },
+
+    /// Event was published from inside a handler of the same bus, which would deadlock.
+    /// Only detected with `EventBus::with_reentrancy_detection`.
+    #[cfg(feature = "sync")]
+    #[error("publish to `{event_type}` from a handler of `{dispatching}` on the same bus")]
+    ReentrantPublish {
+        /// event type which was published
+        event_type: String,
+        /// event type whose handler published
+        dispatching: String,
+    },
+
+    /// Request was handled without any handler replying.
+    #[error("no handler replied to the request")]
+    NoReply,
+
+    /// A handler failed and the handlers which hadn't started yet were skipped.
+    /// Only returned with `ErrorPolicy::FailFast`.
+    #[error("handler failed: {}", .0.error)]
+    HandlerFailed(Box<HandlerFailure>),
+
+    /// Some handlers failed, the others handled the event.
+    #[error("{} of {} handlers failed", .failed.len(), .succeeded + .failed.len())]
+    PartialFailure {
+        /// number of handlers which handled the event
+        succeeded: usize,
+        /// handlers which failed
+        failed: Vec<HandlerFailure>,
+    },
+
+    /// Every failed handler took longer than its timeout, e.g. the handler timeout of the
+    /// bus, the others handled the event. Returned instead of `HandlerFailed` or
+    /// `PartialFailure` when timeouts are the only failures.
+    #[error("{} of {} handlers timed out", .timed_out.len(), .succeeded + .timed_out.len())]
+    Timeout {
+        /// number of handlers which handled the event
+        succeeded: usize,
+        /// handlers which timed out
+        timed_out: Vec<HandlerFailure>,
+    },
+}
+
+/// How a publish deals with handler errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorPolicy {
+    /// Stop at the first handler error: handlers which haven't started are skipped and
+    /// the publish fails with `PublishError::HandlerFailed`. Skipped handlers don't count
+    /// as delivered in a `DispatchReport`.
+    FailFast,
+    /// Run every handler and fail with `PublishError::PartialFailure` listing every error.
+    #[default]
+    CollectAll,
+    /// Run every handler and succeed whatever they return.
+    Ignore,
+}
+
+impl fmt::Display for ErrorPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::FailFast => "fail_fast",
+            Self::CollectAll => "collect_all",
+            Self::Ignore => "ignore",
+        })
+    }
+}
+
+impl FromStr for ErrorPolicy {
+    type Err = BasuError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fail_fast" => Ok(Self::FailFast),
+            "collect_all" => Ok(Self::CollectAll),
+            "ignore" => Ok(Self::Ignore),
+            _ => Err(BasuError::InvalidConfig(format!(
+                "unknown error policy `{}`",
+                s
+            ))),
+        }
+    }
+}
@@ -1,5 +1,7 @@
+use std::sync::Arc;
+
 /// Abstraction for representing event that can hold any data type.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Event<T> {
     /// event data which can be processed by handler
     pub data: T,
@@ -27,3 +29,18 @@ impl<T> Event<T> {
         &self.data
     }
 }
+
+/// Gives access to a shared event, implemented by `Event<T>` only.
+pub(crate) trait AsEvent<T> {
+    fn event(&self) -> &Event<T>;
+}
+
+impl<T> AsEvent<T> for Event<T> {
+    fn event(&self) -> &Event<T> {
+        self
+    }
+}
+
+/// Event kept by the bus after its publish, type-erased so the bus stays `Sync` for any
+/// event data.
+pub(crate) type SharedEvent<T> = Arc<dyn AsEvent<T> + Send + Sync>;
@@ -0,0 +1,61 @@
+use super::Executor;
+use crate::{async_trait, error::BasuError, event::Event, EventBus, Handle, Handler, HandlerId};
+
+use futures::future::BoxFuture;
+use std::sync::Arc;
+
+/// Handler invocation scheduled on an `Executor`.
+pub type Job = BoxFuture<'static, ()>;
+
+/// Handler whose invocations are routed through an `Executor`.
+struct OnExecutor<T> {
+    handler: Arc<dyn Handle<T>>,
+    executor: Executor,
+}
+
+#[async_trait]
+impl<T: Clone + Send + Sync + 'static> Handle<T> for OnExecutor<T> {
+    async fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        let handler = self.handler.clone();
+        let executor = self.executor.clone();
+        let event = event.clone();
+        (self.executor.spawn)(Box::pin(async move {
+            executor.report(handler.handle(&event).await);
+        }));
+
+        Ok(())
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> EventBus<T> {
+    /// Subscribe a handler whose invocations run on the given `Executor` instead of the
+    /// publishing task, for handlers which must stay on a GUI main thread.
+    /// `publish` returns once the invocation is scheduled, handler errors go to the
+    /// executor's error callback.
+    ///
+    /// **Note:** `unsubscribe` doesn't wait for jobs which are already scheduled on the executor.
+    ///
+    /// ```no_run
+    /// let executor = Executor::new(|job| {
+    ///     glib::MainContext::default().spawn(job);
+    /// });
+    ///
+    /// let handler_id = event_bus
+    ///     .subscribe_on("redraw", &executor, Box::new(RedrawHandler))
+    ///     .await;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn subscribe_on(
+        &self,
+        event_type: &str,
+        executor: &Executor,
+        handler: Handler<T>,
+    ) -> HandlerId {
+        let handler = OnExecutor {
+            handler: Arc::from(handler),
+            executor: executor.clone(),
+        };
+
+        self.subscribe(event_type, Box::new(handler)).await
+    }
+}
@@ -0,0 +1,57 @@
+use super::Executor;
+use crate::{error::BasuError, event::Event, EventBus, Handle, Handler, HandlerId};
+
+use std::sync::Arc;
+
+/// Handler invocation scheduled on an `Executor`.
+pub type Job = Box<dyn FnOnce() + Send>;
+
+/// Handler whose invocations are routed through an `Executor`.
+struct OnExecutor<T> {
+    handler: Arc<dyn Handle<T>>,
+    executor: Executor,
+}
+
+impl<T: Clone + Send + Sync + 'static> Handle<T> for OnExecutor<T> {
+    fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        let handler = self.handler.clone();
+        let executor = self.executor.clone();
+        let event = event.clone();
+        (self.executor.spawn)(Box::new(move || {
+            executor.report(handler.handle(&event));
+        }));
+
+        Ok(())
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> EventBus<T> {
+    /// Subscribe a handler whose invocations run on the given `Executor` instead of the
+    /// dispatch threads, for handlers which must stay on a GUI main thread.
+    /// `publish` returns once the invocation is scheduled, handler errors go to the
+    /// executor's error callback.
+    ///
+    /// **Note:** `unsubscribe` doesn't wait for jobs which are already scheduled on the executor.
+    ///
+    /// ```no_run
+    /// let executor = Executor::new(|job| {
+    ///     glib::idle_add_once(job);
+    /// });
+    ///
+    /// let handler_id = event_bus.subscribe_on("redraw", &executor, Box::new(RedrawHandler))?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn subscribe_on(
+        &self,
+        event_type: &str,
+        executor: &Executor,
+        handler: Handler<T>,
+    ) -> Result<HandlerId, BasuError> {
+        let handler = OnExecutor {
+            handler: Arc::from(handler),
+            executor: executor.clone(),
+        };
+
+        self.subscribe(event_type, Box::new(handler))
+    }
+}
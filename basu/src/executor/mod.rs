@@ -0,0 +1,44 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+
+#[cfg(feature = "async")]
+pub use impl_async::Job;
+#[cfg(feature = "sync")]
+pub use impl_sync::Job;
+
+use crate::error::BasuError;
+
+use std::sync::Arc;
+
+/// User-provided executor which runs handler invocations on a specific thread,
+/// e.g. the GUI main loop through `glib::idle_add` or a winit event loop proxy.
+#[derive(Clone)]
+pub struct Executor {
+    spawn: Arc<dyn Fn(Job) + Send + Sync>,
+    on_error: Option<Arc<dyn Fn(BasuError) + Send + Sync>>,
+}
+
+impl Executor {
+    /// create a new `Executor` from a callback which schedules jobs on the target thread.
+    pub fn new(spawn: impl Fn(Job) + Send + Sync + 'static) -> Self {
+        Self {
+            spawn: Arc::new(spawn),
+            on_error: None,
+        }
+    }
+
+    /// set a callback which receives the errors of handlers run by the executor,
+    /// since they can't be returned to the publisher.
+    pub fn on_error(mut self, callback: impl Fn(BasuError) + Send + Sync + 'static) -> Self {
+        self.on_error = Some(Arc::new(callback));
+        self
+    }
+
+    fn report(&self, result: Result<(), BasuError>) {
+        if let (Err(err), Some(on_error)) = (result, &self.on_error) {
+            on_error(err);
+        }
+    }
+}
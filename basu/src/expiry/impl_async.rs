@@ -0,0 +1,60 @@
+use super::Deadline;
+use crate::{async_trait, error::BasuError, event::Event, EventBus, Handle, Handler, HandlerId};
+
+use std::sync::Arc;
+
+/// Handler which is skipped once its deadline has passed.
+struct Expiring<T> {
+    handler: Handler<T>,
+    deadline: Deadline,
+}
+
+#[async_trait]
+impl<T: Sync> Handle<T> for Expiring<T> {
+    async fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        if self.deadline.is_expired() {
+            return Ok(());
+        }
+
+        self.handler.handle(event).await
+    }
+}
+
+impl<T: Send + Sync + 'static> EventBus<T> {
+    /// Subscribe a handler which unsubscribes itself at `deadline`, even if no events
+    /// arrive. The deadline is an `Instant` or a `Duration` from now.
+    ///
+    /// ```no_run
+    /// let event_bus = Arc::new(EventBus::<MyEventData>::new());
+    /// let handler_id = event_bus
+    ///     .subscribe_until("my_event", Box::new(MyEventHandler), Duration::from_secs(30))
+    ///     .await;
+    /// ```
+    ///
+    /// **Note:** The handler is removed by a spawned timer task, it is skipped by publishes
+    /// between the deadline and the removal.
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn subscribe_until(
+        self: &Arc<Self>,
+        event_type: &str,
+        handler: Handler<T>,
+        deadline: impl Into<Deadline>,
+    ) -> HandlerId {
+        let deadline = deadline.into();
+        let handler_id = self
+            .subscribe(event_type, Box::new(Expiring { handler, deadline }))
+            .await;
+
+        let event_bus = Arc::downgrade(self);
+        let event_type = event_type.to_owned();
+        let expired_id = handler_id.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep_until(deadline.instant().into()).await;
+            if let Some(event_bus) = event_bus.upgrade() {
+                let _ = event_bus.unsubscribe(&event_type, &expired_id).await;
+            }
+        });
+
+        handler_id
+    }
+}
@@ -0,0 +1,83 @@
+use super::Deadline;
+use crate::{error::BasuError, event::Event, EventBus, Handle, Handler, HandlerId};
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, OnceLock, Weak,
+    },
+    thread,
+};
+
+/// Handler which unsubscribes itself on the first dispatch after its deadline.
+struct Expiring<T> {
+    handler: Handler<T>,
+    deadline: Deadline,
+    event_type: Arc<str>,
+    handler_id: Arc<OnceLock<HandlerId>>,
+    detached: AtomicBool,
+    event_bus: Weak<EventBus<T>>,
+}
+
+impl<T: Send + Sync + 'static> Handle<T> for Expiring<T> {
+    fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        if !self.deadline.is_expired() {
+            return self.handler.handle(event);
+        }
+
+        // a publish holds the bus while invoking the handler, remove it from another thread
+        if let Some(handler_id) = self.handler_id.get() {
+            if !self.detached.swap(true, Ordering::SeqCst) {
+                let event_bus = self.event_bus.clone();
+                let event_type = self.event_type.clone();
+                let handler_id = handler_id.clone();
+                thread::spawn(move || {
+                    if let Some(event_bus) = event_bus.upgrade() {
+                        let _ = event_bus.unsubscribe(&event_type, &handler_id);
+                    }
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Send + Sync + 'static> EventBus<T> {
+    /// Subscribe a handler which expires at `deadline`. The deadline is an `Instant` or a
+    /// `Duration` from now.
+    ///
+    /// ```no_run
+    /// let event_bus = Arc::new(EventBus::<MyEventData>::new());
+    /// let handler_id = event_bus.subscribe_until(
+    ///     "my_event",
+    ///     Box::new(MyEventHandler),
+    ///     Duration::from_secs(30),
+    /// )?;
+    /// ```
+    ///
+    /// **Note:** Expiry is lazy, an expired handler is no longer invoked but is only
+    /// unsubscribed on the next dispatch of its event type.
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn subscribe_until(
+        self: &Arc<Self>,
+        event_type: &str,
+        handler: Handler<T>,
+        deadline: impl Into<Deadline>,
+    ) -> Result<HandlerId, BasuError> {
+        let slot = Arc::new(OnceLock::new());
+        let expiring = Expiring {
+            handler,
+            deadline: deadline.into(),
+            event_type: Arc::from(event_type),
+            handler_id: slot.clone(),
+            detached: AtomicBool::new(false),
+            event_bus: Arc::downgrade(self),
+        };
+
+        let handler_id = self.subscribe(event_type, Box::new(expiring))?;
+        let _ = slot.set(handler_id.clone());
+
+        Ok(handler_id)
+    }
+}
@@ -0,0 +1,35 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+
+use std::time::{Duration, Instant};
+
+/// Point in time after which a subscription made with `subscribe_until` expires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Deadline(Instant);
+
+impl Deadline {
+    /// the instant the subscription expires at.
+    pub fn instant(&self) -> Instant {
+        self.0
+    }
+
+    /// whether the deadline has passed.
+    pub fn is_expired(&self) -> bool {
+        Instant::now() >= self.0
+    }
+}
+
+impl From<Instant> for Deadline {
+    fn from(instant: Instant) -> Self {
+        Self(instant)
+    }
+}
+
+/// A deadline `Duration` from now.
+impl From<Duration> for Deadline {
+    fn from(duration: Duration) -> Self {
+        Self(Instant::now() + duration)
+    }
+}
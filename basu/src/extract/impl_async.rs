@@ -0,0 +1,75 @@
+use super::{Extractor, FromEvent};
+use crate::{
+    async_trait, context, context::Context, error::BasuError, event::Event, Handle, Handler,
+};
+
+use std::future::Future;
+
+/// Function taking up to four `FromEvent` arguments and returning a future, implemented
+/// for async fns and closures like `|Data(order): Data<Order>, State(db): State<Db>| ...`.
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub trait ExtractHandler<T, Args>: Send + Sync + 'static {
+    /// future returned by the function
+    type Future: Future<Output = Result<(), BasuError>> + Send;
+
+    /// extract the arguments and invoke the function.
+    fn call(&self, event: &Event<T>, context: &Context) -> Result<Self::Future, BasuError>;
+}
+
+macro_rules! impl_extract_handler {
+    ($($arg:ident),*) => {
+        impl<T, F, Fut, $($arg,)*> ExtractHandler<T, ($($arg,)*)> for F
+        where
+            F: Fn($($arg),*) -> Fut + Send + Sync + 'static,
+            Fut: Future<Output = Result<(), BasuError>> + Send,
+            $($arg: FromEvent<T>,)*
+        {
+            type Future = Fut;
+
+            #[allow(non_snake_case, unused_variables)]
+            fn call(&self, event: &Event<T>, context: &Context) -> Result<Fut, BasuError> {
+                $(let $arg = $arg::from_event(event, context)?;)*
+                Ok(self($($arg),*))
+            }
+        }
+    };
+}
+
+impl_extract_handler!();
+impl_extract_handler!(A1);
+impl_extract_handler!(A1, A2);
+impl_extract_handler!(A1, A2, A3);
+impl_extract_handler!(A1, A2, A3, A4);
+
+#[async_trait]
+impl<T, F, Args> Handle<T> for Extractor<F, Args>
+where
+    T: Sync,
+    F: ExtractHandler<T, Args>,
+{
+    async fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        let invocation = self.f.call(event, &context::current())?;
+        invocation.await
+    }
+}
+
+/// Box a function whose arguments are extracted from the event and the context into a
+/// `Handler`, so handlers don't need a `Handle` impl of their own.
+/// A failed extraction fails the handler without invoking the function.
+///
+/// ```no_run
+/// async fn ship(Data(order): Data<Order>, State(warehouse): State<Warehouse>) -> Result<(), BasuError> {
+///     warehouse.ship(order.id).await
+/// }
+///
+/// event_bus.subscribe("order.paid", extract_fn(ship)).await;
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub fn extract_fn<T, Args, F>(f: F) -> Handler<T>
+where
+    T: Sync + 'static,
+    Args: 'static,
+    F: ExtractHandler<T, Args>,
+{
+    Box::new(Extractor::new(f))
+}
@@ -0,0 +1,61 @@
+use super::{Extractor, FromEvent};
+use crate::{context, context::Context, error::BasuError, event::Event, Handle, Handler};
+
+/// Function taking up to four `FromEvent` arguments, implemented for fns and closures
+/// like `|Data(order): Data<Order>, State(db): State<Db>| ...`.
+#[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+pub trait ExtractHandler<T, Args>: Send + Sync + 'static {
+    /// extract the arguments and invoke the function.
+    fn call(&self, event: &Event<T>, context: &Context) -> Result<(), BasuError>;
+}
+
+macro_rules! impl_extract_handler {
+    ($($arg:ident),*) => {
+        impl<T, F, $($arg,)*> ExtractHandler<T, ($($arg,)*)> for F
+        where
+            F: Fn($($arg),*) -> Result<(), BasuError> + Send + Sync + 'static,
+            $($arg: FromEvent<T>,)*
+        {
+            #[allow(non_snake_case, unused_variables)]
+            fn call(&self, event: &Event<T>, context: &Context) -> Result<(), BasuError> {
+                $(let $arg = $arg::from_event(event, context)?;)*
+                self($($arg),*)
+            }
+        }
+    };
+}
+
+impl_extract_handler!();
+impl_extract_handler!(A1);
+impl_extract_handler!(A1, A2);
+impl_extract_handler!(A1, A2, A3);
+impl_extract_handler!(A1, A2, A3, A4);
+
+impl<T, F, Args> Handle<T> for Extractor<F, Args>
+where
+    F: ExtractHandler<T, Args>,
+{
+    fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        self.f.call(event, &context::current())
+    }
+}
+
+/// Box a function whose arguments are extracted from the event and the context into a
+/// `Handler`, so handlers don't need a `Handle` impl of their own.
+/// A failed extraction fails the handler without invoking the function.
+///
+/// ```no_run
+/// fn ship(Data(order): Data<Order>, State(warehouse): State<Warehouse>) -> Result<(), BasuError> {
+///     warehouse.ship(order.id)
+/// }
+///
+/// event_bus.subscribe("order.paid", extract_fn(ship))?;
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+pub fn extract_fn<T, Args, F>(f: F) -> Handler<T>
+where
+    Args: 'static,
+    F: ExtractHandler<T, Args>,
+{
+    Box::new(Extractor::new(f))
+}
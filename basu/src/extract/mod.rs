@@ -0,0 +1,80 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+
+#[cfg(feature = "async")]
+pub use impl_async::{extract_fn, ExtractHandler};
+#[cfg(feature = "sync")]
+pub use impl_sync::{extract_fn, ExtractHandler};
+
+use crate::{context::Context, error::BasuError, event::Event};
+
+use std::{
+    any::{self, Any},
+    marker::PhantomData,
+};
+
+/// Argument of a handler function registered with `extract_fn`, extracted from the event
+/// being handled and the context of the handler.
+pub trait FromEvent<T>: Sized {
+    /// extract the argument, the handler isn't invoked when it fails.
+    fn from_event(event: &Event<T>, context: &Context) -> Result<Self, BasuError>;
+}
+
+/// A clone of the event data.
+#[derive(Debug, Clone)]
+pub struct Data<T>(pub T);
+
+impl<T: Clone> FromEvent<T> for Data<T> {
+    fn from_event(event: &Event<T>, _context: &Context) -> Result<Self, BasuError> {
+        Ok(Data(event.data.clone()))
+    }
+}
+
+/// The context of the handler, see `Context`.
+#[derive(Debug, Clone)]
+pub struct Ctx(pub Context);
+
+impl<T> FromEvent<T> for Ctx {
+    fn from_event(_event: &Event<T>, context: &Context) -> Result<Self, BasuError> {
+        Ok(Ctx(context.clone()))
+    }
+}
+
+/// A clone of the value of type `S` in the context of the handler, extraction fails with
+/// `BasuError::MissingState` when the context holds none.
+#[derive(Debug, Clone)]
+pub struct State<S>(pub S);
+
+impl<T, S: Any + Clone> FromEvent<T> for State<S> {
+    fn from_event(_event: &Event<T>, context: &Context) -> Result<Self, BasuError> {
+        context
+            .get::<S>()
+            .cloned()
+            .map(State)
+            .ok_or(BasuError::MissingState(any::type_name::<S>()))
+    }
+}
+
+/// `None` when the argument can't be extracted instead of failing the handler.
+impl<T, E: FromEvent<T>> FromEvent<T> for Option<E> {
+    fn from_event(event: &Event<T>, context: &Context) -> Result<Self, BasuError> {
+        Ok(E::from_event(event, context).ok())
+    }
+}
+
+/// Handler function with extracted arguments, boxed into a `Handler` by `extract_fn`.
+pub(crate) struct Extractor<F, Args> {
+    f: F,
+    args: PhantomData<fn() -> Args>,
+}
+
+impl<F, Args> Extractor<F, Args> {
+    fn new(f: F) -> Self {
+        Self {
+            f,
+            args: PhantomData,
+        }
+    }
+}
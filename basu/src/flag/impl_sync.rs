@@ -0,0 +1,52 @@
+use super::Flags;
+use crate::{error::BasuError, event::Event, EventBus, Handle, Handler, HandlerId};
+
+/// Handler which only runs while its flag is enabled.
+struct Flagged<T> {
+    handler: Handler<T>,
+    topic: String,
+    name: String,
+    flags: Flags,
+}
+
+impl<T> Handle<T> for Flagged<T> {
+    fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        if self.flags.is_enabled(&self.topic, &self.name) {
+            self.handler.handle(event)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<T: Sync + 'static> EventBus<T> {
+    /// Subscribe a handler named `handler_name` which is skipped while `flags` reports it
+    /// disabled for the event type, toggling it without a redeploy.
+    ///
+    /// ```no_run
+    /// let flags = Flags::new(LaunchDarklyFlags::new(client)).with_ttl(Duration::from_secs(5));
+    /// let handler_id = event_bus.subscribe_flagged(
+    ///     "order",
+    ///     "order_handler_v2",
+    ///     &flags,
+    ///     Box::new(OrderHandlerV2),
+    /// )?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn subscribe_flagged(
+        &self,
+        event_type: &str,
+        handler_name: &str,
+        flags: &Flags,
+        handler: Handler<T>,
+    ) -> Result<HandlerId, BasuError> {
+        let handler = Flagged {
+            handler,
+            topic: event_type.to_owned(),
+            name: handler_name.to_owned(),
+            flags: flags.clone(),
+        };
+
+        self.subscribe(event_type, Box::new(handler))
+    }
+}
@@ -0,0 +1,76 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+/// Source of feature flags toggling handlers, e.g. a LaunchDarkly or flagsmith client.
+pub trait FlagProvider: Send + Sync {
+    /// whether the handler named `handler_name` subscribed to `topic` receives events.
+    fn is_enabled(&self, topic: &str, handler_name: &str) -> bool;
+}
+
+type FlagCache = HashMap<(String, String), (Instant, bool)>;
+
+/// `FlagProvider` with its answers cached for a short time, shared by flagged handlers.
+#[derive(Clone)]
+pub struct Flags {
+    provider: Arc<dyn FlagProvider>,
+    ttl: Duration,
+    cache: Arc<Mutex<FlagCache>>,
+}
+
+impl Flags {
+    /// create a new `Flags` caching the answers of `provider` for one second.
+    pub fn new(provider: impl FlagProvider + 'static) -> Self {
+        Self {
+            provider: Arc::new(provider),
+            ttl: Duration::from_secs(1),
+            cache: Default::default(),
+        }
+    }
+
+    /// set how long an answer of the provider is reused.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// whether the handler named `handler_name` subscribed to `topic` is enabled,
+    /// asking the provider when the cached answer expired.
+    pub fn is_enabled(&self, topic: &str, handler_name: &str) -> bool {
+        let key = (topic.to_owned(), handler_name.to_owned());
+        let now = Instant::now();
+        if let Some((at, enabled)) = self
+            .cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&key)
+        {
+            if now.duration_since(*at) < self.ttl {
+                return *enabled;
+            }
+        }
+
+        let enabled = self.provider.is_enabled(topic, handler_name);
+        self.cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key, (now, enabled));
+        enabled
+    }
+}
+
+impl fmt::Debug for Flags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Flags")
+            .field("ttl", &self.ttl)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{
+    config::Settings,
+    dispatch::{DispatchStrategy, Dispatcher, RayonConfig},
+    error::BasuError,
+    EventBus, HandlerMap,
+};
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, MutexGuard, PoisonError, RwLockWriteGuard},
+};
+
+/// Guard returned by `EventBus::prepare_fork`, holding off publishes and reconfigurations
+/// until the fork is done.
+#[must_use = "the bus is blocked until the guard is resumed or dropped"]
+pub struct ForkGuard<'a, T> {
+    _event_handler_map: MutexGuard<'a, HashMap<String, HandlerMap<T>>>,
+    settings: RwLockWriteGuard<'a, Arc<Settings>>,
+}
+
+impl<T> ForkGuard<'_, T> {
+    /// resume the bus in the parent process, whose dispatch threads survived the fork.
+    pub fn after_fork_parent(self) {}
+
+    /// rebuild the dispatch threads of the bus in the child process and resume it.
+    /// The global rayon pool doesn't survive a fork either and can't be rebuilt, so event
+    /// types dispatched with `DispatchStrategy::Rayon` move to a dedicated pool.
+    pub fn after_fork_child(mut self) -> Result<(), BasuError> {
+        let dispatcher = |strategy: &DispatchStrategy| match strategy {
+            DispatchStrategy::Rayon => {
+                Dispatcher::new(&DispatchStrategy::Dedicated(RayonConfig::default()))
+            }
+            strategy => Dispatcher::new(strategy),
+        };
+
+        let config = self.settings.config.clone();
+        *self.settings = Arc::new(Settings {
+            dispatcher: dispatcher(config.dispatch_strategy())?,
+            topic_dispatchers: config
+                .topic_dispatch_strategies()
+                .map(|(event_type, strategy)| Ok((event_type.clone(), dispatcher(strategy)?)))
+                .collect::<Result<_, BasuError>>()?,
+            config,
+        });
+
+        Ok(())
+    }
+}
+
+impl<T> EventBus<T> {
+    /// Prepare the bus for a `fork()`, e.g. in a pre-fork server, since rayon pools don't
+    /// survive a fork. It waits for in-flight publishes and holds off new ones until
+    /// `after_fork_parent` or `after_fork_child` is called on the returned guard.
+    ///
+    /// **Note:** Threads started by `window`, `aggregate`, `join`, `batcher` and `watch_config`
+    /// don't survive in the child either, start them after the fork.
+    ///
+    /// ```no_run
+    /// let guard = event_bus.prepare_fork()?;
+    /// match unsafe { libc::fork() } {
+    ///     0 => guard.after_fork_child()?,
+    ///     _ => guard.after_fork_parent(),
+    /// }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn prepare_fork(&self) -> Result<ForkGuard<'_, T>, BasuError> {
+        let event_handler_map = self
+            .event_handler_map
+            .lock()
+            .map_err(|_| BasuError::MutexPoisoned)?;
+        let settings = self
+            .settings
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        Ok(ForkGuard {
+            _event_handler_map: event_handler_map,
+            settings,
+        })
+    }
+}
@@ -0,0 +1,30 @@
+use super::FrameBuffer;
+use crate::{async_trait, error::BasuError, event::Event, EventBus, Handle, HandlerId};
+
+#[async_trait]
+impl<T: Clone + Send + Sync> Handle<T> for FrameBuffer<T> {
+    async fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        self.push(event);
+        Ok(())
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> EventBus<T> {
+    /// Start buffering the events of an event type until they are drained with
+    /// `take_frame_events`, e.g. once per tick of a game loop.
+    ///
+    /// ```no_run
+    /// event_bus.buffer_frames("collision").await;
+    ///
+    /// loop {
+    ///     for event in event_bus.take_frame_events("collision") {
+    ///         // Handle the event within the frame
+    ///     }
+    /// }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn buffer_frames(&self, event_type: &str) -> HandlerId {
+        self.subscribe(event_type, Box::new(self.frame_buffer(event_type)))
+            .await
+    }
+}
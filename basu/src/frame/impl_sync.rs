@@ -0,0 +1,28 @@
+use super::FrameBuffer;
+use crate::{error::BasuError, event::Event, EventBus, Handle, HandlerId};
+
+impl<T: Clone + Send + Sync> Handle<T> for FrameBuffer<T> {
+    fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        self.push(event);
+        Ok(())
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> EventBus<T> {
+    /// Start buffering the events of an event type until they are drained with
+    /// `take_frame_events`, e.g. once per tick of a game loop.
+    ///
+    /// ```no_run
+    /// event_bus.buffer_frames("collision")?;
+    ///
+    /// loop {
+    ///     for event in event_bus.take_frame_events("collision") {
+    ///         // Handle the event within the frame
+    ///     }
+    /// }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn buffer_frames(&self, event_type: &str) -> Result<HandlerId, BasuError> {
+        self.subscribe(event_type, Box::new(self.frame_buffer(event_type)))
+    }
+}
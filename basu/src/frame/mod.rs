@@ -0,0 +1,52 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+
+use crate::{event::Event, EventBus};
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+/// Frame buffers holding the events published since the last tick, per event type.
+pub type FrameEvents<T> = Arc<Mutex<HashMap<String, Vec<Event<T>>>>>;
+
+/// Handler which copies events into the bus's frame buffer.
+struct FrameBuffer<T> {
+    event_type: String,
+    frame_events: FrameEvents<T>,
+}
+
+impl<T: Clone> FrameBuffer<T> {
+    fn push(&self, event: &Event<T>) {
+        let mut frame_events = self
+            .frame_events
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        frame_events
+            .entry(self.event_type.clone())
+            .or_default()
+            .push(event.clone());
+    }
+}
+
+impl<T> EventBus<T> {
+    /// Drain the events buffered for an event type since the last call.
+    /// It never blocks on dispatch, so it is safe to call from a frame update.
+    pub fn take_frame_events(&self, event_type: &str) -> Vec<Event<T>> {
+        let mut frame_events = self
+            .frame_events
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        frame_events.remove(event_type).unwrap_or_default()
+    }
+
+    fn frame_buffer(&self, event_type: &str) -> FrameBuffer<T> {
+        FrameBuffer {
+            event_type: event_type.to_owned(),
+            frame_events: self.frame_events.clone(),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! Entry points of the parsers fed with untrusted input, for the cargo-fuzz harnesses in
+//! `fuzz/`. They must never panic, whatever the input.
+
+use crate::{config::BusConfig, reload::parse_config, wildcard::PatternIndex};
+
+use std::str;
+
+/// Parse a config file as read by `ConfigWatcher`, applying every setting.
+pub fn config_file(data: &[u8]) {
+    if let Ok(contents) = str::from_utf8(data) {
+        let _ = parse_config(contents);
+    }
+}
+
+/// Apply a single `key=value` setting with `BusConfig::set`. Settings which parse must
+/// survive a round trip through their textual form.
+pub fn config_setting(data: &[u8]) {
+    let Some((key, value)) = str::from_utf8(data)
+        .ok()
+        .and_then(|setting| setting.split_once('='))
+    else {
+        return;
+    };
+
+    let mut config = BusConfig::default();
+    if config.set(key, value).is_err() {
+        return;
+    }
+
+    let mut reparsed = BusConfig::default();
+    for (key, value) in config.entries() {
+        reparsed
+            .set(&key, &value)
+            .expect("textual form of a setting must parse");
+    }
+    assert_eq!(config, reparsed);
+}
+
+/// Index the first line as a subscribed pattern and match the remaining lines against it
+/// as published event types.
+pub fn event_type_pattern(data: &[u8]) {
+    let Ok(input) = str::from_utf8(data) else {
+        return;
+    };
+    let mut lines = input.lines();
+    let Some(pattern) = lines.next() else {
+        return;
+    };
+
+    let mut index = PatternIndex::default();
+    index.insert(pattern);
+    for event_type in lines {
+        let _ = index.matches(event_type);
+    }
+    index.remove(pattern);
+}
@@ -0,0 +1,35 @@
+use crate::EventBus;
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::{Mutex, OnceLock, PoisonError},
+};
+
+type GlobalBusMap = Mutex<HashMap<TypeId, &'static (dyn Any + Send + Sync)>>;
+
+static GLOBAL_BUSES: OnceLock<GlobalBusMap> = OnceLock::new();
+
+/// Get the process-global `EventBus` for event data type `T`.
+/// The bus is created lazily on first access and lives for the rest of the process,
+/// so small applications don't have to pass an `Arc<EventBus<T>>` around.
+///
+/// ```no_run
+/// struct MyEventData {
+///    // Define your event data structure here
+/// }
+///
+/// let event_bus = basu::global::<MyEventData>();
+/// let event_types = event_bus.list();
+/// ```
+pub fn global<T: Send + 'static>() -> &'static EventBus<T> {
+    let buses = GLOBAL_BUSES.get_or_init(Default::default);
+    let mut buses = buses.lock().unwrap_or_else(PoisonError::into_inner);
+
+    let bus = *buses
+        .entry(TypeId::of::<EventBus<T>>())
+        .or_insert_with(|| Box::leak(Box::new(EventBus::<T>::new())));
+
+    bus.downcast_ref::<EventBus<T>>()
+        .expect("global bus is keyed by its own type")
+}
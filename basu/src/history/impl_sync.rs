@@ -0,0 +1,25 @@
+use crate::{error::BasuError, EventBus, Handler, HandlerId};
+
+impl<T: Sync> EventBus<T> {
+    /// Subscribe a handler which first receives the last `replay` events kept in the history
+    /// of the event type, oldest first, then live events. No event is missed or received
+    /// twice in between, publishes wait for the replay to finish.
+    ///
+    /// ```no_run
+    /// let event_bus = EventBus::<MyEventData>::new().with_history(100);
+    ///
+    /// event_bus.subscribe_with_replay("chat", 20, Box::new(ChatView))?;
+    /// ```
+    ///
+    /// **Note:** Nothing is replayed unless history is kept with `with_history` or
+    /// `with_topic_history`, errors of the handler on replayed events are ignored.
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn subscribe_with_replay(
+        &self,
+        event_type: &str,
+        replay: usize,
+        handler: Handler<T>,
+    ) -> Result<HandlerId, BasuError> {
+        self.attach(event_type, replay, handler)
+    }
+}
@@ -0,0 +1,148 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+
+use crate::{
+    event::{Event, SharedEvent},
+    EventBus,
+};
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex, PoisonError},
+};
+
+/// copies a published event into the history, set once the event data is known to be `Clone`.
+type CopyEvent<T> = Box<dyn Fn(&Event<T>) -> SharedEvent<T> + Send + Sync>;
+
+/// Ring buffers of the last events published to each event type.
+pub(crate) struct History<T> {
+    /// capacity of event types without their own, `0` keeps no history
+    capacity: usize,
+    topic_capacities: HashMap<String, usize>,
+    copy_event: Option<CopyEvent<T>>,
+    events: Mutex<HashMap<String, VecDeque<SharedEvent<T>>>>,
+}
+
+impl<T> Default for History<T> {
+    fn default() -> Self {
+        Self {
+            capacity: 0,
+            topic_capacities: HashMap::new(),
+            copy_event: None,
+            events: Default::default(),
+        }
+    }
+}
+
+impl<T> History<T> {
+    fn capacity(&self, event_type: &str) -> usize {
+        self.topic_capacities
+            .get(event_type)
+            .copied()
+            .unwrap_or(self.capacity)
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> EventBus<T> {
+    /// Keep the last `capacity` events published to every event type, so handlers subscribed
+    /// with `subscribe_with_replay` receive recent history before live events.
+    /// Publishes which fail before dispatch, e.g. to an event type nobody subscribed to,
+    /// are not kept.
+    ///
+    /// ```no_run
+    /// let event_bus = EventBus::<MyEventData>::new().with_history(100);
+    /// ```
+    pub fn with_history(mut self, capacity: usize) -> Self {
+        self.history.capacity = capacity;
+        self.keep_history();
+        self
+    }
+
+    /// Keep the last `capacity` events of a single event type, overriding `with_history`.
+    ///
+    /// ```no_run
+    /// let event_bus = EventBus::<MyEventData>::new().with_topic_history("chat", 50);
+    /// ```
+    pub fn with_topic_history(mut self, event_type: &str, capacity: usize) -> Self {
+        self.history
+            .topic_capacities
+            .insert(event_type.to_owned(), capacity);
+        self.keep_history();
+        self
+    }
+
+    /// return the events kept in the history of `event_type`, oldest first.
+    pub fn history(&self, event_type: &str) -> Vec<Event<T>> {
+        self.history
+            .events
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(event_type)
+            .map(|events| events.iter().map(|event| event.event().clone()).collect())
+            .unwrap_or_default()
+    }
+
+    fn keep_history(&mut self) {
+        self.history.copy_event = Some(Box::new(|event| Arc::new(event.clone())));
+    }
+}
+
+impl<T> EventBus<T> {
+    /// event types with a history capacity of their own.
+    pub(crate) fn history_topics(&self) -> impl Iterator<Item = &String> {
+        self.history.topic_capacities.keys()
+    }
+
+    /// forget the history of `event_type`.
+    pub fn clear_history(&self, event_type: &str) {
+        self.history
+            .events
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(event_type);
+    }
+
+    /// add a published event to the history of its event type, dropping the oldest event
+    /// once the history is full.
+    pub(crate) fn record_history(&self, event_type: &str, event: &Event<T>) {
+        let capacity = self.history.capacity(event_type);
+        let Some(copy_event) = self.history.copy_event.as_ref().filter(|_| capacity > 0) else {
+            return;
+        };
+
+        let mut events = self
+            .history
+            .events
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let events = events.entry(event_type.to_owned()).or_default();
+        if events.len() >= capacity {
+            events.drain(..=events.len() - capacity);
+        }
+        events.push_back(copy_event(event));
+    }
+
+    /// return the events a handler subscribing to `event_type` receives before live events:
+    /// the last `replay` events of the history, or else the retained event.
+    pub(crate) fn catch_up(&self, event_type: &str, replay: usize) -> Vec<SharedEvent<T>> {
+        let replayed: Vec<_> = self
+            .history
+            .events
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(event_type)
+            .map(|events| {
+                let skip = events.len().saturating_sub(replay);
+                events.iter().skip(skip).cloned().collect()
+            })
+            .unwrap_or_default();
+
+        if replayed.is_empty() {
+            self.retained_event(event_type).into_iter().collect()
+        } else {
+            replayed
+        }
+    }
+}
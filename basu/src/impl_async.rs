@@ -1,16 +1,293 @@
 use crate::{
-    async_trait, error::BasuError, event::Event, Arc, EventBus, Handler, HandlerId, HashMap, Mutex,
+    async_trait, context,
+    error::{BasuError, ErrorPolicy, PublishError},
+    event::Event,
+    lifecycle::LifecycleEvent,
+    publish::{Published, Publishing},
+    report::{DispatchReport, Dispatched},
+    Arc, EventBus, Handler, HandlerId, HashMap, Mutex,
 };
 
+use futures::{future::join_all, stream::FuturesUnordered, StreamExt};
+use std::{
+    any::Any,
+    future::Future,
+    slice,
+    sync::PoisonError,
+    time::{Duration, Instant},
+};
+
+/// Number of handlers invoked concurrently before yielding back to the runtime.
+pub(crate) const DEFAULT_FANOUT_CHUNK_SIZE: usize = 256;
+
 /// Implement for event handler
 #[async_trait]
 #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
 pub trait Handle<T>: Send + Sync {
     /// Handle event which is published from `EventBus`
     async fn handle(&self, event: &Event<T>) -> Result<(), BasuError>;
+
+    /// Return the handler as `Any`, so it can be downcast by `inspect_handlers`.
+    /// Defaults to `None`, implement it as `Some(self)` to make a handler inspectable.
+    fn as_any(&self) -> Option<&dyn Any> {
+        None
+    }
+}
+
+/// Closures returning a future are handlers too. The future can't borrow the event,
+/// clone what it needs before the `async` block.
+///
+/// ```no_run
+/// event_bus
+///     .subscribe(
+///         "my_event",
+///         Box::new(|event: &Event<MyEventData>| {
+///             let id = event.data.id;
+///             async move {
+///                 println!("received {}", id);
+///                 Ok(())
+///             }
+///         }),
+///     )
+///     .await;
+/// ```
+#[async_trait]
+impl<T, F, Fut> Handle<T> for F
+where
+    T: Sync,
+    F: Fn(&Event<T>) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<(), BasuError>> + Send,
+{
+    async fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        self(event).await
+    }
+}
+
+/// Box a closure returning a future into a `Handler`, see the `Handle` impl for closures.
+///
+/// ```no_run
+/// event_bus
+///     .subscribe(
+///         "my_event",
+///         handler_fn(|event: &Event<MyEventData>| {
+///             let id = event.data.id;
+///             async move {
+///                 println!("received {}", id);
+///                 Ok(())
+///             }
+///         }),
+///     )
+///     .await;
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub fn handler_fn<T, F, Fut>(f: F) -> Handler<T>
+where
+    T: Sync + 'static,
+    F: Fn(&Event<T>) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), BasuError>> + Send + 'static,
+{
+    Box::new(f)
 }
 
 impl<T> EventBus<T> {
+    /// set how many handlers a publish invokes concurrently before yielding to the runtime.
+    /// Topics with thousands of handlers are dispatched chunk by chunk with a `yield_now`
+    /// in between, so a giant fan-out doesn't starve unrelated tasks. Defaults to 256.
+    ///
+    /// ```no_run
+    /// let event_bus = EventBus::<MyEventData>::new().with_fanout_chunk_size(64);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub fn with_fanout_chunk_size(self, chunk_size: usize) -> Self {
+        self.configured(|config| config.set_fanout_chunk_size(chunk_size))
+    }
+
+    /// set how long every handler invocation may take before it's abandoned and fails with
+    /// `BasuError::HandlerTimeout`, so one stalled handler doesn't hold up the publish.
+    /// Use `HandlerExt::with_timeout` to limit a single subscription instead.
+    ///
+    /// ```no_run
+    /// let event_bus = EventBus::<MyEventData>::new().with_handler_timeout(Duration::from_secs(5));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub fn with_handler_timeout(self, timeout: Duration) -> Self {
+        self.configured(|config| config.set_handler_timeout(Some(timeout)))
+    }
+
+    /// Invoke `f` with every handler, one chunk of concurrent invocations at a time or one
+    /// handler after another when `inline`, and return the failures and skips.
+    /// Priority groups are dispatched one after another, highest priority first.
+    async fn dispatch_with<'a, F, Fut>(
+        &self,
+        event_type: &str,
+        handlers: &[(&'a HandlerId, &'a Handler<T>)],
+        inline: bool,
+        f: F,
+    ) -> Dispatched
+    where
+        F: Fn(&'a Handler<T>) -> Fut,
+        Fut: Future<Output = Result<(), BasuError>>,
+    {
+        let settings = self.settings();
+        let watch = self.slow_watch(event_type, &settings.config);
+        let chunk_size = if inline {
+            1
+        } else {
+            settings.config.fanout_chunk_size()
+        };
+        let timeout = settings.config.handler_timeout();
+        let circuits = self.circuit_watch();
+        let context = context::current();
+        let fail_fast = settings.config.error_policy() == ErrorPolicy::FailFast;
+        let groups = self.priority_groups(handlers);
+        let mut dispatched = Dispatched::default();
+        let chunks = groups.iter().flat_map(|group| group.chunks(chunk_size));
+        for (i, chunk) in chunks.enumerate() {
+            if fail_fast && !dispatched.failed.is_empty() {
+                dispatched.skipped += chunk.len();
+                continue;
+            }
+            if i > 0 && !inline {
+                tokio::task::yield_now().await;
+            }
+            let invocations = chunk.iter().map(|(id, h)| {
+                // each handler runs with its own copy of the publisher's context
+                let invocation = context.clone().scope(async { f(h).await });
+                let watch = watch.as_ref();
+                let circuits = circuits.as_ref();
+                async move {
+                    if circuits.is_some_and(|circuits| !circuits.allows(id)) {
+                        return (*id, None);
+                    }
+
+                    let started = Instant::now();
+                    let result = match timeout {
+                        Some(timeout) => tokio::time::timeout(timeout, invocation)
+                            .await
+                            .unwrap_or(Err(BasuError::HandlerTimeout)),
+                        None => invocation.await,
+                    };
+                    if let Some(watch) = watch {
+                        watch.observe(id, started.elapsed());
+                    }
+                    if let Some(circuits) = circuits {
+                        circuits.record(id, result.is_ok());
+                    }
+                    (*id, Some(result))
+                }
+            });
+
+            if fail_fast {
+                // dropping the invocations still running cancels them
+                let mut invocations: FuturesUnordered<_> = invocations.collect();
+                while let Some((id, outcome)) = invocations.next().await {
+                    let failed = matches!(outcome, Some(Err(_)));
+                    dispatched.record(id, outcome);
+                    if failed {
+                        break;
+                    }
+                }
+                dispatched.skipped += invocations.len();
+            } else {
+                for (id, outcome) in join_all(invocations).await {
+                    dispatched.record(id, outcome);
+                }
+            }
+        }
+
+        dispatched.failed = self.with_owners(dispatched.failed);
+        dispatched
+    }
+
+    /// Admit and record the events of `make_events`, then dispatch them to the subscribers
+    /// of the event type as `publishing` asks, all under the bus lock.
+    /// Every publish path goes through here, so the publish hooks apply to all of them.
+    pub(crate) async fn publish_with<E: AsRef<[Event<T>]>>(
+        &self,
+        event_type: &str,
+        make_events: impl FnOnce() -> E,
+        publishing: Publishing<T>,
+    ) -> Result<Published, PublishError> {
+        let event_handler_map = self.event_handler_map.lock().await;
+        let subscribers = match self.subscribers(&event_handler_map, event_type).await {
+            // lazy events are only built for event types with subscribers
+            Ok(subscribers) if publishing.lazy && subscribers.is_empty() => {
+                return Ok(Published::default())
+            }
+            Err(error) if publishing.lazy => return Err(error),
+            subscribers => subscribers,
+        };
+
+        let events = make_events();
+        let events = events.as_ref();
+        events.iter().try_for_each(|event| self.admit(event))?;
+        // retained under the bus lock, so handlers subscribing meanwhile receive it once
+        let replaced = publishing
+            .retain
+            .as_ref()
+            .map(|event| self.retain(event_type, event.clone()));
+        // watchers see the event even when the event type has no subscribers
+        for event_data in events {
+            self.record_latest(event_type, event_data);
+        }
+
+        let subscribers = match subscribers {
+            Ok(subscribers) => subscribers,
+            // the retained event is kept for later subscribers
+            Err(PublishError::EventTypeNotFound) if replaced.is_some() => {
+                return Ok(Published::default())
+            }
+            Err(error) => {
+                if let (Some(event), Some(previous)) = (&publishing.retain, replaced) {
+                    self.unretain(event_type, event, previous);
+                }
+                return Err(error);
+            }
+        };
+        for event_data in events {
+            self.record_history(event_type, event_data);
+        }
+
+        if let Some(replies) = publishing.replies {
+            self.replies.open(replies);
+        }
+        let deadline = publishing.deadline;
+        let dispatched = self
+            .dispatch_with(
+                event_type,
+                &subscribers.handlers(),
+                publishing.inline,
+                |h| async move {
+                    let handle = async {
+                        for event_data in events {
+                            h.handle(event_data).await?;
+                        }
+                        Ok(())
+                    };
+                    match deadline {
+                        Some(deadline) => tokio::time::timeout_at(deadline.into(), handle)
+                            .await
+                            .unwrap_or(Err(BasuError::HandlerTimeout)),
+                        None => handle.await,
+                    }
+                },
+            )
+            .await;
+
+        let published = Published {
+            subscribers: subscribers.len(),
+            dispatched,
+            policy: self.settings().config.error_policy(),
+            replies: self.replies.close(),
+        };
+        if let (Some(event), Some(previous)) = (&publishing.retain, replaced) {
+            if published.fails() {
+                self.unretain(event_type, event, previous);
+            }
+        }
+        Ok(published)
+    }
+
     /// Subscribe to an event type.
     /// It takes the event type as a string and a handler implementing the `Handle<T>` trait.
     /// The method returns a `HandlerId` that uniquely identifies the handler within the event bus.
@@ -39,9 +316,25 @@ impl<T> EventBus<T> {
     /// ```
     #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
     pub async fn subscribe(&self, event_type: &str, handler: Handler<T>) -> HandlerId {
+        self.attach(event_type, 0, handler).await
+    }
+
+    /// Subscribe a handler after delivering the events it catches up on, the retained event
+    /// or the last `replay` events of the history.
+    pub(crate) async fn attach(
+        &self,
+        event_type: &str,
+        replay: usize,
+        handler: Handler<T>,
+    ) -> HandlerId {
         let mut event_handler_map = self.event_handler_map.lock().await;
 
-        match event_handler_map.get(event_type) {
+        for event in self.catch_up(event_type, replay) {
+            // a failed delivery doesn't prevent the subscription
+            let _ = handler.handle(event.event()).await;
+        }
+
+        let handler_id = match event_handler_map.get(event_type) {
             Some(handler_map) => {
                 let mut handler_map = handler_map.lock().await;
                 let handler_id = HandlerId::new();
@@ -55,14 +348,25 @@ impl<T> EventBus<T> {
                 handler_map.insert(handler_id.clone(), handler);
 
                 event_handler_map.insert(event_type.to_owned(), Arc::new(Mutex::new(handler_map)));
+                self.index_event_type(event_type);
 
                 handler_id
             }
-        }
+        };
+        drop(event_handler_map);
+
+        self.emit_lifecycle(LifecycleEvent::Attached {
+            event_type: event_type.to_owned(),
+            handler_id: handler_id.clone(),
+        });
+        handler_id
     }
 
     /// Unsubscribe handler from an event type.
     /// It takes the event type and the `HandlerId` of the handler to be removed.
+    /// It waits for a publish which is invoking the handler to finish, so once it returns
+    /// the handler has been dropped and won't be invoked again. Only an invocation which a
+    /// `sandboxed` handler abandoned after its time limit may still be running.
     ///
     /// ```no_run
     /// struct MyEventData {
@@ -94,16 +398,23 @@ impl<T> EventBus<T> {
     ) -> Result<(), BasuError> {
         let event_handler_map = self.event_handler_map.lock().await;
 
-        match event_handler_map.get(event_type) {
+        let removed = match event_handler_map.get(event_type) {
             Some(handler_map) => {
                 let mut handler_map = handler_map.lock().await;
-                handler_map.remove(handler_id);
-
-                Ok(())
+                handler_map.remove(handler_id).is_some()
             }
 
-            None => Err(BasuError::EventTypeNotFOUND),
+            None => return Err(BasuError::EventTypeNotFOUND),
+        };
+        drop(event_handler_map);
+
+        if removed {
+            self.emit_lifecycle(LifecycleEvent::Detached {
+                event_type: event_type.to_owned(),
+                handler_id: handler_id.clone(),
+            });
         }
+        Ok(())
     }
 
     /// Publish an event to subscribed handlers,
@@ -134,20 +445,165 @@ impl<T> EventBus<T> {
     /// event_bus.publish("my_event", &event).await?;
     /// ```
     #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
-    pub async fn publish(&self, event_type: &str, event_data: &Event<T>) -> Result<(), BasuError> {
+    pub async fn publish(
+        &self,
+        event_type: &str,
+        event_data: &Event<T>,
+    ) -> Result<(), PublishError> {
+        self.publish_with(
+            event_type,
+            || slice::from_ref(event_data),
+            Publishing::default(),
+        )
+        .await?
+        .check()
+    }
+
+    /// Publish an event and invoke the handlers one after another on the calling task,
+    /// without concurrent fan-out, for publishers which need the side effects of every
+    /// handler completed in order before proceeding.
+    ///
+    /// ```no_run
+    /// event_bus.publish_inline("cache.invalidate", &event).await?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn publish_inline(
+        &self,
+        event_type: &str,
+        event_data: &Event<T>,
+    ) -> Result<(), PublishError> {
+        self.publish_with(
+            event_type,
+            || slice::from_ref(event_data),
+            Publishing {
+                inline: true,
+                ..Default::default()
+            },
+        )
+        .await?
+        .check()
+    }
+
+    /// Publish an event built by `make_event` only when the event type has subscribers,
+    /// saving the allocation or serialization of payloads nobody listens to.
+    /// The event is built and dispatched under the bus lock, so handlers subscribed
+    /// meanwhile can't be missed.
+    ///
+    /// ```no_run
+    /// event_bus
+    ///     .publish_lazy("my_event", || Event::new(build_report()))
+    ///     .await?;
+    /// ```
+    ///
+    /// **Note:** Handlers skipping events internally, e.g. filtered handlers, still count
+    /// as subscribers.
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn publish_lazy(
+        &self,
+        event_type: &str,
+        make_event: impl FnOnce() -> Event<T>,
+    ) -> Result<(), PublishError> {
+        self.publish_with(
+            event_type,
+            || [make_event()],
+            Publishing {
+                lazy: true,
+                ..Default::default()
+            },
+        )
+        .await?
+        .check()
+    }
+
+    /// Publish a batch of events to subscribed handlers in one dispatch.
+    /// Each handler receives the events of the batch in order.
+    ///
+    /// ```no_run
+    /// let events = vec![Event::new(sample_a), Event::new(sample_b)];
+    ///
+    /// event_bus.publish_batch("metrics", &events).await?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn publish_batch(
+        &self,
+        event_type: &str,
+        events: &[Event<T>],
+    ) -> Result<(), PublishError> {
+        self.publish_with(event_type, || events, Publishing::default())
+            .await?
+            .check()
+    }
+
+    /// Publish an event and return a `DispatchReport` of the fan-out, so callers can log
+    /// how many handlers received the event and which of them failed.
+    /// Handler errors are part of the report, only errors before dispatch are returned.
+    ///
+    /// ```no_run
+    /// let report = event_bus.publish_report("my_event", &event).await?;
+    ///
+    /// for failure in &report.failed {
+    ///     eprintln!("handler {:?} failed: {}", failure.handler_id, failure.error);
+    /// }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn publish_report(
+        &self,
+        event_type: &str,
+        event_data: &Event<T>,
+    ) -> Result<DispatchReport, PublishError> {
+        let started = Instant::now();
+        let Published {
+            subscribers,
+            dispatched,
+            ..
+        } = self
+            .publish_with(
+                event_type,
+                || slice::from_ref(event_data),
+                Publishing::default(),
+            )
+            .await?;
+        Ok(DispatchReport {
+            delivered: dispatched.delivered(subscribers),
+            failed: dispatched.failed,
+            skipped: dispatched.skipped,
+            duration: started.elapsed(),
+        })
+    }
+
+    /// Prepare an event for publishing without dispatching it yet.
+    /// It checks that the event type has at least one subscribed handler and returns a
+    /// `Prepared` publish which is dispatched on `commit` or discarded on `abort`.
+    ///
+    /// ```no_run
+    /// let event = Event::new(MyEventData { /* initialize your event data */ });
+    /// let prepared = event_bus.publish_prepared("my_event", event).await?;
+    ///
+    /// match db_transaction.commit().await {
+    ///     Ok(_) => prepared.commit().await?,
+    ///     Err(_) => prepared.abort(),
+    /// }
+    /// ```
+    ///
+    /// **Note:** Handlers subscribed between prepare and commit also receive the event.
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn publish_prepared(
+        &self,
+        event_type: &str,
+        event: Event<T>,
+    ) -> Result<Prepared<'_, T>, PublishError> {
         let event_handler_map = self.event_handler_map.lock().await;
 
-        match event_handler_map.get(event_type) {
-            Some(handler_map) => {
-                let handler_map = handler_map.lock().await;
-                let futures = handler_map.iter().map(|(_id, h)| h.handle(event_data));
-                futures::future::try_join_all(futures)
-                    .await
-                    .map(|_| ())
-                    .map_err(Into::into)
-            }
-            None => Err(BasuError::EventTypeNotFOUND),
+        let subscribers = self.subscribers(&event_handler_map, event_type).await?;
+        if subscribers.is_empty() {
+            return Err(PublishError::NoSubscribers);
         }
+
+        Ok(Prepared {
+            event_bus: self,
+            event_type: event_type.to_owned(),
+            event,
+        })
     }
 
     /// List all registered event types.
@@ -185,6 +641,27 @@ impl<T> EventBus<T> {
         event_handler_map.keys().cloned().collect()
     }
 
+    /// Pre-create the entries of latency-critical event types, so the first subscribe and
+    /// publish don't pay for allocating them.
+    ///
+    /// ```no_run
+    /// event_bus.warmup(&["order.placed", "order.filled"]).await;
+    /// ```
+    ///
+    /// **Note:** Warmed event types are listed by `list` and publishing to them succeeds
+    /// even before a handler subscribes.
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn warmup(&self, event_types: &[&str]) {
+        let mut event_handler_map = self.event_handler_map.lock().await;
+
+        for event_type in event_types {
+            if !event_handler_map.contains_key(*event_type) {
+                event_handler_map.insert((*event_type).to_owned(), Default::default());
+                self.index_event_type(event_type);
+            }
+        }
+    }
+
     /// Get the number of registered handlers for a specific event type.
     ///
     /// ```no_run
@@ -212,6 +689,83 @@ impl<T> EventBus<T> {
         }
     }
 
+    /// Check whether an event type has at least one subscribed handler, wildcard handlers
+    /// included, so publishers can skip building expensive payloads when nobody is listening.
+    ///
+    /// ```no_run
+    /// if event_bus.has_subscribers("my_event").await {
+    ///     event_bus.publish("my_event", &Event::new(build_report())).await?;
+    /// }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn has_subscribers(&self, event_type: &str) -> bool {
+        let event_handler_map = self.event_handler_map.lock().await;
+
+        let subscribers = self.subscribers(&event_handler_map, event_type).await;
+        subscribers.is_ok_and(|subscribers| !subscribers.is_empty())
+    }
+
+    /// Check whether a handler is subscribed to an event type.
+    ///
+    /// ```no_run
+    /// let handler_id = event_bus.subscribe("my_event", Box::new(MyEventHandler)).await;
+    ///
+    /// assert!(event_bus.contains_handler("my_event", &handler_id).await);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn contains_handler(&self, event_type: &str, handler_id: &HandlerId) -> bool {
+        let event_handler_map = self.event_handler_map.lock().await;
+
+        match event_handler_map.get(event_type) {
+            Some(handler_map) => handler_map.lock().await.contains_key(handler_id),
+            None => false,
+        }
+    }
+
+    /// Wait until every publish to `event_type` started before the call has been handled,
+    /// e.g. to make sure all writes landed before taking a snapshot.
+    ///
+    /// ```no_run
+    /// tokio::spawn(async move { event_bus.publish("write", &event).await });
+    /// // ...
+    /// event_bus.flush("write").await;
+    /// ```
+    ///
+    /// **Note:** Events buffered outside the bus, by a `Batcher`, an `EventStream` or an
+    /// `Executor`, are not waited for.
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn flush(&self, event_type: &str) {
+        // a publish holds the bus and the handler maps until its handlers returned
+        let event_handler_map = self.event_handler_map.lock().await;
+        if let Some(handler_map) = event_handler_map.get(event_type) {
+            drop(handler_map.lock().await);
+        }
+    }
+
+    /// Call `f` with the event type, id and handler of every subscribed handler of type `H`,
+    /// e.g. to find a metrics handler and flush it. Only handlers implementing
+    /// `Handle::as_any` are visited.
+    ///
+    /// ```no_run
+    /// event_bus
+    ///     .inspect_handlers(|_event_type, _handler_id, metrics: &MetricsHandler| metrics.flush())
+    ///     .await;
+    /// ```
+    ///
+    /// **Note:** `f` runs while the bus is locked, it must not subscribe or publish.
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn inspect_handlers<H: 'static>(&self, mut f: impl FnMut(&str, &HandlerId, &H)) {
+        let event_handler_map = self.event_handler_map.lock().await;
+
+        for (event_type, handler_map) in event_handler_map.iter() {
+            for (handler_id, handler) in handler_map.lock().await.iter() {
+                if let Some(handler) = handler.as_any().and_then(|h| h.downcast_ref::<H>()) {
+                    f(event_type, handler_id, handler);
+                }
+            }
+        }
+    }
+
     /// Clear all event handlers from the event bus.
     /// It removes all registered event handlers.
     ///
@@ -245,6 +799,93 @@ impl<T> EventBus<T> {
     pub async fn clear(&self) {
         let mut event_handler_map = self.event_handler_map.lock().await;
 
-        event_handler_map.clear();
+        let mut detached = Vec::new();
+        self.clear_index();
+        for (event_type, handler_map) in event_handler_map.drain() {
+            let handler_map = handler_map.lock().await;
+            detached.extend(
+                handler_map
+                    .keys()
+                    .map(|handler_id| LifecycleEvent::Detached {
+                        event_type: event_type.clone(),
+                        handler_id: handler_id.clone(),
+                    }),
+            );
+        }
+        drop(event_handler_map);
+
+        for event in detached {
+            self.emit_lifecycle(event);
+        }
     }
+
+    /// Clear the handlers of a single event type instead of the whole bus.
+    /// It removes the event type with its handlers and the events buffered for it by
+    /// `buffer_frames`, and returns how many handlers were removed.
+    ///
+    /// ```no_run
+    /// let removed = event_bus.clear_event_type("my_event").await?;
+    ///
+    /// println!("{} handlers cleared", removed);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn clear_event_type(&self, event_type: &str) -> Result<usize, BasuError> {
+        let mut event_handler_map = self.event_handler_map.lock().await;
+
+        let handler_map = event_handler_map
+            .remove(event_type)
+            .ok_or(BasuError::EventTypeNotFOUND)?;
+        self.unindex_event_type(event_type);
+        let handler_map = handler_map.lock().await;
+        let detached: Vec<_> = handler_map
+            .keys()
+            .map(|handler_id| LifecycleEvent::Detached {
+                event_type: event_type.to_owned(),
+                handler_id: handler_id.clone(),
+            })
+            .collect();
+        drop(handler_map);
+        drop(event_handler_map);
+
+        self.frame_events
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(event_type);
+
+        let removed = detached.len();
+        for event in detached {
+            self.emit_lifecycle(event);
+        }
+        Ok(removed)
+    }
+}
+
+/// An event which has been validated for publishing but not dispatched yet.
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub struct Prepared<'a, T> {
+    event_bus: &'a EventBus<T>,
+    event_type: String,
+    event: Event<T>,
+}
+
+impl<T> Prepared<'_, T> {
+    /// return the event type the event will be published to.
+    pub fn event_type(&self) -> &str {
+        &self.event_type
+    }
+
+    /// return the prepared event.
+    pub fn event(&self) -> &Event<T> {
+        &self.event
+    }
+
+    /// Dispatch the prepared event to subscribed handlers.
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn commit(self) -> Result<(), PublishError> {
+        self.event_bus.publish(&self.event_type, &self.event).await
+    }
+
+    /// Discard the prepared event without dispatching it.
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub fn abort(self) {}
 }
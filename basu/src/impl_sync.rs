@@ -1,15 +1,211 @@
-use crate::{error::BasuError, event::Event, Arc, EventBus, Handler, HandlerId, HashMap, Mutex};
-#[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
-use rayon::prelude::*;
+use crate::{
+    context,
+    dispatch::Dispatcher,
+    error::{BasuError, ErrorPolicy, PublishError},
+    event::Event,
+    lifecycle::LifecycleEvent,
+    publish::{Published, Publishing},
+    reentrancy::HandlerTag,
+    report::{DispatchReport, Dispatched},
+    Arc, EventBus, Handler, HandlerId, HashMap, Mutex,
+};
+
+use std::{
+    any::Any,
+    slice,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        PoisonError,
+    },
+    time::Instant,
+};
 
 /// Implement for event handler
 #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
 pub trait Handle<T>: Send + Sync {
     /// Handle event which is published from `EventBus`
     fn handle(&self, event: &Event<T>) -> Result<(), BasuError>;
+
+    /// Return the handler as `Any`, so it can be downcast by `inspect_handlers`.
+    /// Defaults to `None`, implement it as `Some(self)` to make a handler inspectable.
+    fn as_any(&self) -> Option<&dyn Any> {
+        None
+    }
+}
+
+/// Closures are handlers too.
+///
+/// ```no_run
+/// event_bus.subscribe(
+///     "my_event",
+///     Box::new(|event: &Event<MyEventData>| {
+///         println!("received {}", event.data.id);
+///         Ok(())
+///     }),
+/// )?;
+/// ```
+impl<T, F> Handle<T> for F
+where
+    F: Fn(&Event<T>) -> Result<(), BasuError> + Send + Sync,
+{
+    fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        self(event)
+    }
+}
+
+/// Box a closure into a `Handler`, see the `Handle` impl for closures.
+///
+/// ```no_run
+/// event_bus.subscribe(
+///     "my_event",
+///     handler_fn(|event: &Event<MyEventData>| {
+///         println!("received {}", event.data.id);
+///         Ok(())
+///     }),
+/// )?;
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+pub fn handler_fn<T, F>(f: F) -> Handler<T>
+where
+    F: Fn(&Event<T>) -> Result<(), BasuError> + Send + Sync + 'static,
+{
+    Box::new(f)
 }
 
 impl<T: Sync> EventBus<T> {
+    /// Invoke `f` with every handler using `dispatcher` and return the failures and skips.
+    /// Priority groups are dispatched one after another, highest priority first.
+    fn dispatch_with<F>(
+        &self,
+        dispatcher: &Dispatcher,
+        event_type: &str,
+        handlers: &[(&HandlerId, &Handler<T>)],
+        f: F,
+    ) -> Dispatched
+    where
+        F: Fn(&Handler<T>) -> Result<(), BasuError> + Sync,
+    {
+        let settings = self.settings();
+        let watch = self.slow_watch(event_type, &settings.config);
+        let key = self.reentrancy_key();
+        let circuits = self.circuit_watch();
+        let fail_fast = settings.config.error_policy() == ErrorPolicy::FailFast;
+        let aborted = AtomicBool::new(false);
+        // each handler runs with its own copy of the publisher's context
+        let context = context::current();
+        let mut dispatched = Dispatched::default();
+        for group in self.priority_groups(handlers).iter() {
+            let Dispatched { failed, skipped } =
+                dispatcher.dispatch(group, watch.as_ref(), |id, h| {
+                    if circuits
+                        .as_ref()
+                        .is_some_and(|circuits| !circuits.allows(id))
+                        || aborted.load(Ordering::Relaxed)
+                    {
+                        return None;
+                    }
+
+                    let _tag = key.map(|key| HandlerTag::enter(key, event_type));
+                    let result = context.clone().scope(|| f(h));
+                    if let Some(circuits) = &circuits {
+                        circuits.record(id, result.is_ok());
+                    }
+                    // handlers already running on other threads still finish
+                    if fail_fast && result.is_err() {
+                        aborted.store(true, Ordering::Relaxed);
+                    }
+                    Some(result)
+                });
+            dispatched.failed.extend(failed);
+            dispatched.skipped += skipped;
+        }
+        dispatched.failed = self.with_owners(dispatched.failed);
+        dispatched
+    }
+
+    /// Admit and record the events of `make_events`, then dispatch them to the subscribers
+    /// of the event type as `publishing` asks, all under the bus lock.
+    /// Every publish path goes through here, so the publish hooks apply to all of them.
+    pub(crate) fn publish_with<E: AsRef<[Event<T>]>>(
+        &self,
+        event_type: &str,
+        make_events: impl FnOnce() -> E,
+        publishing: Publishing<T>,
+    ) -> Result<Published, PublishError> {
+        self.check_reentrancy(event_type)?;
+
+        let event_handler_map = self
+            .event_handler_map
+            .lock()
+            .map_err(|_| PublishError::MutexPoisoned)?;
+        let subscribers = match self.subscribers(&event_handler_map, event_type) {
+            // lazy events are only built for event types with subscribers
+            Ok(subscribers) if publishing.lazy && subscribers.is_empty() => {
+                return Ok(Published::default())
+            }
+            Err(error) if publishing.lazy => return Err(error),
+            subscribers => subscribers,
+        };
+
+        let events = make_events();
+        let events = events.as_ref();
+        events.iter().try_for_each(|event| self.admit(event))?;
+        // retained under the bus lock, so handlers subscribing meanwhile receive it once
+        let replaced = publishing
+            .retain
+            .as_ref()
+            .map(|event| self.retain(event_type, event.clone()));
+
+        let subscribers = match subscribers {
+            Ok(subscribers) => subscribers,
+            // the retained event is kept for later subscribers
+            Err(PublishError::EventTypeNotFound) if replaced.is_some() => {
+                return Ok(Published::default())
+            }
+            Err(error) => {
+                if let (Some(event), Some(previous)) = (&publishing.retain, replaced) {
+                    self.unretain(event_type, event, previous);
+                }
+                return Err(error);
+            }
+        };
+        for event_data in events {
+            self.record_history(event_type, event_data);
+        }
+
+        let dispatcher = if publishing.inline {
+            Dispatcher::Inline
+        } else {
+            self.dispatcher(event_type)
+        };
+        if let Some(replies) = publishing.replies {
+            self.replies.open(replies);
+        }
+        let dispatched =
+            self.dispatch_with(&dispatcher, event_type, &subscribers.handlers(), |h| {
+                let result = events
+                    .iter()
+                    .try_for_each(|event_data| h.handle(event_data));
+                match publishing.deadline {
+                    Some(deadline) if Instant::now() > deadline => Err(BasuError::HandlerTimeout),
+                    _ => result,
+                }
+            });
+
+        let published = Published {
+            subscribers: subscribers.len(),
+            dispatched,
+            policy: self.settings().config.error_policy(),
+            replies: self.replies.close(),
+        };
+        if let (Some(event), Some(previous)) = (&publishing.retain, replaced) {
+            if published.fails() {
+                self.unretain(event_type, event, previous);
+            }
+        }
+        Ok(published)
+    }
+
     /// Subscribe to an event type.
     /// It takes the event type as a string and a handler implementing the `Handle<T>` trait.
     /// The method returns a `HandlerId` that uniquely identifies the handler within the event bus.
@@ -37,18 +233,34 @@ impl<T: Sync> EventBus<T> {
     /// ```
     #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
     pub fn subscribe(&self, event_type: &str, handler: Handler<T>) -> Result<HandlerId, BasuError> {
+        self.attach(event_type, 0, handler)
+    }
+
+    /// Subscribe a handler after delivering the events it catches up on, the retained event
+    /// or the last `replay` events of the history.
+    pub(crate) fn attach(
+        &self,
+        event_type: &str,
+        replay: usize,
+        handler: Handler<T>,
+    ) -> Result<HandlerId, BasuError> {
         let mut event_handler_map = self
             .event_handler_map
             .lock()
             .map_err(|_| BasuError::MutexPoisoned)?;
 
-        match event_handler_map.get(event_type) {
+        for event in self.catch_up(event_type, replay) {
+            // a failed delivery doesn't prevent the subscription
+            let _ = handler.handle(event.event());
+        }
+
+        let handler_id = match event_handler_map.get(event_type) {
             Some(handler_map) => {
                 let mut handler_map = handler_map.lock().map_err(|_| BasuError::MutexPoisoned)?;
                 let handler_id = HandlerId::new();
                 handler_map.insert(handler_id.clone(), handler);
 
-                Ok(handler_id)
+                handler_id
             }
             None => {
                 let mut handler_map = HashMap::new();
@@ -56,14 +268,25 @@ impl<T: Sync> EventBus<T> {
                 handler_map.insert(handler_id.clone(), handler);
 
                 event_handler_map.insert(event_type.to_owned(), Arc::new(Mutex::new(handler_map)));
+                self.index_event_type(event_type);
 
-                Ok(handler_id)
+                handler_id
             }
-        }
+        };
+        drop(event_handler_map);
+
+        self.emit_lifecycle(LifecycleEvent::Attached {
+            event_type: event_type.to_owned(),
+            handler_id: handler_id.clone(),
+        });
+        Ok(handler_id)
     }
 
     /// Unsubscribe handler from an event type.
     /// It takes the event type and the `HandlerId` of the handler to be removed.
+    /// It waits for a publish which is invoking the handler to finish, so once it returns
+    /// the handler has been dropped and won't be invoked again. Only an invocation which a
+    /// `sandboxed` handler abandoned after its time limit may still be running.
     ///
     /// ```no_run
     /// struct MyEventData {
@@ -93,16 +316,23 @@ impl<T: Sync> EventBus<T> {
             .lock()
             .map_err(|_| BasuError::MutexPoisoned)?;
 
-        match event_handler_map.get(event_type) {
+        let removed = match event_handler_map.get(event_type) {
             Some(handler_map) => {
                 let mut handler_map = handler_map.lock().map_err(|_| BasuError::MutexPoisoned)?;
-                handler_map.remove(handler_id);
-
-                Ok(())
+                handler_map.remove(handler_id).is_some()
             }
 
-            None => Err(BasuError::EventTypeNotFOUND),
+            None => return Err(BasuError::EventTypeNotFOUND),
+        };
+        drop(event_handler_map);
+
+        if removed {
+            self.emit_lifecycle(LifecycleEvent::Detached {
+                event_type: event_type.to_owned(),
+                handler_id: handler_id.clone(),
+            });
         }
+        Ok(())
     }
 
     /// Publish an event to subscribed handlers,
@@ -132,22 +362,154 @@ impl<T: Sync> EventBus<T> {
     /// event_bus.publish("my_event", &event)?;
     /// ```
     #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
-    pub fn publish(&self, event_type: &str, event_data: &Event<T>) -> Result<(), BasuError> {
+    pub fn publish(&self, event_type: &str, event_data: &Event<T>) -> Result<(), PublishError> {
+        self.publish_with(
+            event_type,
+            || slice::from_ref(event_data),
+            Publishing::default(),
+        )?
+        .check()
+    }
+
+    /// Publish an event and invoke the handlers on the calling thread, one after another,
+    /// whatever the dispatch strategy of the event type, for publishers which need the
+    /// side effects of every handler completed before proceeding.
+    ///
+    /// ```no_run
+    /// event_bus.publish_inline("cache.invalidate", &event)?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn publish_inline(
+        &self,
+        event_type: &str,
+        event_data: &Event<T>,
+    ) -> Result<(), PublishError> {
+        self.publish_with(
+            event_type,
+            || slice::from_ref(event_data),
+            Publishing {
+                inline: true,
+                ..Default::default()
+            },
+        )?
+        .check()
+    }
+
+    /// Publish an event built by `make_event` only when the event type has subscribers,
+    /// saving the allocation or serialization of payloads nobody listens to.
+    /// The event is built and dispatched under the bus lock, so handlers subscribed
+    /// meanwhile can't be missed.
+    ///
+    /// ```no_run
+    /// event_bus.publish_lazy("my_event", || Event::new(build_report()))?;
+    /// ```
+    ///
+    /// **Note:** Handlers skipping events internally, e.g. filtered handlers, still count
+    /// as subscribers.
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn publish_lazy(
+        &self,
+        event_type: &str,
+        make_event: impl FnOnce() -> Event<T>,
+    ) -> Result<(), PublishError> {
+        self.publish_with(
+            event_type,
+            || [make_event()],
+            Publishing {
+                lazy: true,
+                ..Default::default()
+            },
+        )?
+        .check()
+    }
+
+    /// Publish a batch of events to subscribed handlers in one dispatch.
+    /// Each handler receives the events of the batch in order.
+    ///
+    /// ```no_run
+    /// let events = vec![Event::new(sample_a), Event::new(sample_b)];
+    ///
+    /// event_bus.publish_batch("metrics", &events)?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn publish_batch(&self, event_type: &str, events: &[Event<T>]) -> Result<(), PublishError> {
+        self.publish_with(event_type, || events, Publishing::default())?
+            .check()
+    }
+
+    /// Publish an event and return a `DispatchReport` of the fan-out, so callers can log
+    /// how many handlers received the event and which of them failed.
+    /// Handler errors are part of the report, only errors before dispatch are returned.
+    ///
+    /// ```no_run
+    /// let report = event_bus.publish_report("my_event", &event)?;
+    ///
+    /// for failure in &report.failed {
+    ///     eprintln!("handler {:?} failed: {}", failure.handler_id, failure.error);
+    /// }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn publish_report(
+        &self,
+        event_type: &str,
+        event_data: &Event<T>,
+    ) -> Result<DispatchReport, PublishError> {
+        let started = Instant::now();
+        let Published {
+            subscribers,
+            dispatched,
+            ..
+        } = self.publish_with(
+            event_type,
+            || slice::from_ref(event_data),
+            Publishing::default(),
+        )?;
+        Ok(DispatchReport {
+            delivered: dispatched.delivered(subscribers),
+            failed: dispatched.failed,
+            skipped: dispatched.skipped,
+            duration: started.elapsed(),
+        })
+    }
+
+    /// Prepare an event for publishing without dispatching it yet.
+    /// It checks that the event type has at least one subscribed handler and returns a
+    /// `Prepared` publish which is dispatched on `commit` or discarded on `abort`.
+    ///
+    /// ```no_run
+    /// let event = Event::new(MyEventData { /* initialize your event data */ });
+    /// let prepared = event_bus.publish_prepared("my_event", event)?;
+    ///
+    /// match db_transaction.commit() {
+    ///     Ok(_) => prepared.commit()?,
+    ///     Err(_) => prepared.abort(),
+    /// }
+    /// ```
+    ///
+    /// **Note:** Handlers subscribed between prepare and commit also receive the event.
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn publish_prepared(
+        &self,
+        event_type: &str,
+        event: Event<T>,
+    ) -> Result<Prepared<'_, T>, PublishError> {
+        self.check_reentrancy(event_type)?;
+
         let event_handler_map = self
             .event_handler_map
             .lock()
-            .map_err(|_| BasuError::MutexPoisoned)?;
+            .map_err(|_| PublishError::MutexPoisoned)?;
 
-        match event_handler_map.get(event_type) {
-            Some(handler_map) => {
-                let handler_map = handler_map.lock().map_err(|_| BasuError::MutexPoisoned)?;
-                handler_map
-                    .par_iter()
-                    .try_for_each(|(_id, h)| h.handle(event_data))?;
-                Ok(())
-            }
-            None => Err(BasuError::EventTypeNotFOUND),
+        let subscribers = self.subscribers(&event_handler_map, event_type)?;
+        if subscribers.is_empty() {
+            return Err(PublishError::NoSubscribers);
         }
+
+        Ok(Prepared {
+            event_bus: self,
+            event_type: event_type.to_owned(),
+            event,
+        })
     }
 
     /// List all registered event types.
@@ -188,6 +550,36 @@ impl<T: Sync> EventBus<T> {
         Ok(event_types)
     }
 
+    /// Pre-create the entries of latency-critical event types and start the dispatch threads
+    /// they use, so the first subscribe and publish don't pay for allocating or spawning them.
+    ///
+    /// ```no_run
+    /// event_bus.warmup(&["order.placed", "order.filled"])?;
+    /// ```
+    ///
+    /// **Note:** Warmed event types are listed by `list` and publishing to them succeeds
+    /// even before a handler subscribes.
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn warmup(&self, event_types: &[&str]) -> Result<(), BasuError> {
+        let mut event_handler_map = self
+            .event_handler_map
+            .lock()
+            .map_err(|_| BasuError::MutexPoisoned)?;
+
+        for event_type in event_types {
+            if !event_handler_map.contains_key(*event_type) {
+                event_handler_map.insert((*event_type).to_owned(), Default::default());
+                self.index_event_type(event_type);
+            }
+        }
+        drop(event_handler_map);
+
+        for event_type in event_types {
+            self.dispatcher(event_type).warmup();
+        }
+        Ok(())
+    }
+
     /// Get the number of registered handlers for a specific event type.
     ///
     /// ```no_run
@@ -218,6 +610,113 @@ impl<T: Sync> EventBus<T> {
         }
     }
 
+    /// Check whether an event type has at least one subscribed handler, wildcard handlers
+    /// included, so publishers can skip building expensive payloads when nobody is listening.
+    ///
+    /// ```no_run
+    /// if event_bus.has_subscribers("my_event")? {
+    ///     event_bus.publish("my_event", &Event::new(build_report()))?;
+    /// }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn has_subscribers(&self, event_type: &str) -> Result<bool, BasuError> {
+        let event_handler_map = self
+            .event_handler_map
+            .lock()
+            .map_err(|_| BasuError::MutexPoisoned)?;
+
+        let subscribers = self.subscribers(&event_handler_map, event_type);
+        match subscribers {
+            Ok(subscribers) => Ok(!subscribers.is_empty()),
+            Err(PublishError::EventTypeNotFound) => Ok(false),
+            Err(PublishError::MutexPoisoned) => Err(BasuError::MutexPoisoned),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Check whether a handler is subscribed to an event type.
+    ///
+    /// ```no_run
+    /// let handler_id = event_bus.subscribe("my_event", Box::new(MyEventHandler))?;
+    ///
+    /// assert!(event_bus.contains_handler("my_event", &handler_id)?);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn contains_handler(
+        &self,
+        event_type: &str,
+        handler_id: &HandlerId,
+    ) -> Result<bool, BasuError> {
+        let event_handler_map = self
+            .event_handler_map
+            .lock()
+            .map_err(|_| BasuError::MutexPoisoned)?;
+
+        match event_handler_map.get(event_type) {
+            Some(handler_map) => {
+                let handler_map = handler_map.lock().map_err(|_| BasuError::MutexPoisoned)?;
+                Ok(handler_map.contains_key(handler_id))
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Wait until every publish to `event_type` started before the call has been handled,
+    /// e.g. to make sure all writes landed before taking a snapshot.
+    ///
+    /// ```no_run
+    /// thread::spawn(move || event_bus.publish("write", &event));
+    /// // ...
+    /// event_bus.flush("write")?;
+    /// ```
+    ///
+    /// **Note:** Events buffered outside the bus, by a `Batcher`, a channel or an
+    /// `Executor`, are not waited for.
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn flush(&self, event_type: &str) -> Result<(), BasuError> {
+        // a publish holds the bus and the handler maps until its handlers returned
+        let event_handler_map = self
+            .event_handler_map
+            .lock()
+            .map_err(|_| BasuError::MutexPoisoned)?;
+        if let Some(handler_map) = event_handler_map.get(event_type) {
+            drop(handler_map.lock().map_err(|_| BasuError::MutexPoisoned)?);
+        }
+        Ok(())
+    }
+
+    /// Call `f` with the event type, id and handler of every subscribed handler of type `H`,
+    /// e.g. to find a metrics handler and flush it. Only handlers implementing
+    /// `Handle::as_any` are visited.
+    ///
+    /// ```no_run
+    /// event_bus.inspect_handlers(|_event_type, _handler_id, metrics: &MetricsHandler| {
+    ///     metrics.flush()
+    /// })?;
+    /// ```
+    ///
+    /// **Note:** `f` runs while the bus is locked, it must not subscribe or publish.
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn inspect_handlers<H: 'static>(
+        &self,
+        mut f: impl FnMut(&str, &HandlerId, &H),
+    ) -> Result<(), BasuError> {
+        let event_handler_map = self
+            .event_handler_map
+            .lock()
+            .map_err(|_| BasuError::MutexPoisoned)?;
+
+        for (event_type, handler_map) in event_handler_map.iter() {
+            let handler_map = handler_map.lock().map_err(|_| BasuError::MutexPoisoned)?;
+            for (handler_id, handler) in handler_map.iter() {
+                if let Some(handler) = handler.as_any().and_then(|h| h.downcast_ref::<H>()) {
+                    f(event_type, handler_id, handler);
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Clear all event handlers from the event bus.
     /// It removes all registered event handlers.
     ///
@@ -253,7 +752,97 @@ impl<T: Sync> EventBus<T> {
             .lock()
             .map_err(|_| BasuError::MutexPoisoned)?;
 
-        event_handler_map.clear();
+        let mut detached = Vec::new();
+        self.clear_index();
+        for (event_type, handler_map) in event_handler_map.drain() {
+            let handler_map = handler_map.lock().map_err(|_| BasuError::MutexPoisoned)?;
+            detached.extend(
+                handler_map
+                    .keys()
+                    .map(|handler_id| LifecycleEvent::Detached {
+                        event_type: event_type.clone(),
+                        handler_id: handler_id.clone(),
+                    }),
+            );
+        }
+        drop(event_handler_map);
+
+        for event in detached {
+            self.emit_lifecycle(event);
+        }
         Ok(())
     }
+
+    /// Clear the handlers of a single event type instead of the whole bus.
+    /// It removes the event type with its handlers and the events buffered for it by
+    /// `buffer_frames`, and returns how many handlers were removed.
+    ///
+    /// ```no_run
+    /// let removed = event_bus.clear_event_type("my_event")?;
+    ///
+    /// println!("{} handlers cleared", removed);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn clear_event_type(&self, event_type: &str) -> Result<usize, BasuError> {
+        let mut event_handler_map = self
+            .event_handler_map
+            .lock()
+            .map_err(|_| BasuError::MutexPoisoned)?;
+
+        let handler_map = event_handler_map
+            .remove(event_type)
+            .ok_or(BasuError::EventTypeNotFOUND)?;
+        self.unindex_event_type(event_type);
+        let handler_map = handler_map.lock().map_err(|_| BasuError::MutexPoisoned)?;
+        let detached: Vec<_> = handler_map
+            .keys()
+            .map(|handler_id| LifecycleEvent::Detached {
+                event_type: event_type.to_owned(),
+                handler_id: handler_id.clone(),
+            })
+            .collect();
+        drop(handler_map);
+        drop(event_handler_map);
+
+        self.frame_events
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(event_type);
+
+        let removed = detached.len();
+        for event in detached {
+            self.emit_lifecycle(event);
+        }
+        Ok(removed)
+    }
+}
+
+/// An event which has been validated for publishing but not dispatched yet.
+#[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+pub struct Prepared<'a, T> {
+    event_bus: &'a EventBus<T>,
+    event_type: String,
+    event: Event<T>,
+}
+
+impl<T: Sync> Prepared<'_, T> {
+    /// return the event type the event will be published to.
+    pub fn event_type(&self) -> &str {
+        &self.event_type
+    }
+
+    /// return the prepared event.
+    pub fn event(&self) -> &Event<T> {
+        &self.event
+    }
+
+    /// Dispatch the prepared event to subscribed handlers.
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn commit(self) -> Result<(), PublishError> {
+        self.event_bus.publish(&self.event_type, &self.event)
+    }
+
+    /// Discard the prepared event without dispatching it.
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn abort(self) {}
 }
@@ -0,0 +1,69 @@
+use super::{Join, JoinState, Side};
+use crate::{async_trait, error::BasuError, event::Event, EventBus, Handle, HandlerId};
+
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+/// Handler which feeds one side of a join.
+struct JoinSide<T> {
+    side: Side,
+    state: Arc<JoinState<T>>,
+    combined: mpsc::UnboundedSender<T>,
+}
+
+#[async_trait]
+impl<T: Clone + Send + Sync + 'static> Handle<T> for JoinSide<T> {
+    async fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        if let Some(combined) = self.state.accept(self.side, event) {
+            let _ = self.combined.send(combined);
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> EventBus<T> {
+    /// Subscribe a `Join` correlating the `left` and `right` event types, combined events
+    /// are published in order to `output_type` from one task per join, their publish
+    /// errors are dropped. The task stops once both sides are unsubscribed.
+    /// It returns the handler ids of the left and right side.
+    ///
+    /// ```no_run
+    /// let event_bus = Arc::new(EventBus::<Message>::new());
+    /// let (order_id, payment_id) = event_bus
+    ///     .join("order", "payment", join, "order.paid")
+    ///     .await;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn join(
+        self: &Arc<Self>,
+        left: &str,
+        right: &str,
+        join: Join<T>,
+        output_type: &str,
+    ) -> (HandlerId, HandlerId) {
+        let state = Arc::new(JoinState::new(join));
+        let (sender, mut receiver) = mpsc::unbounded_channel::<T>();
+        let event_bus = Arc::downgrade(self);
+        let output_type = output_type.to_owned();
+        tokio::spawn(async move {
+            while let Some(combined) = receiver.recv().await {
+                let Some(event_bus) = event_bus.upgrade() else {
+                    break;
+                };
+                let _ = event_bus.publish(&output_type, &Event::new(combined)).await;
+            }
+        });
+
+        let side = |side| JoinSide {
+            side,
+            state: state.clone(),
+            combined: sender.clone(),
+        };
+
+        let left_id = self.subscribe(left, Box::new(side(Side::Left))).await;
+        let right_id = self.subscribe(right, Box::new(side(Side::Right))).await;
+
+        (left_id, right_id)
+    }
+}
@@ -0,0 +1,71 @@
+use super::{Join, JoinState, Side};
+use crate::{error::BasuError, event::Event, EventBus, Handle, HandlerId};
+
+use std::{
+    sync::{
+        mpsc::{self, Sender},
+        Arc,
+    },
+    thread,
+};
+
+/// Handler which feeds one side of a join.
+struct JoinSide<T> {
+    side: Side,
+    state: Arc<JoinState<T>>,
+    combined: Sender<T>,
+}
+
+impl<T: Clone + Send + Sync + 'static> Handle<T> for JoinSide<T> {
+    fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        if let Some(combined) = self.state.accept(self.side, event) {
+            let _ = self.combined.send(combined);
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> EventBus<T> {
+    /// Subscribe a `Join` correlating the `left` and `right` event types, combined events
+    /// are published in order to `output_type` from one thread per join, their publish
+    /// errors are dropped. The thread stops once both sides are unsubscribed.
+    /// It returns the handler ids of the left and right side.
+    ///
+    /// ```no_run
+    /// let event_bus = Arc::new(EventBus::<Message>::new());
+    /// let (order_id, payment_id) = event_bus.join("order", "payment", join, "order.paid")?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn join(
+        self: &Arc<Self>,
+        left: &str,
+        right: &str,
+        join: Join<T>,
+        output_type: &str,
+    ) -> Result<(HandlerId, HandlerId), BasuError> {
+        let state = Arc::new(JoinState::new(join));
+        let (sender, receiver) = mpsc::channel::<T>();
+        let event_bus = Arc::downgrade(self);
+        let output_type = output_type.to_owned();
+        thread::spawn(move || {
+            for combined in receiver {
+                let Some(event_bus) = event_bus.upgrade() else {
+                    break;
+                };
+                let _ = event_bus.publish(&output_type, &Event::new(combined));
+            }
+        });
+
+        let side = |side| JoinSide {
+            side,
+            state: state.clone(),
+            combined: sender.clone(),
+        };
+
+        let left_id = self.subscribe(left, Box::new(side(Side::Left)))?;
+        let right_id = self.subscribe(right, Box::new(side(Side::Right)))?;
+
+        Ok((left_id, right_id))
+    }
+}
@@ -0,0 +1,128 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+
+use crate::event::Event;
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+type KeyFn<T> = Box<dyn Fn(&Event<T>) -> String + Send + Sync>;
+type CombineFn<T> = Box<dyn Fn(&Event<T>, &Event<T>) -> T + Send + Sync>;
+type Pending<T> = HashMap<String, VecDeque<(Instant, Event<T>)>>;
+
+/// Correlation of two topics by key, each event is combined with the oldest unmatched
+/// event of the other topic with the same key which arrived within the window.
+pub struct Join<T> {
+    key: KeyFn<T>,
+    within: Duration,
+    combine: CombineFn<T>,
+}
+
+impl<T> Join<T> {
+    /// create a new `Join` from the correlation `key` of an event, the window in which
+    /// two events match and the function `combine`-ing the left and right event.
+    ///
+    /// ```no_run
+    /// let join = Join::new(
+    ///     |event: &Event<Message>| event.data.order_id.to_string(),
+    ///     Duration::from_secs(5),
+    ///     |order, payment| Message::PaidOrder(order.data.clone(), payment.data.clone()),
+    /// );
+    /// ```
+    pub fn new(
+        key: impl Fn(&Event<T>) -> String + Send + Sync + 'static,
+        within: Duration,
+        combine: impl Fn(&Event<T>, &Event<T>) -> T + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            key: Box::new(key),
+            within,
+            combine: Box::new(combine),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Side {
+    Left,
+    Right,
+}
+
+/// Unmatched events of both topics shared by the two join handlers.
+struct JoinState<T> {
+    join: Join<T>,
+    pending: Mutex<(Pending<T>, Pending<T>)>,
+    last_sweep: Mutex<Instant>,
+}
+
+impl<T: Clone> JoinState<T> {
+    fn new(join: Join<T>) -> Self {
+        Self {
+            join,
+            pending: Default::default(),
+            last_sweep: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// match an event against the other topic, buffering it when there is no match.
+    fn accept(&self, side: Side, event: &Event<T>) -> Option<T> {
+        let key = (self.join.key)(event);
+        let now = Instant::now();
+        let within = self.join.within;
+        let expired = |(at, _): &(Instant, Event<T>)| now.duration_since(*at) > within;
+
+        let mut pending = self.pending.lock().unwrap_or_else(PoisonError::into_inner);
+        let pending = &mut *pending;
+        self.sweep(pending, now);
+
+        let (own, other) = match side {
+            Side::Left => (&mut pending.0, &mut pending.1),
+            Side::Right => (&mut pending.1, &mut pending.0),
+        };
+
+        let matched = other.get_mut(&key).and_then(|queue| {
+            while queue.front().is_some_and(expired) {
+                queue.pop_front();
+            }
+            queue.pop_front()
+        });
+        if other.get(&key).is_some_and(VecDeque::is_empty) {
+            other.remove(&key);
+        }
+
+        match matched {
+            Some((_, other_event)) => Some(match side {
+                Side::Left => (self.join.combine)(event, &other_event),
+                Side::Right => (self.join.combine)(&other_event, event),
+            }),
+            None => {
+                own.entry(key).or_default().push_back((now, event.clone()));
+                None
+            }
+        }
+    }
+
+    /// drop the expired events of every key, at most once per window.
+    fn sweep(&self, pending: &mut (Pending<T>, Pending<T>), now: Instant) {
+        let mut last_sweep = self
+            .last_sweep
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if now.duration_since(*last_sweep) < self.join.within {
+            return;
+        }
+        *last_sweep = now;
+
+        for side in [&mut pending.0, &mut pending.1] {
+            side.retain(|_, queue| {
+                queue.retain(|(at, _)| now.duration_since(*at) <= self.join.within);
+                !queue.is_empty()
+            });
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::EventBus;
+
+use std::{ops::Deref, sync::OnceLock};
+
+/// `EventBus` created on first access, so it can live in a `static` without `lazy_static`
+/// or `once_cell` in downstream code.
+///
+/// ```no_run
+/// static EVENT_BUS: LazyEventBus<MyEventData> = EventBus::const_new();
+///
+/// EVENT_BUS.subscribe("my_event", Box::new(MyEventHandler)).await;
+/// ```
+///
+/// **Note:** The static is built at compile time, no code runs before `main`. The bus is
+/// created by the first thread accessing it, threads racing on first access wait for that
+/// single initialization and all see the same bus. The init function must not access the
+/// bus it initializes, it would deadlock.
+pub struct LazyEventBus<T> {
+    bus: OnceLock<EventBus<T>>,
+    init: fn() -> EventBus<T>,
+}
+
+impl<T> LazyEventBus<T> {
+    /// create a `LazyEventBus` which creates the bus with `EventBus::new`.
+    pub const fn new() -> Self {
+        Self::with_init(EventBus::new)
+    }
+
+    /// create a `LazyEventBus` which creates the bus with `init`, e.g. to configure it.
+    ///
+    /// ```no_run
+    /// static EVENT_BUS: LazyEventBus<MyEventData> =
+    ///     LazyEventBus::with_init(|| EventBus::new().with_history(100));
+    /// ```
+    pub const fn with_init(init: fn() -> EventBus<T>) -> Self {
+        Self {
+            bus: OnceLock::new(),
+            init,
+        }
+    }
+
+    /// return the bus, creating it on first access.
+    pub fn get(&self) -> &EventBus<T> {
+        self.bus.get_or_init(self.init)
+    }
+}
+
+impl<T> Default for LazyEventBus<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Deref for LazyEventBus<T> {
+    type Target = EventBus<T>;
+
+    fn deref(&self) -> &Self::Target {
+        self.get()
+    }
+}
+
+impl<T> EventBus<T> {
+    /// create a `LazyEventBus` in a const context, for a bus stored in a `static`.
+    pub const fn const_new() -> LazyEventBus<T> {
+        LazyEventBus::new()
+    }
+}
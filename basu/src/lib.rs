@@ -6,13 +6,13 @@
 //! ### Features:
 //!
 //! - Support for both asynchronous and synchronous event handling. Choose the approach
-//!  that best fits your application's needs.
+//!   that best fits your application's needs.
 //!
 //! - Provides an abstraction for representing events, allowing you to define custom
-//!  event structures with payload data.
+//!   event structures with payload data.
 //!
 //! - Well-defined error types and error handling mechanisms for reliable event bus
-//!  operations.
+//!   operations.
 //!
 //! To enable the synchronous event handling capability, use the `sync` feature:
 //!
@@ -35,29 +35,138 @@ compile_error!("Either `async` or `sync` feature must be enabled");
 #[cfg(all(feature = "async", feature = "sync"))]
 compile_error!("The `async` and `sync` features cannot be enabled simultaneously");
 
+/// basu actor
+pub mod actor;
+/// basu aggregate
+pub mod aggregate;
+/// basu backfill
+pub mod backfill;
+/// basu batch
+pub mod batch;
+#[cfg(feature = "async")]
+/// basu broadcast
+pub mod broadcast;
+/// basu budget
+pub mod budget;
+/// basu buffered
+pub mod buffered;
+#[cfg(feature = "sync")]
+/// basu channel
+pub mod channel;
+/// basu circuit
+pub mod circuit;
+/// basu combinator
+pub mod combinator;
+/// basu command
+pub mod command;
+/// basu config
+pub mod config;
+/// basu context
+pub mod context;
+#[cfg(feature = "sync")]
+/// basu dispatch
+pub mod dispatch;
 /// basu error
 pub mod error;
 /// basu event
 pub mod event;
+/// basu executor
+pub mod executor;
+/// basu expiry
+pub mod expiry;
+/// basu extract
+pub mod extract;
+/// basu flag
+pub mod flag;
+#[cfg(feature = "sync")]
+/// basu fork
+pub mod fork;
+/// basu frame
+pub mod frame;
+#[cfg(feature = "fuzzing")]
+/// basu fuzz
+pub mod fuzz;
+mod global;
+/// basu history
+pub mod history;
 #[cfg(feature = "async")]
 mod impl_async;
 #[cfg(feature = "sync")]
 mod impl_sync;
+/// basu join
+pub mod join;
+/// basu lazy
+pub mod lazy;
+/// basu lifecycle
+pub mod lifecycle;
+/// basu named
+pub mod named;
+/// basu outcome
+pub mod outcome;
+/// basu owner
+pub mod owner;
+/// basu prelude
+pub mod prelude;
+/// basu priority
+pub mod priority;
+mod publish;
+/// basu query
+pub mod query;
+#[cfg(feature = "sync")]
+/// basu reentrancy
+pub mod reentrancy;
+/// basu reload
+pub mod reload;
+/// basu report
+pub mod report;
+/// basu request
+pub mod request;
+/// basu retained
+pub mod retained;
+#[cfg(feature = "async")]
+/// basu scope
+pub mod scope;
+#[cfg(feature = "tower")]
+/// basu service
+pub mod service;
+/// basu slow
+pub mod slow;
+#[cfg(feature = "async")]
+/// basu stream
+pub mod stream;
+/// basu subscription
+pub mod subscription;
+/// basu tenancy
+pub mod tenancy;
 #[cfg(test)]
 mod tests;
+/// basu validate
+pub mod validate;
+#[cfg(feature = "async")]
+/// basu watch
+pub mod watch;
+/// basu wildcard
+pub mod wildcard;
+/// basu window
+pub mod window;
 
 #[cfg(feature = "async")]
 pub use async_trait::async_trait;
+pub use global::global;
 #[cfg(feature = "async")]
-pub use impl_async::Handle;
+pub use impl_async::{handler_fn, Handle, Prepared};
 #[cfg(feature = "sync")]
-pub use impl_sync::Handle;
+pub use impl_sync::{handler_fn, Handle, Prepared};
 #[cfg(feature = "sync")]
 use std::sync::Mutex;
 #[cfg(feature = "async")]
 use tokio::sync::Mutex;
 
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt,
+    sync::{Arc, RwLock},
+};
 
 use uuid::Uuid;
 
@@ -69,18 +178,107 @@ pub type HandlerMap<T> = Arc<Mutex<HashMap<HandlerId, Handler<T>>>>;
 pub type EventHandlerMap<T> = Arc<Mutex<HashMap<String, HandlerMap<T>>>>;
 
 /// An asynchronous `EventBus` to interact with.
-#[derive(Default)]
 pub struct EventBus<T> {
+    name: Option<String>,
+    labels: BTreeMap<String, String>,
+    declared_topics: BTreeSet<String>,
     event_handler_map: EventHandlerMap<T>,
+    frame_events: frame::FrameEvents<T>,
+    lifecycle_hooks: Vec<lifecycle::LifecycleHook>,
+    slow_handler_hooks: Vec<slow::SlowHandlerHook>,
+    circuit_breaker: Option<circuit::CircuitBreaker>,
+    circuit_hooks: Vec<circuit::CircuitHook>,
+    circuits: circuit::Circuits,
+    settings: RwLock<Arc<config::Settings>>,
+    handler_names: named::HandlerNames,
+    handler_owners: owner::HandlerOwners,
+    handler_priorities: priority::HandlerPriorities,
+    history: history::History<T>,
+    patterns: std::sync::Mutex<wildcard::PatternIndex>,
+    replies: request::ReplySlot,
+    retained: retained::RetainedEvents<T>,
+    tenancy: Option<Arc<tenancy::Tenancy<T>>>,
+    #[cfg(feature = "async")]
+    watches: watch::Watches<T>,
+    #[cfg(feature = "sync")]
+    detect_reentrancy: bool,
 }
 
 impl<T> EventBus<T> {
     /// create a new `EventBus`
     pub fn new() -> Self {
         Self {
+            name: None,
+            labels: BTreeMap::new(),
+            declared_topics: BTreeSet::new(),
             event_handler_map: Default::default(),
+            frame_events: Default::default(),
+            lifecycle_hooks: Vec::new(),
+            slow_handler_hooks: Vec::new(),
+            circuit_breaker: None,
+            circuit_hooks: Vec::new(),
+            circuits: Default::default(),
+            settings: Default::default(),
+            handler_names: Default::default(),
+            handler_owners: Default::default(),
+            handler_priorities: Default::default(),
+            history: Default::default(),
+            patterns: Default::default(),
+            replies: Default::default(),
+            retained: Default::default(),
+            tenancy: None,
+            #[cfg(feature = "async")]
+            watches: Default::default(),
+            #[cfg(feature = "sync")]
+            detect_reentrancy: false,
+        }
+    }
+
+    /// create a new `EventBus` with a name, so buses can be told apart in logs and errors.
+    /// The name and the labels are part of the `HandlerFailure`s of publish errors and dispatch
+    /// reports and of `SlowHandler` reports. Lifecycle, circuit and config reload hooks are added to one bus,
+    /// capture the name in the hook to tell the buses apart.
+    ///
+    /// ```no_run
+    /// let event_bus = EventBus::<MyEventData>::named("payments").with_label("region", "eu");
+    /// ```
+    pub fn named(name: impl Into<String>) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..Self::new()
         }
     }
+
+    /// attach a key/value instrumentation label to the `EventBus`, reported along with its name.
+    pub fn with_label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.labels.insert(key.into(), value.into());
+        self
+    }
+
+    /// return the name of the `EventBus`, if any.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// return the instrumentation labels of the `EventBus`.
+    pub fn labels(&self) -> &BTreeMap<String, String> {
+        &self.labels
+    }
+}
+
+impl<T> Default for EventBus<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for EventBus<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventBus")
+            .field("name", &self.name)
+            .field("labels", &self.labels)
+            .finish_non_exhaustive()
+    }
 }
 
 /// HandlerId is the key in `HandlerMap` hash map.
@@ -0,0 +1,62 @@
+use crate::{EventBus, HandlerId};
+
+use std::sync::Arc;
+
+/// Lifecycle change of a handler registered on an `EventBus`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LifecycleEvent {
+    /// A handler was subscribed to an event type.
+    Attached {
+        /// event type the handler was subscribed to
+        event_type: String,
+        /// id of the handler
+        handler_id: HandlerId,
+    },
+    /// A handler was removed from an event type.
+    /// The handler has been dropped, has no invocation in flight and won't be invoked again,
+    /// except an invocation which a `sandboxed` handler abandoned after its time limit.
+    Detached {
+        /// event type the handler was removed from
+        event_type: String,
+        /// id of the handler
+        handler_id: HandlerId,
+    },
+}
+
+/// Callback notified of handler lifecycle changes.
+pub type LifecycleHook = Arc<dyn Fn(&LifecycleEvent) + Send + Sync>;
+
+impl<T> EventBus<T> {
+    /// add a hook which is notified whenever a handler is attached to or detached from the bus,
+    /// so plugin frameworks know when a handler's code can be unloaded safely.
+    ///
+    /// ```no_run
+    /// let event_bus = EventBus::<MyEventData>::new().with_lifecycle_hook(|event| {
+    ///     if let LifecycleEvent::Detached { handler_id, .. } = event {
+    ///         plugins.release(handler_id);
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// **Note:** Hooks run after the bus locks are released, on the task or thread which
+    /// subscribed or unsubscribed the handler.
+    pub fn with_lifecycle_hook(
+        mut self,
+        hook: impl Fn(&LifecycleEvent) + Send + Sync + 'static,
+    ) -> Self {
+        self.lifecycle_hooks.push(Arc::new(hook));
+        self
+    }
+
+    pub(crate) fn emit_lifecycle(&self, event: LifecycleEvent) {
+        if let LifecycleEvent::Detached { handler_id, .. } = &event {
+            self.forget_handler_name(handler_id);
+            self.forget_handler_owner(handler_id);
+            self.forget_circuit(handler_id);
+            self.forget_handler_priority(handler_id);
+        }
+        for hook in &self.lifecycle_hooks {
+            hook(&event);
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use super::HandlerInfo;
+use crate::{error::BasuError, lifecycle::LifecycleEvent, EventBus, Handler, HandlerId};
+
+use std::sync::PoisonError;
+
+impl<T: Sync> EventBus<T> {
+    /// Subscribe a handler under a name, so it can be found by `handler_name` and
+    /// `unsubscribe_where` predicates. Names don't need to be unique.
+    ///
+    /// ```no_run
+    /// let handler_id =
+    ///     event_bus.subscribe_named("my_event", "tmp-preview", Box::new(MyEventHandler))?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn subscribe_named(
+        &self,
+        event_type: &str,
+        name: &str,
+        handler: Handler<T>,
+    ) -> Result<HandlerId, BasuError> {
+        let handler_id = self.subscribe(event_type, handler)?;
+        self.set_handler_name(&handler_id, name);
+
+        Ok(handler_id)
+    }
+
+    /// Unsubscribe every handler matching `predicate`, which receives the event type and a
+    /// `HandlerInfo` of each handler. All matching handlers are removed under the bus lock,
+    /// so no publish sees a partial cleanup. It returns how many handlers were removed.
+    ///
+    /// ```no_run
+    /// let removed = event_bus.unsubscribe_where(|_event_type, info| {
+    ///     info.name.is_some_and(|name| name.starts_with("tmp-"))
+    /// })?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn unsubscribe_where(
+        &self,
+        mut predicate: impl FnMut(&str, &HandlerInfo<'_>) -> bool,
+    ) -> Result<usize, BasuError> {
+        let event_handler_map = self
+            .event_handler_map
+            .lock()
+            .map_err(|_| BasuError::MutexPoisoned)?;
+
+        let names = self
+            .handler_names
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        let mut detached = Vec::new();
+        for (event_type, handler_map) in event_handler_map.iter() {
+            let mut handler_map = handler_map.lock().map_err(|_| BasuError::MutexPoisoned)?;
+            handler_map.retain(|handler_id, _| {
+                let info = HandlerInfo {
+                    id: handler_id,
+                    name: names.get(handler_id).map(|name| &**name),
+                };
+                let matched = predicate(event_type, &info);
+                if matched {
+                    detached.push(LifecycleEvent::Detached {
+                        event_type: event_type.clone(),
+                        handler_id: handler_id.clone(),
+                    });
+                }
+                !matched
+            });
+        }
+        drop(event_handler_map);
+
+        let removed = detached.len();
+        for event in detached {
+            self.emit_lifecycle(event);
+        }
+        Ok(removed)
+    }
+
+    /// Clear the handlers of a single event type matching `predicate`, e.g. every handler
+    /// subscribed under one name, and return how many handlers were removed.
+    ///
+    /// ```no_run
+    /// let removed =
+    ///     event_bus.clear_event_type_where("my_event", |info| info.name == Some("preview"))?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn clear_event_type_where(
+        &self,
+        event_type: &str,
+        mut predicate: impl FnMut(&HandlerInfo<'_>) -> bool,
+    ) -> Result<usize, BasuError> {
+        let event_handler_map = self
+            .event_handler_map
+            .lock()
+            .map_err(|_| BasuError::MutexPoisoned)?;
+
+        let handler_map = event_handler_map
+            .get(event_type)
+            .ok_or(BasuError::EventTypeNotFOUND)?;
+        let names = self
+            .handler_names
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        let mut handler_map = handler_map.lock().map_err(|_| BasuError::MutexPoisoned)?;
+        let mut detached = Vec::new();
+        handler_map.retain(|handler_id, _| {
+            let info = HandlerInfo {
+                id: handler_id,
+                name: names.get(handler_id).map(|name| &**name),
+            };
+            let matched = predicate(&info);
+            if matched {
+                detached.push(LifecycleEvent::Detached {
+                    event_type: event_type.to_owned(),
+                    handler_id: handler_id.clone(),
+                });
+            }
+            !matched
+        });
+        drop(handler_map);
+        drop(event_handler_map);
+
+        let removed = detached.len();
+        for event in detached {
+            self.emit_lifecycle(event);
+        }
+        Ok(removed)
+    }
+}
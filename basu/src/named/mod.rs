@@ -0,0 +1,48 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+
+use crate::{EventBus, HandlerId};
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, PoisonError, RwLock},
+};
+
+/// Names of the handlers subscribed with `subscribe_named`.
+pub(crate) type HandlerNames = RwLock<HashMap<HandlerId, Arc<str>>>;
+
+/// Description of a subscribed handler, given to `unsubscribe_where` predicates.
+#[derive(Debug, Clone, Copy)]
+pub struct HandlerInfo<'a> {
+    /// id of the handler
+    pub id: &'a HandlerId,
+    /// name given with `subscribe_named`, if any
+    pub name: Option<&'a str>,
+}
+
+impl<T> EventBus<T> {
+    /// return the name a handler was subscribed with, if any.
+    pub fn handler_name(&self, handler_id: &HandlerId) -> Option<String> {
+        self.handler_names
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(handler_id)
+            .map(|name| name.to_string())
+    }
+
+    pub(crate) fn set_handler_name(&self, handler_id: &HandlerId, name: &str) {
+        self.handler_names
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(handler_id.clone(), Arc::from(name));
+    }
+
+    pub(crate) fn forget_handler_name(&self, handler_id: &HandlerId) {
+        self.handler_names
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(handler_id);
+    }
+}
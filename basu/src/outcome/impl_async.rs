@@ -0,0 +1,100 @@
+use super::{HandleOutcome, SignalHandler};
+use crate::{async_trait, error::BasuError, event::Event, EventBus, Handle, HandlerId};
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, OnceLock, Weak,
+};
+
+/// Implement for event handler which can unsubscribe itself
+#[async_trait]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub trait HandleSignal<T>: Send + Sync {
+    /// Handle event which is published from `EventBus` and tell whether to keep receiving events
+    async fn handle(&self, event: &Event<T>) -> Result<HandleOutcome, BasuError>;
+}
+
+/// Handler which unsubscribes itself when the inner handler signals it.
+struct Signaled<T> {
+    handler: SignalHandler<T>,
+    done: Arc<AtomicBool>,
+    event_type: Arc<str>,
+    handler_id: Arc<OnceLock<HandlerId>>,
+    event_bus: Weak<EventBus<T>>,
+}
+
+#[async_trait]
+impl<T: Send + Sync + 'static> Handle<T> for Signaled<T> {
+    async fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        if self.done.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        let outcome = self.handler.handle(event).await?;
+        if outcome == HandleOutcome::Unsubscribe && !self.done.swap(true, Ordering::SeqCst) {
+            if let Some(handler_id) = self.handler_id.get() {
+                // a publish holds the bus while invoking the handler, remove it from a task
+                let event_bus = self.event_bus.clone();
+                let event_type = self.event_type.clone();
+                let handler_id = handler_id.clone();
+                tokio::spawn(async move {
+                    if let Some(event_bus) = event_bus.upgrade() {
+                        let _ = event_bus.unsubscribe(&event_type, &handler_id).await;
+                    }
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Send + Sync + 'static> EventBus<T> {
+    /// Subscribe a `HandleSignal` handler, which unsubscribes itself by returning
+    /// `HandleOutcome::Unsubscribe` without needing its own `HandlerId`.
+    ///
+    /// ```no_run
+    /// struct UntilReady;
+    ///
+    /// #[async_trait]
+    /// impl HandleSignal<Status> for UntilReady {
+    ///     async fn handle(&self, event: &Event<Status>) -> Result<HandleOutcome, BasuError> {
+    ///         Ok(match event.data {
+    ///             Status::Ready => HandleOutcome::Unsubscribe,
+    ///             _ => HandleOutcome::Continue,
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// let event_bus = Arc::new(EventBus::<Status>::new());
+    /// let handler_id = event_bus.subscribe_signal("status", Box::new(UntilReady)).await;
+    /// ```
+    ///
+    /// **Note:** The handler is removed from a spawned task, it is skipped by publishes
+    /// racing with the removal.
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn subscribe_signal(
+        self: &Arc<Self>,
+        event_type: &str,
+        handler: SignalHandler<T>,
+    ) -> HandlerId {
+        let done = Arc::new(AtomicBool::new(false));
+        let slot = Arc::new(OnceLock::new());
+        let signaled = Signaled {
+            handler,
+            done: done.clone(),
+            event_type: Arc::from(event_type),
+            handler_id: slot.clone(),
+            event_bus: Arc::downgrade(self),
+        };
+
+        let handler_id = self.subscribe(event_type, Box::new(signaled)).await;
+        let _ = slot.set(handler_id.clone());
+        // the handler may have signaled before its id was known to it
+        if done.load(Ordering::SeqCst) {
+            let _ = self.unsubscribe(event_type, &handler_id).await;
+        }
+
+        handler_id
+    }
+}
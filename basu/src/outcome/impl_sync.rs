@@ -0,0 +1,100 @@
+use super::{HandleOutcome, SignalHandler};
+use crate::{error::BasuError, event::Event, EventBus, Handle, HandlerId};
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, OnceLock, Weak,
+    },
+    thread,
+};
+
+/// Implement for event handler which can unsubscribe itself
+#[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+pub trait HandleSignal<T>: Send + Sync {
+    /// Handle event which is published from `EventBus` and tell whether to keep receiving events
+    fn handle(&self, event: &Event<T>) -> Result<HandleOutcome, BasuError>;
+}
+
+/// Handler which unsubscribes itself when the inner handler signals it.
+struct Signaled<T> {
+    handler: SignalHandler<T>,
+    done: Arc<AtomicBool>,
+    event_type: Arc<str>,
+    handler_id: Arc<OnceLock<HandlerId>>,
+    event_bus: Weak<EventBus<T>>,
+}
+
+impl<T: Send + Sync + 'static> Handle<T> for Signaled<T> {
+    fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        if self.done.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        let outcome = self.handler.handle(event)?;
+        if outcome == HandleOutcome::Unsubscribe && !self.done.swap(true, Ordering::SeqCst) {
+            if let Some(handler_id) = self.handler_id.get() {
+                // a publish holds the bus while invoking the handler, remove it from a thread
+                let event_bus = self.event_bus.clone();
+                let event_type = self.event_type.clone();
+                let handler_id = handler_id.clone();
+                thread::spawn(move || {
+                    if let Some(event_bus) = event_bus.upgrade() {
+                        let _ = event_bus.unsubscribe(&event_type, &handler_id);
+                    }
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Send + Sync + 'static> EventBus<T> {
+    /// Subscribe a `HandleSignal` handler, which unsubscribes itself by returning
+    /// `HandleOutcome::Unsubscribe` without needing its own `HandlerId`.
+    ///
+    /// ```no_run
+    /// struct UntilReady;
+    ///
+    /// impl HandleSignal<Status> for UntilReady {
+    ///     fn handle(&self, event: &Event<Status>) -> Result<HandleOutcome, BasuError> {
+    ///         Ok(match event.data {
+    ///             Status::Ready => HandleOutcome::Unsubscribe,
+    ///             _ => HandleOutcome::Continue,
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// let event_bus = Arc::new(EventBus::<Status>::new());
+    /// let handler_id = event_bus.subscribe_signal("status", Box::new(UntilReady))?;
+    /// ```
+    ///
+    /// **Note:** The handler is removed from a spawned thread, it is skipped by publishes
+    /// racing with the removal.
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn subscribe_signal(
+        self: &Arc<Self>,
+        event_type: &str,
+        handler: SignalHandler<T>,
+    ) -> Result<HandlerId, BasuError> {
+        let done = Arc::new(AtomicBool::new(false));
+        let slot = Arc::new(OnceLock::new());
+        let signaled = Signaled {
+            handler,
+            done: done.clone(),
+            event_type: Arc::from(event_type),
+            handler_id: slot.clone(),
+            event_bus: Arc::downgrade(self),
+        };
+
+        let handler_id = self.subscribe(event_type, Box::new(signaled))?;
+        let _ = slot.set(handler_id.clone());
+        // the handler may have signaled before its id was known to it
+        if done.load(Ordering::SeqCst) {
+            self.unsubscribe(event_type, &handler_id)?;
+        }
+
+        Ok(handler_id)
+    }
+}
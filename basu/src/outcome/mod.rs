@@ -0,0 +1,22 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+
+#[cfg(feature = "async")]
+pub use impl_async::HandleSignal;
+#[cfg(feature = "sync")]
+pub use impl_sync::HandleSignal;
+
+/// Signal handler
+pub type SignalHandler<T> = Box<dyn HandleSignal<T>>;
+
+/// What a `HandleSignal` handler wants after handling an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HandleOutcome {
+    /// Keep receiving events.
+    #[default]
+    Continue,
+    /// Unsubscribe the handler, it receives no further events.
+    Unsubscribe,
+}
@@ -0,0 +1,23 @@
+use crate::{error::BasuError, EventBus, Handler, HandlerId};
+
+impl<T: Sync> EventBus<T> {
+    /// Subscribe a handler owned by a team or component, e.g. `"team-payments"`.
+    /// The owner is part of the handler's failures and slow handler reports, so problems can
+    /// be routed to the people who maintain the handler.
+    ///
+    /// ```no_run
+    /// event_bus.subscribe_owned("order.paid", "team-payments", Box::new(CapturePayment))?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn subscribe_owned(
+        &self,
+        event_type: &str,
+        owner: &str,
+        handler: Handler<T>,
+    ) -> Result<HandlerId, BasuError> {
+        let handler_id = self.subscribe(event_type, handler)?;
+        self.set_handler_owner(&handler_id, owner);
+
+        Ok(handler_id)
+    }
+}
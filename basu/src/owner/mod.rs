@@ -0,0 +1,60 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+
+use crate::{report::HandlerFailure, EventBus, HandlerId};
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, PoisonError, RwLock},
+};
+
+/// Owners of the handlers subscribed with `subscribe_owned`.
+pub(crate) type HandlerOwners = RwLock<HashMap<HandlerId, Arc<str>>>;
+
+impl<T> EventBus<T> {
+    /// return the owner a handler was subscribed with, if any.
+    pub fn handler_owner(&self, handler_id: &HandlerId) -> Option<String> {
+        self.handler_owners
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(handler_id)
+            .map(|owner| owner.to_string())
+    }
+
+    pub(crate) fn set_handler_owner(&self, handler_id: &HandlerId, owner: &str) {
+        self.handler_owners
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(handler_id.clone(), Arc::from(owner));
+    }
+
+    pub(crate) fn forget_handler_owner(&self, handler_id: &HandlerId) {
+        self.handler_owners
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(handler_id);
+    }
+
+    /// tag the failures of a dispatch with the owners of the failed handlers and the name
+    /// and labels of the bus.
+    pub(crate) fn with_owners(&self, mut failed: Vec<HandlerFailure>) -> Vec<HandlerFailure> {
+        if failed.is_empty() {
+            return failed;
+        }
+
+        let owners = self
+            .handler_owners
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        for failure in &mut failed {
+            failure.owner = owners
+                .get(&failure.handler_id)
+                .map(|owner| owner.to_string());
+            failure.bus = self.name.clone();
+            failure.labels = self.labels.clone();
+        }
+        failed
+    }
+}
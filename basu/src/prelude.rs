@@ -0,0 +1,8 @@
+#[cfg(feature = "async")]
+pub use crate::async_trait;
+pub use crate::{
+    combinator::HandlerExt,
+    error::{BasuError, PublishError},
+    event::Event,
+    handler_fn, EventBus, Handle, Handler, HandlerId,
+};
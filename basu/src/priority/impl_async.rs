@@ -0,0 +1,28 @@
+use crate::{EventBus, Handler, HandlerId};
+
+impl<T> EventBus<T> {
+    /// Subscribe a handler with a priority. On publish, handlers run in groups of equal
+    /// priority, highest first, and a group starts once every handler of the previous group
+    /// finished. Handlers subscribed with `subscribe` have `DEFAULT_PRIORITY`.
+    ///
+    /// ```no_run
+    /// event_bus
+    ///     .subscribe_with_priority("order.created", 10, Box::new(ValidateOrder))
+    ///     .await;
+    /// event_bus
+    ///     .subscribe_with_priority("order.created", -10, Box::new(AuditLog))
+    ///     .await;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn subscribe_with_priority(
+        &self,
+        event_type: &str,
+        priority: i32,
+        handler: Handler<T>,
+    ) -> HandlerId {
+        let handler_id = self.subscribe(event_type, handler).await;
+        self.set_handler_priority(&handler_id, priority);
+
+        handler_id
+    }
+}
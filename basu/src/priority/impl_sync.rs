@@ -0,0 +1,24 @@
+use crate::{error::BasuError, EventBus, Handler, HandlerId};
+
+impl<T: Sync> EventBus<T> {
+    /// Subscribe a handler with a priority. On publish, handlers run in groups of equal
+    /// priority, highest first, and a group starts once every handler of the previous group
+    /// finished. Handlers subscribed with `subscribe` have `DEFAULT_PRIORITY`.
+    ///
+    /// ```no_run
+    /// event_bus.subscribe_with_priority("order.created", 10, Box::new(ValidateOrder))?;
+    /// event_bus.subscribe_with_priority("order.created", -10, Box::new(AuditLog))?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn subscribe_with_priority(
+        &self,
+        event_type: &str,
+        priority: i32,
+        handler: Handler<T>,
+    ) -> Result<HandlerId, BasuError> {
+        let handler_id = self.subscribe(event_type, handler)?;
+        self.set_handler_priority(&handler_id, priority);
+
+        Ok(handler_id)
+    }
+}
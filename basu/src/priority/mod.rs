@@ -0,0 +1,79 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+
+use crate::{EventBus, Handler, HandlerId};
+
+use std::{
+    borrow::Cow,
+    cmp::Reverse,
+    collections::{BTreeMap, HashMap},
+    sync::{PoisonError, RwLock},
+};
+
+/// Priority of the handlers subscribed without one.
+pub const DEFAULT_PRIORITY: i32 = 0;
+
+/// Priorities of the handlers subscribed with `subscribe_with_priority`.
+pub(crate) type HandlerPriorities = RwLock<HashMap<HandlerId, i32>>;
+
+/// Handlers of equal priority, dispatched together.
+pub(crate) type PriorityGroup<'h, 'a, T> = Cow<'h, [(&'a HandlerId, &'a Handler<T>)]>;
+
+impl<T> EventBus<T> {
+    /// return the priority of a handler, `DEFAULT_PRIORITY` unless it was subscribed with one.
+    pub fn handler_priority(&self, handler_id: &HandlerId) -> i32 {
+        self.handler_priorities
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(handler_id)
+            .copied()
+            .unwrap_or(DEFAULT_PRIORITY)
+    }
+
+    pub(crate) fn set_handler_priority(&self, handler_id: &HandlerId, priority: i32) {
+        self.handler_priorities
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(handler_id.clone(), priority);
+    }
+
+    pub(crate) fn forget_handler_priority(&self, handler_id: &HandlerId) {
+        self.handler_priorities
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(handler_id);
+    }
+
+    /// split handlers into groups of equal priority, highest priority first.
+    pub(crate) fn priority_groups<'h, 'a>(
+        &self,
+        handlers: &'h [(&'a HandlerId, &'a Handler<T>)],
+    ) -> Vec<PriorityGroup<'h, 'a, T>> {
+        let priorities = self
+            .handler_priorities
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        if priorities.is_empty() {
+            return vec![Cow::Borrowed(handlers)];
+        }
+
+        let mut groups: BTreeMap<_, Vec<_>> = BTreeMap::new();
+        for &(handler_id, handler) in handlers {
+            let priority = priorities
+                .get(handler_id)
+                .copied()
+                .unwrap_or(DEFAULT_PRIORITY);
+            groups
+                .entry(Reverse(priority))
+                .or_default()
+                .push((handler_id, handler));
+        }
+
+        match groups.len() {
+            0 | 1 => vec![Cow::Borrowed(handlers)],
+            _ => groups.into_values().map(Cow::Owned).collect(),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{
+    error::{BasuError, ErrorPolicy, PublishError},
+    event::SharedEvent,
+    report::Dispatched,
+    request::Replies,
+};
+
+use std::time::Instant;
+
+/// How a publish path dispatches its events, applied by `publish_with` under the bus lock.
+pub(crate) struct Publishing<T> {
+    /// invoke the handlers one after another on the publishing thread or task
+    pub(crate) inline: bool,
+    /// build the events only when the event type has subscribers
+    pub(crate) lazy: bool,
+    /// handler invocations finishing after the deadline fail with `BasuError::HandlerTimeout`
+    pub(crate) deadline: Option<Instant>,
+    /// replies to collect from the responders during the dispatch
+    pub(crate) replies: Option<Replies>,
+    /// event to retain, kept unless the publish fails
+    pub(crate) retain: Option<SharedEvent<T>>,
+}
+
+impl<T> Default for Publishing<T> {
+    fn default() -> Self {
+        Self {
+            inline: false,
+            lazy: false,
+            deadline: None,
+            replies: None,
+            retain: None,
+        }
+    }
+}
+
+/// Outcome of `publish_with`.
+#[derive(Default)]
+pub(crate) struct Published {
+    /// number of handlers the events were dispatched to
+    pub(crate) subscribers: usize,
+    pub(crate) dispatched: Dispatched,
+    /// error policy the events were dispatched with
+    pub(crate) policy: ErrorPolicy,
+    /// replies collected from the responders
+    pub(crate) replies: Option<Replies>,
+}
+
+impl Published {
+    /// whether the publish fails under its error policy.
+    pub(crate) fn fails(&self) -> bool {
+        !self.dispatched.failed.is_empty() && self.policy != ErrorPolicy::Ignore
+    }
+
+    /// outcome of the publish under its error policy, skipped handlers don't count as
+    /// succeeded.
+    pub(crate) fn check(mut self) -> Result<(), PublishError> {
+        if !self.fails() {
+            return Ok(());
+        }
+
+        if self
+            .dispatched
+            .failed
+            .iter()
+            .all(|failure| matches!(failure.error, BasuError::HandlerTimeout))
+        {
+            return Err(PublishError::Timeout {
+                succeeded: self.dispatched.delivered(self.subscribers),
+                timed_out: self.dispatched.failed,
+            });
+        }
+        match self.policy {
+            ErrorPolicy::FailFast => Err(PublishError::HandlerFailed(Box::new(
+                self.dispatched.failed.swap_remove(0),
+            ))),
+            _ => Err(PublishError::PartialFailure {
+                succeeded: self.dispatched.delivered(self.subscribers),
+                failed: self.dispatched.failed,
+            }),
+        }
+    }
+}
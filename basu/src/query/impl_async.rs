@@ -0,0 +1,152 @@
+use super::{CachePolicy, QueryBus, QueryCache, QueryEntry, QueryHandler};
+use crate::{async_trait, error::BasuError};
+
+use std::{collections::hash_map::Entry, sync::Arc, time::Instant};
+
+/// Implement for query handler
+#[async_trait]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub trait HandleQuery<Q, R>: Send + Sync {
+    /// Handle query which is sent from `QueryBus`
+    async fn handle(&self, query: Q) -> Result<R, BasuError>;
+}
+
+impl<Q, R: Clone> QueryBus<Q, R> {
+    /// Register the handler of a query type.
+    /// Each query type accepts exactly one handler, registering a second one fails with
+    /// `BasuError::HandlerAlreadyRegistered`.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// struct GetOrder {
+    ///     id: u64,
+    /// }
+    ///
+    /// struct GetOrderHandler;
+    ///
+    /// #[async_trait]
+    /// impl HandleQuery<GetOrder, Order> for GetOrderHandler {
+    ///     async fn handle(&self, query: GetOrder) -> Result<Order, BasuError> {
+    ///         // Build the projection here
+    ///         // ...
+    ///     }
+    /// }
+    ///
+    /// let query_bus = QueryBus::<GetOrder, Order>::new();
+    /// query_bus.register("get_order", Box::new(GetOrderHandler)).await?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn register(
+        &self,
+        query_type: &str,
+        handler: QueryHandler<Q, R>,
+    ) -> Result<(), BasuError> {
+        self.insert(query_type, handler, None).await
+    }
+
+    /// Register the handler of a query type whose responses are cached according to `policy`.
+    ///
+    /// ```no_run
+    /// let policy = CachePolicy::new(Duration::from_secs(30), |query: &GetOrder| query.id.to_string());
+    /// query_bus
+    ///     .register_cached("get_order", Box::new(GetOrderHandler), policy)
+    ///     .await?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn register_cached(
+        &self,
+        query_type: &str,
+        handler: QueryHandler<Q, R>,
+        policy: CachePolicy<Q>,
+    ) -> Result<(), BasuError> {
+        self.insert(query_type, handler, Some(policy)).await
+    }
+
+    async fn insert(
+        &self,
+        query_type: &str,
+        handler: QueryHandler<Q, R>,
+        policy: Option<CachePolicy<Q>>,
+    ) -> Result<(), BasuError> {
+        let mut query_handler_map = self.query_handler_map.lock().await;
+
+        match query_handler_map.entry(query_type.to_owned()) {
+            Entry::Occupied(_) => Err(BasuError::HandlerAlreadyRegistered),
+            Entry::Vacant(entry) => {
+                entry.insert(QueryEntry {
+                    handler: Arc::from(handler),
+                    cache: policy.map(|policy| Arc::new(QueryCache::new(policy))),
+                });
+                Ok(())
+            }
+        }
+    }
+
+    /// Unregister the handler of a query type, dropping its cached responses.
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn unregister(&self, query_type: &str) -> Result<(), BasuError> {
+        let mut query_handler_map = self.query_handler_map.lock().await;
+
+        match query_handler_map.remove(query_type) {
+            Some(_) => Ok(()),
+            None => Err(BasuError::QueryTypeNotFound),
+        }
+    }
+
+    /// Send a query to its handler and return the response.
+    /// A fresh cached response is returned without invoking the handler.
+    ///
+    /// ```no_run
+    /// let order = query_bus.query("get_order", GetOrder { id: 7 }).await?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn query(&self, query_type: &str, query: Q) -> Result<R, BasuError> {
+        let (handler, cache) = {
+            let query_handler_map = self.query_handler_map.lock().await;
+            let entry = query_handler_map
+                .get(query_type)
+                .ok_or(BasuError::QueryTypeNotFound)?;
+            (entry.handler.clone(), entry.cache.clone())
+        };
+
+        let cache = match cache {
+            Some(cache) => cache,
+            None => return handler.handle(query).await,
+        };
+
+        let key = (cache.policy.key)(&query);
+        {
+            let responses = cache.responses.lock().await;
+            if let Some((cached_at, response)) = responses.get(&key) {
+                if cached_at.elapsed() < cache.policy.ttl {
+                    return Ok(response.clone());
+                }
+            }
+        }
+
+        let response = handler.handle(query).await?;
+        let mut responses = cache.responses.lock().await;
+        responses.retain(|_, (cached_at, _)| cached_at.elapsed() < cache.policy.ttl);
+        responses.insert(key, (Instant::now(), response.clone()));
+
+        Ok(response)
+    }
+
+    /// Drop all cached responses of a query type.
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn invalidate(&self, query_type: &str) -> Result<(), BasuError> {
+        let cache = {
+            let query_handler_map = self.query_handler_map.lock().await;
+            let entry = query_handler_map
+                .get(query_type)
+                .ok_or(BasuError::QueryTypeNotFound)?;
+            entry.cache.clone()
+        };
+
+        if let Some(cache) = cache {
+            cache.responses.lock().await.clear();
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+
+#[cfg(feature = "async")]
+pub use impl_async::HandleQuery;
+#[cfg(feature = "sync")]
+pub use impl_sync::HandleQuery;
+
+use crate::Mutex;
+
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// Query hanlder
+pub type QueryHandler<Q, R> = Box<dyn HandleQuery<Q, R>>;
+/// Query hanlder map
+pub type QueryHandlerMap<Q, R> = Arc<Mutex<HashMap<String, QueryEntry<Q, R>>>>;
+
+/// Response caching of a query type.
+pub struct CachePolicy<Q> {
+    ttl: Duration,
+    key: Box<dyn Fn(&Q) -> String + Send + Sync>,
+}
+
+impl<Q> CachePolicy<Q> {
+    /// create a new `CachePolicy` which keeps responses for `ttl`, keyed by `key`.
+    ///
+    /// ```no_run
+    /// let policy = CachePolicy::new(Duration::from_secs(30), |query: &GetOrder| query.id.to_string());
+    /// ```
+    pub fn new(ttl: Duration, key: impl Fn(&Q) -> String + Send + Sync + 'static) -> Self {
+        Self {
+            ttl,
+            key: Box::new(key),
+        }
+    }
+}
+
+/// Registered query handler with its optional response cache.
+pub struct QueryEntry<Q, R> {
+    handler: Arc<dyn HandleQuery<Q, R>>,
+    cache: Option<Arc<QueryCache<Q, R>>>,
+}
+
+struct QueryCache<Q, R> {
+    policy: CachePolicy<Q>,
+    responses: Mutex<HashMap<String, (Instant, R)>>,
+}
+
+impl<Q, R> QueryCache<Q, R> {
+    fn new(policy: CachePolicy<Q>) -> Self {
+        Self {
+            policy,
+            responses: Default::default(),
+        }
+    }
+}
+
+/// A `QueryBus` which routes each query type to exactly one handler returning a value.
+pub struct QueryBus<Q, R> {
+    query_handler_map: QueryHandlerMap<Q, R>,
+}
+
+impl<Q, R> QueryBus<Q, R> {
+    /// create a new `QueryBus`
+    pub fn new() -> Self {
+        Self {
+            query_handler_map: Default::default(),
+        }
+    }
+}
+
+impl<Q, R> Default for QueryBus<Q, R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{error::PublishError, EventBus};
+
+use std::cell::RefCell;
+
+thread_local! {
+    /// Buses whose handlers run on this thread, with the event type being dispatched.
+    static DISPATCHING: RefCell<Vec<(usize, String)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Tags the current thread as running a handler of a bus until dropped.
+pub(crate) struct HandlerTag {
+    bus: usize,
+}
+
+impl HandlerTag {
+    pub(crate) fn enter(bus: usize, event_type: &str) -> Self {
+        DISPATCHING.with(|dispatching| {
+            dispatching.borrow_mut().push((bus, event_type.to_owned()));
+        });
+        Self { bus }
+    }
+}
+
+impl Drop for HandlerTag {
+    fn drop(&mut self) {
+        DISPATCHING.with(|dispatching| {
+            let mut dispatching = dispatching.borrow_mut();
+            if let Some(i) = dispatching.iter().rposition(|(bus, _)| *bus == self.bus) {
+                dispatching.remove(i);
+            }
+        });
+    }
+}
+
+impl<T> EventBus<T> {
+    /// Detect publishes from inside a handler of the same bus, which deadlock because a
+    /// publish holds the bus until every handler returned. They fail with
+    /// `PublishError::ReentrantPublish` instead of hanging.
+    ///
+    /// ```no_run
+    /// let event_bus = EventBus::<MyEventData>::new().with_reentrancy_detection();
+    /// ```
+    ///
+    /// **Note:** Threads are tagged while they run a handler, a handler publishing from a
+    /// thread it spawned still deadlocks.
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn with_reentrancy_detection(mut self) -> Self {
+        self.detect_reentrancy = true;
+        self
+    }
+
+    /// identity of the bus in handler tags, if reentrancy detection is enabled.
+    pub(crate) fn reentrancy_key(&self) -> Option<usize> {
+        self.detect_reentrancy
+            .then_some(self as *const Self as usize)
+    }
+
+    /// fail if the current thread is running a handler of this bus.
+    pub(crate) fn check_reentrancy(&self, event_type: &str) -> Result<(), PublishError> {
+        let Some(key) = self.reentrancy_key() else {
+            return Ok(());
+        };
+
+        DISPATCHING.with(|dispatching| {
+            match dispatching
+                .borrow()
+                .iter()
+                .rev()
+                .find(|(bus, _)| *bus == key)
+            {
+                Some((_, dispatching)) => Err(PublishError::ReentrantPublish {
+                    event_type: event_type.to_owned(),
+                    dispatching: dispatching.clone(),
+                }),
+                None => Ok(()),
+            }
+        })
+    }
+}
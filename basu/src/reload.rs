@@ -0,0 +1,202 @@
+use crate::{config::BusConfig, error::BasuError, EventBus};
+
+use std::{
+    fs,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+    time::{Duration, SystemTime},
+};
+
+/// One setting changed by a config reload, `None` when the setting was at its default.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigChange {
+    /// key of the setting, as accepted by `BusConfig::set`
+    pub key: String,
+    /// value before the reload
+    pub old: Option<String>,
+    /// value after the reload
+    pub new: Option<String>,
+}
+
+type ReloadCallback = Arc<dyn Fn(&[ConfigChange]) + Send + Sync>;
+type ErrorCallback = Arc<dyn Fn(BasuError) + Send + Sync>;
+
+/// Watcher which reloads the settings of an `EventBus` from a file whenever it changes.
+///
+/// The file holds one `key = value` setting per line, keyed like `BusConfig::set`, with `#`
+/// starting a comment line. Settings missing from the file are reset to their default.
+///
+/// ```text
+/// # basu.conf
+/// dispatch_strategy = pool:4
+/// dispatch_strategy.redraw = inline
+/// ```
+pub struct ConfigWatcher {
+    path: PathBuf,
+    interval: Duration,
+    on_reload: Option<ReloadCallback>,
+    on_error: Option<ErrorCallback>,
+}
+
+impl ConfigWatcher {
+    /// create a new `ConfigWatcher` of the file at `path`, checked every second.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            interval: Duration::from_secs(1),
+            on_reload: None,
+            on_error: None,
+        }
+    }
+
+    /// set how often the modification time of the file is checked.
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// set a callback which receives the changes of every reload which changed a setting.
+    pub fn on_reload(mut self, callback: impl Fn(&[ConfigChange]) + Send + Sync + 'static) -> Self {
+        self.on_reload = Some(Arc::new(callback));
+        self
+    }
+
+    /// set a callback which receives the errors of reading or applying the file,
+    /// the previous settings stay in effect.
+    pub fn on_error(mut self, callback: impl Fn(BasuError) + Send + Sync + 'static) -> Self {
+        self.on_error = Some(Arc::new(callback));
+        self
+    }
+
+    fn report(&self, err: BasuError) {
+        if let Some(on_error) = &self.on_error {
+            on_error(err);
+        }
+    }
+
+    fn modified(&self) -> Result<SystemTime, BasuError> {
+        fs::metadata(&self.path)
+            .and_then(|metadata| metadata.modified())
+            .map_err(|err| self.io_error(err))
+    }
+
+    fn load(&self) -> Result<BusConfig, BasuError> {
+        let contents = fs::read_to_string(&self.path).map_err(|err| self.io_error(err))?;
+        parse_config(&contents)
+    }
+
+    fn io_error(&self, err: std::io::Error) -> BasuError {
+        BasuError::InvalidConfig(format!("{}: {}", self.path.display(), err))
+    }
+}
+
+/// parse the contents of a config file, settings missing from it keep their default.
+pub(crate) fn parse_config(contents: &str) -> Result<BusConfig, BasuError> {
+    let mut config = BusConfig::default();
+    for line in contents.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (key, value) = line.split_once('=').ok_or_else(|| {
+            BasuError::InvalidConfig(format!("expected `key = value`, found `{}`", line))
+        })?;
+        config.set(key.trim(), value.trim())?;
+    }
+
+    Ok(config)
+}
+
+/// Running `ConfigWatcher`, which stops once this is dropped.
+#[derive(Debug)]
+pub struct ConfigWatch {
+    stop: Arc<AtomicBool>,
+}
+
+impl Drop for ConfigWatch {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+fn diff(old: &BusConfig, new: &BusConfig) -> Vec<ConfigChange> {
+    let (old, new) = (old.entries(), new.entries());
+    let mut keys: Vec<_> = old.keys().chain(new.keys()).collect();
+    keys.sort();
+    keys.dedup();
+
+    keys.into_iter()
+        .filter(|key| old.get(*key) != new.get(*key))
+        .map(|key| ConfigChange {
+            key: key.clone(),
+            old: old.get(key).cloned(),
+            new: new.get(key).cloned(),
+        })
+        .collect()
+}
+
+impl<T: Send + 'static> EventBus<T> {
+    /// Start a `ConfigWatcher` applying its file to the bus through `reconfigure`, once
+    /// right away and then whenever the file's modification time changes.
+    /// It runs on its own thread until the returned `ConfigWatch` or the bus is dropped.
+    ///
+    /// ```no_run
+    /// let event_bus = Arc::new(EventBus::<MyEventData>::new());
+    /// let _watch = event_bus.watch_config(
+    ///     ConfigWatcher::new("/etc/app/basu.conf")
+    ///         .on_reload(|changes| log::info!("basu config reloaded: {:?}", changes))
+    ///         .on_error(|err| log::warn!("basu config not applied: {}", err)),
+    /// );
+    /// ```
+    pub fn watch_config(self: &Arc<Self>, watcher: ConfigWatcher) -> ConfigWatch {
+        let event_bus = Arc::downgrade(self);
+        let stop = Arc::new(AtomicBool::new(false));
+        let stopped = stop.clone();
+
+        thread::spawn(move || {
+            let mut last_modified = None;
+            let mut failing = false;
+            while !stopped.load(Ordering::Relaxed) {
+                let Some(event_bus) = event_bus.upgrade() else {
+                    break;
+                };
+
+                let reloaded = watcher.modified().and_then(|modified| {
+                    if last_modified == Some(modified) {
+                        return Ok(());
+                    }
+                    last_modified = Some(modified);
+
+                    let config = watcher.load()?;
+                    let changes = diff(&event_bus.config(), &config);
+                    if !changes.is_empty() {
+                        event_bus.reconfigure(|current| *current = config)?;
+                        if let Some(on_reload) = &watcher.on_reload {
+                            on_reload(&changes);
+                        }
+                    }
+
+                    Ok(())
+                });
+                drop(event_bus);
+
+                match reloaded {
+                    Ok(()) => failing = false,
+                    // report a failure once rather than on every check
+                    Err(err) if !failing => {
+                        failing = true;
+                        watcher.report(err);
+                    }
+                    Err(_) => {}
+                }
+
+                thread::sleep(watcher.interval);
+            }
+        });
+
+        ConfigWatch { stop }
+    }
+}
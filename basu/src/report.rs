@@ -0,0 +1,84 @@
+use crate::{error::BasuError, HandlerId};
+
+use std::{collections::BTreeMap, time::Duration};
+
+/// Error of one handler during a dispatch.
+#[derive(Debug)]
+pub struct HandlerFailure {
+    /// id of the failed handler
+    pub handler_id: HandlerId,
+    /// owner the handler was subscribed with, if any
+    pub owner: Option<String>,
+    /// name of the bus the handler is subscribed to, if the bus is named
+    pub bus: Option<String>,
+    /// instrumentation labels of the bus the handler is subscribed to
+    pub labels: BTreeMap<String, String>,
+    /// error returned by the handler
+    pub error: BasuError,
+}
+
+/// Outcome of a dispatch, returned by `publish_report` so callers can log fan-out results.
+#[derive(Debug)]
+pub struct DispatchReport {
+    /// number of handlers which handled the event
+    pub delivered: usize,
+    /// handlers which returned an error
+    pub failed: Vec<HandlerFailure>,
+    /// number of handlers which were not invoked, e.g. because their circuit is open
+    pub skipped: usize,
+    /// time taken by the dispatch
+    pub duration: Duration,
+}
+
+impl DispatchReport {
+    /// whether every handler handled the event.
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Failures of a dispatch and the number of handlers it skipped.
+#[derive(Debug, Default)]
+pub(crate) struct Dispatched {
+    pub(crate) failed: Vec<HandlerFailure>,
+    pub(crate) skipped: usize,
+}
+
+impl Dispatched {
+    /// record the outcome of a handler, `None` when it was skipped without being invoked.
+    pub(crate) fn record(
+        &mut self,
+        handler_id: &HandlerId,
+        outcome: Option<Result<(), BasuError>>,
+    ) {
+        match outcome {
+            Some(Ok(())) => {}
+            Some(Err(error)) => self.failed.push(HandlerFailure {
+                handler_id: handler_id.clone(),
+                owner: None,
+                bus: None,
+                labels: BTreeMap::new(),
+                error,
+            }),
+            None => self.skipped += 1,
+        }
+    }
+
+    /// return how many of the `handlers` dispatched to handled the event.
+    pub(crate) fn delivered(&self, handlers: usize) -> usize {
+        handlers - self.failed.len() - self.skipped
+    }
+}
+
+impl<'a> FromIterator<(&'a HandlerId, Option<Result<(), BasuError>>)> for Dispatched {
+    fn from_iter<I>(outcomes: I) -> Self
+    where
+        I: IntoIterator<Item = (&'a HandlerId, Option<Result<(), BasuError>>)>,
+    {
+        let mut dispatched = Self::default();
+        for (handler_id, outcome) in outcomes {
+            dispatched.record(handler_id, outcome);
+        }
+        dispatched
+    }
+}
@@ -0,0 +1,154 @@
+use super::{Gathered, Replies, ReplySlot, RequestHandler};
+use crate::{
+    async_trait,
+    error::{BasuError, PublishError},
+    event::Event,
+    publish::Publishing,
+    EventBus, Handle, HandlerId,
+};
+
+use std::{
+    slice,
+    time::{Duration, Instant},
+};
+
+/// Implement for event handler replying to requests
+#[async_trait]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub trait HandleRequest<T, R>: Send + Sync {
+    /// Handle event which is published from `EventBus`, the reply is returned to `publish_request`
+    async fn handle(&self, event: &Event<T>) -> Result<Option<R>, BasuError>;
+}
+
+/// Handler which hands the replies of a `HandleRequest` handler to the request.
+struct Responder<T, R> {
+    handler: RequestHandler<T, R>,
+    replies: ReplySlot,
+}
+
+#[async_trait]
+impl<T: Sync, R: Send + 'static> Handle<T> for Responder<T, R> {
+    async fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        if let Some(reply) = self.handler.handle(event).await? {
+            self.replies.push(reply);
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Sync + 'static> EventBus<T> {
+    /// Subscribe a `HandleRequest` handler whose replies are returned to `publish_request`.
+    /// It receives plain publishes too, their replies are dropped.
+    ///
+    /// ```no_run
+    /// struct PriceQuote;
+    ///
+    /// #[async_trait]
+    /// impl HandleRequest<Order, u64> for PriceQuote {
+    ///     async fn handle(&self, event: &Event<Order>) -> Result<Option<u64>, BasuError> {
+    ///         Ok(Some(quote(&event.data)))
+    ///     }
+    /// }
+    ///
+    /// event_bus.subscribe_responder("order.quote", Box::new(PriceQuote)).await;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn subscribe_responder<R: Send + 'static>(
+        &self,
+        event_type: &str,
+        handler: RequestHandler<T, R>,
+    ) -> HandlerId {
+        let responder = Responder {
+            handler,
+            replies: self.replies.clone(),
+        };
+
+        self.subscribe(event_type, Box::new(responder)).await
+    }
+
+    /// Publish an event as a request and return the first reply of the handlers subscribed
+    /// with `subscribe_responder`, or `PublishError::NoReply` when none of them replied.
+    ///
+    /// ```no_run
+    /// let price: u64 = event_bus.publish_request("order.quote", &event).await?;
+    /// ```
+    ///
+    /// **Note:** The errors of failed handlers are only returned when no handler replied.
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn publish_request<R: Send + 'static>(
+        &self,
+        event_type: &str,
+        event_data: &Event<T>,
+    ) -> Result<R, PublishError> {
+        self.publish_request_all(event_type, event_data)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or(PublishError::NoReply)
+    }
+
+    /// Publish an event as a request and return every reply in the order they arrived,
+    /// so the publisher can choose among them.
+    ///
+    /// ```no_run
+    /// let prices: Vec<u64> = event_bus.publish_request_all("order.quote", &event).await?;
+    /// let best = prices.into_iter().min();
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn publish_request_all<R: Send + 'static>(
+        &self,
+        event_type: &str,
+        event_data: &Event<T>,
+    ) -> Result<Vec<R>, PublishError> {
+        let mut published = self
+            .publish_with(
+                event_type,
+                || slice::from_ref(event_data),
+                Publishing {
+                    replies: Some(Replies::new::<R>(None)),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let replies = published.take_replies::<R>();
+
+        if replies.is_empty() {
+            published.check()?;
+        }
+        Ok(replies)
+    }
+
+    /// Publish an event to every handler and gather the replies which arrive within `timeout`.
+    /// Handlers still running at the deadline are cancelled and listed as timed out.
+    ///
+    /// ```no_run
+    /// let quotes: Gathered<u64> = event_bus
+    ///     .publish_gather("order.quote", &event, Duration::from_millis(200))
+    ///     .await?;
+    /// let best = quotes.replies.into_iter().min();
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn publish_gather<R: Send + 'static>(
+        &self,
+        event_type: &str,
+        event_data: &Event<T>,
+        timeout: Duration,
+    ) -> Result<Gathered<R>, PublishError> {
+        let deadline = Instant::now() + timeout;
+        let mut published = self
+            .publish_with(
+                event_type,
+                || slice::from_ref(event_data),
+                Publishing {
+                    deadline: Some(deadline),
+                    replies: Some(Replies::new::<R>(Some(deadline))),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let replies = published.take_replies::<R>();
+
+        Ok(Gathered::new(replies, published.dispatched.failed))
+    }
+}
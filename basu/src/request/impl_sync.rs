@@ -0,0 +1,146 @@
+use super::{Gathered, Replies, ReplySlot, RequestHandler};
+use crate::{
+    error::{BasuError, PublishError},
+    event::Event,
+    publish::Publishing,
+    EventBus, Handle, HandlerId,
+};
+
+use std::{
+    slice,
+    time::{Duration, Instant},
+};
+
+/// Implement for event handler replying to requests
+#[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+pub trait HandleRequest<T, R>: Send + Sync {
+    /// Handle event which is published from `EventBus`, the reply is returned to `publish_request`
+    fn handle(&self, event: &Event<T>) -> Result<Option<R>, BasuError>;
+}
+
+/// Handler which hands the replies of a `HandleRequest` handler to the request.
+struct Responder<T, R> {
+    handler: RequestHandler<T, R>,
+    replies: ReplySlot,
+}
+
+impl<T: Sync, R: Send + 'static> Handle<T> for Responder<T, R> {
+    fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        if let Some(reply) = self.handler.handle(event)? {
+            self.replies.push(reply);
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Sync + 'static> EventBus<T> {
+    /// Subscribe a `HandleRequest` handler whose replies are returned to `publish_request`.
+    /// It receives plain publishes too, their replies are dropped.
+    ///
+    /// ```no_run
+    /// struct PriceQuote;
+    ///
+    /// impl HandleRequest<Order, u64> for PriceQuote {
+    ///     fn handle(&self, event: &Event<Order>) -> Result<Option<u64>, BasuError> {
+    ///         Ok(Some(quote(&event.data)))
+    ///     }
+    /// }
+    ///
+    /// event_bus.subscribe_responder("order.quote", Box::new(PriceQuote))?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn subscribe_responder<R: Send + 'static>(
+        &self,
+        event_type: &str,
+        handler: RequestHandler<T, R>,
+    ) -> Result<HandlerId, BasuError> {
+        let responder = Responder {
+            handler,
+            replies: self.replies.clone(),
+        };
+
+        self.subscribe(event_type, Box::new(responder))
+    }
+
+    /// Publish an event as a request and return the first reply of the handlers subscribed
+    /// with `subscribe_responder`, or `PublishError::NoReply` when none of them replied.
+    ///
+    /// ```no_run
+    /// let price: u64 = event_bus.publish_request("order.quote", &event)?;
+    /// ```
+    ///
+    /// **Note:** The errors of failed handlers are only returned when no handler replied.
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn publish_request<R: Send + 'static>(
+        &self,
+        event_type: &str,
+        event_data: &Event<T>,
+    ) -> Result<R, PublishError> {
+        self.publish_request_all(event_type, event_data)?
+            .into_iter()
+            .next()
+            .ok_or(PublishError::NoReply)
+    }
+
+    /// Publish an event as a request and return every reply in the order they arrived,
+    /// so the publisher can choose among them.
+    ///
+    /// ```no_run
+    /// let prices: Vec<u64> = event_bus.publish_request_all("order.quote", &event)?;
+    /// let best = prices.into_iter().min();
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn publish_request_all<R: Send + 'static>(
+        &self,
+        event_type: &str,
+        event_data: &Event<T>,
+    ) -> Result<Vec<R>, PublishError> {
+        let mut published = self.publish_with(
+            event_type,
+            || slice::from_ref(event_data),
+            Publishing {
+                replies: Some(Replies::new::<R>(None)),
+                ..Default::default()
+            },
+        )?;
+        let replies = published.take_replies::<R>();
+
+        if replies.is_empty() {
+            published.check()?;
+        }
+        Ok(replies)
+    }
+
+    /// Publish an event to every handler and gather the replies which arrive within `timeout`.
+    /// Handlers finishing after the deadline are listed as timed out and their replies dropped.
+    ///
+    /// ```no_run
+    /// let quotes: Gathered<u64> =
+    ///     event_bus.publish_gather("order.quote", &event, Duration::from_millis(200))?;
+    /// let best = quotes.replies.into_iter().min();
+    /// ```
+    ///
+    /// **Note:** Running handlers cannot be cancelled, the call returns once every handler finished.
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn publish_gather<R: Send + 'static>(
+        &self,
+        event_type: &str,
+        event_data: &Event<T>,
+        timeout: Duration,
+    ) -> Result<Gathered<R>, PublishError> {
+        let deadline = Instant::now() + timeout;
+        let mut published = self.publish_with(
+            event_type,
+            || slice::from_ref(event_data),
+            Publishing {
+                deadline: Some(deadline),
+                replies: Some(Replies::new::<R>(Some(deadline))),
+                ..Default::default()
+            },
+        )?;
+        let replies = published.take_replies::<R>();
+
+        Ok(Gathered::new(replies, published.dispatched.failed))
+    }
+}
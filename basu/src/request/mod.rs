@@ -0,0 +1,118 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+
+#[cfg(feature = "async")]
+pub use impl_async::HandleRequest;
+#[cfg(feature = "sync")]
+pub use impl_sync::HandleRequest;
+
+use crate::{error::BasuError, publish::Published, report::HandlerFailure, HandlerId};
+
+use std::{
+    any::Any,
+    sync::{Arc, Mutex, PoisonError},
+    time::Instant,
+};
+
+/// Request handler
+pub type RequestHandler<T, R> = Box<dyn HandleRequest<T, R>>;
+
+/// Replies of a scatter-gather publish, returned by `publish_gather`.
+#[derive(Debug)]
+pub struct Gathered<R> {
+    /// replies which arrived before the deadline, in the order they arrived
+    pub replies: Vec<R>,
+    /// handlers which did not finish before the deadline
+    pub timed_out: Vec<HandlerId>,
+    /// handlers which returned an error before the deadline
+    pub failed: Vec<HandlerFailure>,
+}
+
+impl<R> Gathered<R> {
+    /// split the failures of a dispatch into timed out and failed handlers.
+    fn new(replies: Vec<R>, failures: Vec<HandlerFailure>) -> Self {
+        let (timed_out, failed): (Vec<_>, _) = failures
+            .into_iter()
+            .partition(|failure| matches!(failure.error, BasuError::HandlerTimeout));
+
+        Self {
+            replies,
+            timed_out: timed_out
+                .into_iter()
+                .map(|failure| failure.handler_id)
+                .collect(),
+            failed,
+        }
+    }
+
+    /// whether every handler finished before the deadline without an error.
+    pub fn is_complete(&self) -> bool {
+        self.timed_out.is_empty() && self.failed.is_empty()
+    }
+}
+
+/// Replies collected for the request being dispatched.
+pub(crate) struct Replies {
+    /// `Vec` of the reply type
+    replies: Box<dyn Any + Send>,
+    /// replies arriving later are dropped
+    deadline: Option<Instant>,
+}
+
+impl Replies {
+    /// collect replies of type `R` which arrive before `deadline`, if any.
+    pub(crate) fn new<R: Send + 'static>(deadline: Option<Instant>) -> Self {
+        Self {
+            replies: Box::new(Vec::<R>::new()),
+            deadline,
+        }
+    }
+}
+
+impl Published {
+    /// take the replies of type `R` collected during the dispatch.
+    fn take_replies<R: Send + 'static>(&mut self) -> Vec<R> {
+        self.replies
+            .take()
+            .and_then(|replies| replies.replies.downcast::<Vec<R>>().ok())
+            .map(|replies| *replies)
+            .unwrap_or_default()
+    }
+}
+
+/// Replies of the request being dispatched.
+/// Publishes are serialized by the bus lock, so at most one request collects replies.
+#[derive(Clone, Default)]
+pub(crate) struct ReplySlot(Arc<Mutex<Option<Replies>>>);
+
+impl ReplySlot {
+    /// start collecting `replies`.
+    pub(crate) fn open(&self, replies: Replies) {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner) = Some(replies);
+    }
+
+    /// stop collecting replies and return the collected ones.
+    pub(crate) fn close(&self) -> Option<Replies> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner).take()
+    }
+
+    /// keep a reply if a request with replies of type `R` is being dispatched.
+    fn push<R: Send + 'static>(&self, reply: R) {
+        if let Some(replies) = self
+            .0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_mut()
+            .filter(|replies| {
+                replies
+                    .deadline
+                    .is_none_or(|deadline| Instant::now() <= deadline)
+            })
+            .and_then(|replies| replies.replies.downcast_mut::<Vec<R>>())
+        {
+            replies.push(reply);
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{
+    error::PublishError,
+    event::{Event, SharedEvent},
+    publish::Publishing,
+    EventBus,
+};
+
+use std::{slice, sync::Arc};
+
+impl<T: Send + Sync + 'static> EventBus<T> {
+    /// Publish an event and retain it as the current value of `event_type`, like an MQTT
+    /// retained message. Handlers subscribing afterwards receive it right away, before any
+    /// later event. Publishing without subscribers succeeds, the event is kept for them.
+    /// When the publish fails otherwise, e.g. a handler fails, the previously retained event
+    /// is kept instead.
+    ///
+    /// ```no_run
+    /// event_bus.publish_retained("config", Event::new(config))?;
+    ///
+    /// // receives the config immediately
+    /// event_bus.subscribe("config", Box::new(ConfigHandler))?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn publish_retained(
+        &self,
+        event_type: &str,
+        event_data: Event<T>,
+    ) -> Result<(), PublishError> {
+        let event: SharedEvent<T> = Arc::new(event_data);
+        self.publish_with(
+            event_type,
+            || slice::from_ref(event.event()),
+            Publishing {
+                retain: Some(event.clone()),
+                ..Default::default()
+            },
+        )?
+        .check()
+    }
+}
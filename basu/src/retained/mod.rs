@@ -0,0 +1,70 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+
+use crate::{event::SharedEvent, EventBus};
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+/// Retained event of each event type.
+pub(crate) type RetainedEvents<T> = Mutex<HashMap<String, SharedEvent<T>>>;
+
+impl<T> EventBus<T> {
+    /// return whether an event of `event_type` is retained.
+    pub fn has_retained(&self, event_type: &str) -> bool {
+        self.retained
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .contains_key(event_type)
+    }
+
+    /// forget the retained event of `event_type`, later subscribers no longer receive it.
+    pub fn clear_retained(&self, event_type: &str) {
+        self.retained
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(event_type);
+    }
+
+    pub(crate) fn retained_event(&self, event_type: &str) -> Option<SharedEvent<T>> {
+        self.retained
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(event_type)
+            .cloned()
+    }
+
+    /// retain `event` and return the event it replaces.
+    pub(crate) fn retain(&self, event_type: &str, event: SharedEvent<T>) -> Option<SharedEvent<T>> {
+        self.retained
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(event_type.to_owned(), event)
+    }
+
+    /// retain `previous` again in place of `event` after the publish of `event` failed,
+    /// unless a later event replaced it meanwhile.
+    pub(crate) fn unretain(
+        &self,
+        event_type: &str,
+        event: &SharedEvent<T>,
+        previous: Option<SharedEvent<T>>,
+    ) {
+        let mut retained = self.retained.lock().unwrap_or_else(PoisonError::into_inner);
+        if !retained
+            .get(event_type)
+            .is_some_and(|current| Arc::ptr_eq(current, event))
+        {
+            return;
+        }
+
+        match previous {
+            Some(previous) => retained.insert(event_type.to_owned(), previous),
+            None => retained.remove(event_type),
+        };
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{error::PublishError, event::Event, EventBus};
+
+use futures::{
+    future::{poll_fn, BoxFuture},
+    stream::FuturesUnordered,
+    task::AtomicWaker,
+    StreamExt,
+};
+use std::{
+    future::Future,
+    pin::pin,
+    sync::{Arc, Mutex, PoisonError},
+    task::Poll,
+};
+
+/// Publishes started inside a scope and not yet picked up by the scope driver.
+struct Pending<'a> {
+    futures: Mutex<Vec<BoxFuture<'a, Result<(), PublishError>>>>,
+    waker: AtomicWaker,
+}
+
+/// Handle to publish events whose dispatch is tied to an enclosing `EventBus::scope`.
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub struct Scope<'a, T> {
+    event_bus: &'a EventBus<T>,
+    pending: Arc<Pending<'a>>,
+}
+
+impl<T> Clone for Scope<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            event_bus: self.event_bus,
+            pending: self.pending.clone(),
+        }
+    }
+}
+
+impl<'a, T: Send + Sync> Scope<'a, T> {
+    /// Start publishing an event without waiting for its handlers.
+    /// The dispatch runs concurrently with the scope body and is awaited when the scope ends.
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub fn publish(&self, event_type: &str, event: Event<T>) {
+        let event_bus = self.event_bus;
+        let event_type = event_type.to_owned();
+        let future = Box::pin(async move { event_bus.publish(&event_type, &event).await });
+
+        self.pending
+            .futures
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(future);
+        self.pending.waker.wake();
+    }
+}
+
+impl<T: Send + Sync> EventBus<T> {
+    /// Run `f` with a `Scope` whose publishes can't outlive the call.
+    /// The scope resolves once `f` has finished and every publish started through the scope
+    /// has been handled; dropping the scope future cancels the publishes still in flight.
+    /// It returns the first error of those publishes, if any.
+    ///
+    /// ```no_run
+    /// let response = event_bus
+    ///     .scope(|scoped| async move {
+    ///         scoped.publish("audit", Event::new(audit_entry));
+    ///         scoped.publish("metrics", Event::new(sample));
+    ///         build_response().await
+    ///     })
+    ///     .await?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn scope<'a, F, Fut, R>(&'a self, f: F) -> Result<R, PublishError>
+    where
+        F: FnOnce(Scope<'a, T>) -> Fut,
+        Fut: Future<Output = R>,
+    {
+        let pending = Arc::new(Pending {
+            futures: Mutex::new(Vec::new()),
+            waker: AtomicWaker::new(),
+        });
+        let mut body = pin!(f(Scope {
+            event_bus: self,
+            pending: pending.clone(),
+        }));
+
+        let mut in_flight = FuturesUnordered::new();
+        let mut output = None;
+        let mut error = None;
+        poll_fn(|cx| {
+            pending.waker.register(cx.waker());
+            if output.is_none() {
+                if let Poll::Ready(value) = body.as_mut().poll(cx) {
+                    output = Some(value);
+                }
+            }
+
+            in_flight.extend(
+                pending
+                    .futures
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .drain(..),
+            );
+            while let Poll::Ready(Some(result)) = in_flight.poll_next_unpin(cx) {
+                if let Err(err) = result {
+                    error.get_or_insert(err);
+                }
+            }
+
+            match output {
+                Some(_) if in_flight.is_empty() => Poll::Ready(()),
+                _ => Poll::Pending,
+            }
+        })
+        .await;
+
+        match error {
+            Some(err) => Err(err),
+            None => Ok(output.expect("scope body has finished")),
+        }
+    }
+}
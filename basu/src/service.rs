@@ -0,0 +1,111 @@
+use crate::{
+    async_trait,
+    error::{BasuError, PublishError},
+    event::Event,
+    EventBus, Handle, Handler,
+};
+
+use futures::future::{poll_fn, BoxFuture};
+use std::{
+    error::Error as StdError,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tower_service::Service;
+
+/// `tower::Service` publishing every event it is called with to one event type.
+pub struct Publisher<T> {
+    event_bus: Arc<EventBus<T>>,
+    event_type: Arc<str>,
+}
+
+impl<T> Clone for Publisher<T> {
+    fn clone(&self) -> Self {
+        Self {
+            event_bus: self.event_bus.clone(),
+            event_type: self.event_type.clone(),
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Service<Event<T>> for Publisher<T> {
+    type Response = ();
+    type Error = PublishError;
+    type Future = BoxFuture<'static, Result<(), PublishError>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, event: Event<T>) -> Self::Future {
+        let event_bus = self.event_bus.clone();
+        let event_type = self.event_type.clone();
+        Box::pin(async move { event_bus.publish(&event_type, &event).await })
+    }
+}
+
+/// Handler calling a `tower::Service` with a clone of every event.
+struct ServiceHandler<S>(S);
+
+#[async_trait]
+impl<T, S> Handle<T> for ServiceHandler<S>
+where
+    T: Clone + Send + Sync + 'static,
+    S: Service<Event<T>> + Clone + Send + Sync + 'static,
+    S::Error: Into<Box<dyn StdError + Send + Sync>>,
+    S::Future: Send,
+{
+    async fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        let mut service = self.0.clone();
+        let to_error = |err: S::Error| BasuError::from(anyhow::anyhow!(err.into()));
+
+        poll_fn(|cx| service.poll_ready(cx))
+            .await
+            .map_err(to_error)?;
+        service.call(event.clone()).await.map_err(to_error)?;
+
+        Ok(())
+    }
+}
+
+impl<T: Send + Sync + 'static> EventBus<T> {
+    /// Create a `tower::Service` publishing to `event_type`, so middleware stacks such as
+    /// rate limiting or load shedding can be put in front of the bus.
+    ///
+    /// ```no_run
+    /// let event_bus = Arc::new(EventBus::<Order>::new());
+    /// let publisher = ServiceBuilder::new()
+    ///     .rate_limit(100, Duration::from_secs(1))
+    ///     .service(event_bus.publisher("order"));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "tower")))]
+    pub fn publisher(self: &Arc<Self>, event_type: &str) -> Publisher<T> {
+        Publisher {
+            event_bus: self.clone(),
+            event_type: Arc::from(event_type),
+        }
+    }
+}
+
+/// Wrap a `tower::Service` as a handler, so middleware stacks such as retry or timeouts
+/// can be reused around event handling. The service is cloned for every event and its
+/// response is dropped.
+///
+/// ```no_run
+/// let handler = service_handler(
+///     ServiceBuilder::new()
+///         .timeout(Duration::from_secs(1))
+///         .service(OrderService::new()),
+/// );
+/// let handler_id = event_bus.subscribe("order", handler).await;
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "tower")))]
+pub fn service_handler<T, S>(service: S) -> Handler<T>
+where
+    T: Clone + Send + Sync + 'static,
+    S: Service<Event<T>> + Clone + Send + Sync + 'static,
+    S::Error: Into<Box<dyn StdError + Send + Sync>>,
+    S::Future: Send,
+{
+    Box::new(ServiceHandler(service))
+}
@@ -0,0 +1,132 @@
+use crate::{config::BusConfig, named::HandlerNames, owner::HandlerOwners, EventBus, HandlerId};
+
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, PoisonError},
+    time::Duration,
+};
+
+/// A handler invocation which took longer than the slow handler threshold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlowHandler {
+    /// event type the handler was invoked for
+    pub event_type: String,
+    /// id of the handler
+    pub handler_id: HandlerId,
+    /// name the handler was subscribed with, if any
+    pub handler_name: Option<String>,
+    /// owner the handler was subscribed with, if any
+    pub handler_owner: Option<String>,
+    /// name of the bus the handler is subscribed to, if the bus is named
+    pub bus: Option<String>,
+    /// instrumentation labels of the bus the handler is subscribed to
+    pub labels: BTreeMap<String, String>,
+    /// time the invocation took
+    pub elapsed: Duration,
+    /// threshold the invocation exceeded
+    pub threshold: Duration,
+}
+
+/// Callback notified of slow handler invocations.
+pub type SlowHandlerHook = Arc<dyn Fn(&SlowHandler) + Send + Sync>;
+
+impl<T> EventBus<T> {
+    /// set how long handlers may take before they're reported to the slow handler hooks.
+    ///
+    /// ```no_run
+    /// let event_bus = EventBus::<MyEventData>::new()
+    ///     .with_slow_handler_threshold(Duration::from_millis(200))
+    ///     .with_slow_handler_hook(|slow| {
+    ///         log::warn!(
+    ///             "handler {:?} of `{}` owned by {:?} took {:?}",
+    ///             slow.handler_name, slow.event_type, slow.handler_owner, slow.elapsed
+    ///         );
+    ///     });
+    /// ```
+    pub fn with_slow_handler_threshold(self, threshold: Duration) -> Self {
+        self.configured(|config| config.set_slow_handler_threshold(Some(threshold)))
+    }
+
+    /// set the slow handler threshold of a single event type.
+    pub fn with_topic_slow_handler_threshold(self, event_type: &str, threshold: Duration) -> Self {
+        self.configured(|config| {
+            config.set_topic_slow_handler_threshold(event_type, Some(threshold))
+        })
+    }
+
+    /// add a hook which is notified whenever a handler exceeds its slow handler threshold.
+    ///
+    /// **Note:** Hooks run on the dispatching task or thread right after the slow invocation,
+    /// while the bus is locked, so they must not publish to the same bus.
+    pub fn with_slow_handler_hook(
+        mut self,
+        hook: impl Fn(&SlowHandler) + Send + Sync + 'static,
+    ) -> Self {
+        self.slow_handler_hooks.push(Arc::new(hook));
+        self
+    }
+
+    /// watch the invocations of a dispatch, if a threshold and hooks are set.
+    pub(crate) fn slow_watch<'a>(
+        &'a self,
+        event_type: &'a str,
+        config: &BusConfig,
+    ) -> Option<SlowWatch<'a>> {
+        if self.slow_handler_hooks.is_empty() {
+            return None;
+        }
+
+        Some(SlowWatch {
+            bus: self.name.as_deref(),
+            labels: &self.labels,
+            event_type,
+            threshold: config.slow_handler_threshold(event_type)?,
+            hooks: &self.slow_handler_hooks,
+            names: &self.handler_names,
+            owners: &self.handler_owners,
+        })
+    }
+}
+
+/// Reports the slow invocations of a single dispatch.
+pub(crate) struct SlowWatch<'a> {
+    bus: Option<&'a str>,
+    labels: &'a BTreeMap<String, String>,
+    event_type: &'a str,
+    threshold: Duration,
+    hooks: &'a [SlowHandlerHook],
+    names: &'a HandlerNames,
+    owners: &'a HandlerOwners,
+}
+
+impl SlowWatch<'_> {
+    pub(crate) fn observe(&self, handler_id: &HandlerId, elapsed: Duration) {
+        if elapsed <= self.threshold {
+            return;
+        }
+
+        let slow = SlowHandler {
+            event_type: self.event_type.to_owned(),
+            handler_id: handler_id.clone(),
+            handler_name: self
+                .names
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .get(handler_id)
+                .map(|name| name.to_string()),
+            handler_owner: self
+                .owners
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .get(handler_id)
+                .map(|owner| owner.to_string()),
+            bus: self.bus.map(str::to_owned),
+            labels: self.labels.clone(),
+            elapsed,
+            threshold: self.threshold,
+        };
+        for hook in self.hooks {
+            hook(&slow);
+        }
+    }
+}
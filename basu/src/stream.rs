@@ -0,0 +1,313 @@
+use crate::{
+    async_trait,
+    error::{BasuError, PublishError},
+    event::Event,
+    EventBus, Handle, HandlerId,
+};
+
+use futures::{future::BoxFuture, stream::FusedStream, task::AtomicWaker, Sink, Stream};
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+use tokio::sync::Notify;
+
+/// `Sink` publishing every event sent into it to one event type, one publish at a time.
+pub struct EventSink<T> {
+    event_bus: Arc<EventBus<T>>,
+    event_type: Arc<str>,
+    in_flight: Option<BoxFuture<'static, Result<(), PublishError>>>,
+}
+
+impl<T> EventSink<T> {
+    fn poll_in_flight(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), PublishError>> {
+        match self.in_flight.as_mut() {
+            Some(publish) => {
+                let result = futures::ready!(publish.as_mut().poll(cx));
+                self.in_flight = None;
+                Poll::Ready(result)
+            }
+            None => Poll::Ready(Ok(())),
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Sink<Event<T>> for EventSink<T> {
+    type Error = PublishError;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.get_mut().poll_in_flight(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, event: Event<T>) -> Result<(), Self::Error> {
+        let this = self.get_mut();
+        let event_bus = this.event_bus.clone();
+        let event_type = this.event_type.clone();
+        this.in_flight = Some(Box::pin(async move {
+            event_bus.publish(&event_type, &event).await
+        }));
+
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.get_mut().poll_in_flight(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.get_mut().poll_in_flight(cx)
+    }
+}
+
+/// What the handler feeding an `EventStream` does with an event when the buffer is full.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LagPolicy {
+    /// hold up publishing until the stream catches up
+    #[default]
+    Block,
+    /// drop the oldest buffered event, so the stream skips ahead to the latest events
+    SkipToLatest,
+    /// end the stream once the events already buffered are taken
+    Disconnect,
+}
+
+/// How far an `EventStream` is behind the publishes of its event type.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StreamLag {
+    /// number of buffered events not taken from the stream yet
+    pub behind: usize,
+    /// time the oldest buffered event has been waiting
+    pub oldest_pending: Option<Duration>,
+    /// number of events dropped by `LagPolicy::SkipToLatest`
+    pub skipped: u64,
+}
+
+/// Events buffered between the handler feeding an `EventStream` and the stream.
+struct Buffer<T> {
+    state: Mutex<BufferState<T>>,
+    capacity: usize,
+    policy: LagPolicy,
+    readable: AtomicWaker,
+    writable: Notify,
+}
+
+struct BufferState<T> {
+    events: VecDeque<(Instant, Event<T>)>,
+    skipped: u64,
+    /// no more events will be buffered
+    closed: bool,
+    /// the stream was dropped
+    dropped: bool,
+}
+
+impl<T> Buffer<T> {
+    fn new(capacity: usize, policy: LagPolicy) -> Self {
+        Self {
+            state: Mutex::new(BufferState {
+                events: VecDeque::with_capacity(capacity),
+                skipped: 0,
+                closed: false,
+                dropped: false,
+            }),
+            capacity,
+            policy,
+            readable: AtomicWaker::new(),
+            writable: Notify::new(),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, BufferState<T>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Fused `Stream` of the events published to one event type.
+/// It ends once the bus is dropped or the handler feeding it is unsubscribed.
+pub struct EventStream<T> {
+    handler_id: HandlerId,
+    buffer: Arc<Buffer<T>>,
+    terminated: bool,
+}
+
+impl<T> EventStream<T> {
+    /// return the id of the handler feeding the stream, to unsubscribe it.
+    pub fn handler_id(&self) -> &HandlerId {
+        &self.handler_id
+    }
+
+    /// return how far the stream is behind, e.g. to export it as a metric.
+    pub fn lag(&self) -> StreamLag {
+        let state = self.buffer.state();
+
+        StreamLag {
+            behind: state.events.len(),
+            oldest_pending: state.events.front().map(|(buffered, _)| buffered.elapsed()),
+            skipped: state.skipped,
+        }
+    }
+}
+
+impl<T> Stream for EventStream<T> {
+    type Item = Event<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.terminated {
+            return Poll::Ready(None);
+        }
+
+        this.buffer.readable.register(cx.waker());
+        let mut state = this.buffer.state();
+        match state.events.pop_front() {
+            Some((_, event)) => {
+                drop(state);
+                this.buffer.writable.notify_one();
+                Poll::Ready(Some(event))
+            }
+            None if state.closed => {
+                this.terminated = true;
+                Poll::Ready(None)
+            }
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl<T> FusedStream for EventStream<T> {
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+impl<T> Drop for EventStream<T> {
+    fn drop(&mut self) {
+        self.buffer.state().dropped = true;
+        self.buffer.writable.notify_one();
+    }
+}
+
+/// Handler forwarding clones of the events into an `EventStream`.
+struct Forward<T> {
+    buffer: Arc<Buffer<T>>,
+}
+
+#[async_trait]
+impl<T: Clone + Send + Sync + 'static> Handle<T> for Forward<T> {
+    async fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        loop {
+            let writable = {
+                let mut state = self.buffer.state();
+                // a dropped or disconnected stream no longer wants events
+                if state.dropped || state.closed {
+                    return Ok(());
+                }
+                let full = state.events.len() >= self.buffer.capacity;
+                match self.buffer.policy {
+                    LagPolicy::Block if full => Some(self.buffer.writable.notified()),
+                    LagPolicy::Disconnect if full => {
+                        state.closed = true;
+                        None
+                    }
+                    policy => {
+                        if full && policy == LagPolicy::SkipToLatest {
+                            state.events.pop_front();
+                            state.skipped += 1;
+                        }
+                        state.events.push_back((Instant::now(), event.clone()));
+                        None
+                    }
+                }
+            };
+
+            match writable {
+                Some(writable) => writable.await,
+                None => {
+                    self.buffer.readable.wake();
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+impl<T> Drop for Forward<T> {
+    fn drop(&mut self) {
+        self.buffer.state().closed = true;
+        self.buffer.readable.wake();
+    }
+}
+
+impl<T: Send + Sync + 'static> EventBus<T> {
+    /// Create a `Sink` publishing to `event_type`, a send completes once its publish did.
+    ///
+    /// ```no_run
+    /// let event_bus = Arc::new(EventBus::<Order>::new());
+    /// orders.map(|order| Ok(Event::new(order))).forward(event_bus.sink("order")).await?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub fn sink(self: &Arc<Self>, event_type: &str) -> EventSink<T> {
+        EventSink {
+            event_bus: self.clone(),
+            event_type: Arc::from(event_type),
+            in_flight: None,
+        }
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> EventBus<T> {
+    /// Subscribe a `Stream` of the events published to `event_type`, buffering up to
+    /// `capacity` events. A full buffer holds up publishing until the stream catches up,
+    /// so a consumer which publishes to the same bus needs a buffer large enough to never
+    /// fill, a publish waiting on the buffer blocks all other publishes of the bus.
+    ///
+    /// **Note:** Dropping the stream doesn't unsubscribe its handler, unsubscribe
+    /// `EventStream::handler_id` to stop receiving events.
+    ///
+    /// ```no_run
+    /// let mut orders = event_bus.stream("order", 64).await;
+    /// while let Some(order) = orders.next().await {
+    ///     // ...
+    /// }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn stream(&self, event_type: &str, capacity: usize) -> EventStream<T> {
+        self.stream_with_policy(event_type, capacity, LagPolicy::Block)
+            .await
+    }
+
+    /// Subscribe a `Stream` like `stream`, with a `LagPolicy` deciding what happens to
+    /// events published while its buffer is full. `EventStream::lag` tells how far behind
+    /// the stream is.
+    ///
+    /// ```no_run
+    /// let mut prices = event_bus
+    ///     .stream_with_policy("price", 16, LagPolicy::SkipToLatest)
+    ///     .await;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn stream_with_policy(
+        &self,
+        event_type: &str,
+        capacity: usize,
+        policy: LagPolicy,
+    ) -> EventStream<T> {
+        let buffer = Arc::new(Buffer::new(capacity.max(1), policy));
+        let handler_id = self
+            .subscribe(
+                event_type,
+                Box::new(Forward {
+                    buffer: buffer.clone(),
+                }),
+            )
+            .await;
+
+        EventStream {
+            handler_id,
+            buffer,
+            terminated: false,
+        }
+    }
+}
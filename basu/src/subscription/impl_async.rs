@@ -0,0 +1,74 @@
+use super::Subscription;
+use crate::{EventBus, Handler};
+
+use futures::future::BoxFuture;
+use std::sync::Arc;
+
+/// Unsubscribe the handler of a `Subscription`, if the bus still exists.
+pub(super) type Release = Box<dyn FnOnce() -> BoxFuture<'static, ()> + Send + Sync>;
+
+impl Subscription {
+    /// Unsubscribe the handler and wait until it is removed, unlike dropping the guard
+    /// which removes it from a spawned task.
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn unsubscribe(mut self) {
+        if let Some(release) = self.release.take() {
+            release().await;
+        }
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        if let Some(release) = self.release.take() {
+            if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+                runtime.spawn(release());
+            }
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> EventBus<T> {
+    /// Subscribe a handler and return a `Subscription` guard which unsubscribes it once
+    /// dropped, so handlers can't leak when their owner goes away.
+    /// The guard holds a weak reference and doesn't keep the bus alive.
+    ///
+    /// ```no_run
+    /// let event_bus = Arc::new(EventBus::<MyEventData>::new());
+    /// let subscription = event_bus
+    ///     .subscribe_guarded("my_event", Box::new(MyEventHandler))
+    ///     .await;
+    ///
+    /// // the handler is unsubscribed here
+    /// drop(subscription);
+    /// ```
+    ///
+    /// **Note:** Dropping the guard removes the handler from a spawned task, it is left
+    /// subscribed when the guard is dropped outside of a tokio runtime.
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn subscribe_guarded(
+        self: &Arc<Self>,
+        event_type: &str,
+        handler: Handler<T>,
+    ) -> Subscription {
+        let handler_id = self.subscribe(event_type, handler).await;
+        let event_bus = Arc::downgrade(self);
+        let release: Release = {
+            let event_type = event_type.to_owned();
+            let handler_id = handler_id.clone();
+            Box::new(move || {
+                Box::pin(async move {
+                    if let Some(event_bus) = event_bus.upgrade() {
+                        let _ = event_bus.unsubscribe(&event_type, &handler_id).await;
+                    }
+                })
+            })
+        };
+
+        Subscription {
+            event_type: event_type.to_owned(),
+            handler_id,
+            release: Some(release),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use super::Subscription;
+use crate::{error::BasuError, EventBus, Handler};
+
+use std::sync::Arc;
+
+/// Unsubscribe the handler of a `Subscription`, if the bus still exists.
+pub(super) type Release = Box<dyn FnOnce() -> Result<(), BasuError> + Send + Sync>;
+
+impl Subscription {
+    /// Unsubscribe the handler, returning the error dropping the guard would ignore.
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn unsubscribe(mut self) -> Result<(), BasuError> {
+        match self.release.take() {
+            Some(release) => release(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        if let Some(release) = self.release.take() {
+            let _ = release();
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> EventBus<T> {
+    /// Subscribe a handler and return a `Subscription` guard which unsubscribes it once
+    /// dropped, so handlers can't leak when their owner goes away.
+    /// The guard holds a weak reference and doesn't keep the bus alive.
+    ///
+    /// ```no_run
+    /// let event_bus = Arc::new(EventBus::<MyEventData>::new());
+    /// let subscription = event_bus.subscribe_guarded("my_event", Box::new(MyEventHandler))?;
+    ///
+    /// // the handler is unsubscribed here
+    /// drop(subscription);
+    /// ```
+    ///
+    /// **Note:** Don't drop the guard inside a handler of the same bus, unsubscribing waits
+    /// for the running publish to finish.
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn subscribe_guarded(
+        self: &Arc<Self>,
+        event_type: &str,
+        handler: Handler<T>,
+    ) -> Result<Subscription, BasuError> {
+        let handler_id = self.subscribe(event_type, handler)?;
+        let event_bus = Arc::downgrade(self);
+        let release: Release = {
+            let event_type = event_type.to_owned();
+            let handler_id = handler_id.clone();
+            Box::new(move || match event_bus.upgrade() {
+                Some(event_bus) => event_bus.unsubscribe(&event_type, &handler_id),
+                None => Ok(()),
+            })
+        };
+
+        Ok(Subscription {
+            event_type: event_type.to_owned(),
+            handler_id,
+            release: Some(release),
+        })
+    }
+}
@@ -0,0 +1,50 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+
+#[cfg(feature = "async")]
+use impl_async::Release;
+#[cfg(feature = "sync")]
+use impl_sync::Release;
+
+use crate::HandlerId;
+
+use std::fmt;
+
+/// Guard of a handler subscribed with `subscribe_guarded`, which unsubscribes the handler
+/// once it is dropped.
+#[must_use = "the handler is unsubscribed as soon as the `Subscription` is dropped"]
+pub struct Subscription {
+    event_type: String,
+    handler_id: HandlerId,
+    release: Option<Release>,
+}
+
+impl Subscription {
+    /// event type the handler is subscribed to.
+    pub fn event_type(&self) -> &str {
+        &self.event_type
+    }
+
+    /// id of the subscribed handler.
+    pub fn handler_id(&self) -> &HandlerId {
+        &self.handler_id
+    }
+
+    /// drop the guard but keep the handler subscribed, returning its id.
+    pub fn detach(mut self) -> HandlerId {
+        self.release = None;
+        self.handler_id.clone()
+    }
+}
+
+impl fmt::Debug for Subscription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Subscription")
+            .field("event_type", &self.event_type)
+            .field("handler_id", &self.handler_id)
+            .field("attached", &self.release.is_some())
+            .finish()
+    }
+}
@@ -0,0 +1,50 @@
+use super::Tenancy;
+use crate::{async_trait, error::BasuError, event::Event, EventBus, Handle, Handler, HandlerId};
+
+use std::sync::Arc;
+
+/// Handler which only receives the events of one tenant.
+struct TenantScoped<T> {
+    handler: Handler<T>,
+    tenant: String,
+    tenancy: Option<Arc<Tenancy<T>>>,
+}
+
+#[async_trait]
+impl<T: Sync> Handle<T> for TenantScoped<T> {
+    async fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        match &self.tenancy {
+            Some(tenancy) if tenancy.tenant_of(event).as_deref() == Some(&self.tenant) => {
+                self.handler.handle(event).await
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<T: Sync + 'static> EventBus<T> {
+    /// Subscribe a handler which only receives the events of `tenant`.
+    ///
+    /// **Note:** Without `with_tenancy` no event has a tenant, so the handler receives nothing.
+    ///
+    /// ```no_run
+    /// let handler_id = event_bus
+    ///     .subscribe_tenant("order", "acme", Box::new(AcmeOrderHandler))
+    ///     .await;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn subscribe_tenant(
+        &self,
+        event_type: &str,
+        tenant: &str,
+        handler: Handler<T>,
+    ) -> HandlerId {
+        let handler = TenantScoped {
+            handler,
+            tenant: tenant.to_owned(),
+            tenancy: self.tenancy.clone(),
+        };
+
+        self.subscribe(event_type, Box::new(handler)).await
+    }
+}
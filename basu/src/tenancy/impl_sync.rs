@@ -0,0 +1,47 @@
+use super::Tenancy;
+use crate::{error::BasuError, event::Event, EventBus, Handle, Handler, HandlerId};
+
+use std::sync::Arc;
+
+/// Handler which only receives the events of one tenant.
+struct TenantScoped<T> {
+    handler: Handler<T>,
+    tenant: String,
+    tenancy: Option<Arc<Tenancy<T>>>,
+}
+
+impl<T> Handle<T> for TenantScoped<T> {
+    fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        match &self.tenancy {
+            Some(tenancy) if tenancy.tenant_of(event).as_deref() == Some(&self.tenant) => {
+                self.handler.handle(event)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<T: Sync + 'static> EventBus<T> {
+    /// Subscribe a handler which only receives the events of `tenant`.
+    ///
+    /// **Note:** Without `with_tenancy` no event has a tenant, so the handler receives nothing.
+    ///
+    /// ```no_run
+    /// let handler_id = event_bus.subscribe_tenant("order", "acme", Box::new(AcmeOrderHandler))?;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn subscribe_tenant(
+        &self,
+        event_type: &str,
+        tenant: &str,
+        handler: Handler<T>,
+    ) -> Result<HandlerId, BasuError> {
+        let handler = TenantScoped {
+            handler,
+            tenant: tenant.to_owned(),
+            tenancy: self.tenancy.clone(),
+        };
+
+        self.subscribe(event_type, Box::new(handler))
+    }
+}
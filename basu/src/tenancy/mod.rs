@@ -0,0 +1,121 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+
+use crate::{error::PublishError, event::Event, EventBus};
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, PoisonError},
+    time::Instant,
+};
+
+type TenantFn<T> = Box<dyn Fn(&Event<T>) -> Option<String> + Send + Sync>;
+
+/// Token bucket of one tenant.
+struct Bucket {
+    tokens: f64,
+    refilled: Instant,
+}
+
+/// Tenancy layer of an `EventBus`, telling which tenant an event belongs to and
+/// limiting the publish rate of each tenant so a noisy tenant can't starve the others.
+///
+/// ```no_run
+/// let tenancy = Tenancy::new(|event: &Event<Message>| Some(event.data.tenant_id.clone()))
+///     .with_rate_limit(1_000)
+///     .with_tenant_rate_limit("batch-importer", 100);
+///
+/// let event_bus = EventBus::<Message>::new().with_tenancy(tenancy);
+/// ```
+pub struct Tenancy<T> {
+    tenant_of: TenantFn<T>,
+    rate_limit: Option<u32>,
+    tenant_rate_limits: HashMap<String, u32>,
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+impl<T> Tenancy<T> {
+    /// create a new `Tenancy` from the function returning the tenant of an event,
+    /// events without a tenant are never limited.
+    pub fn new(tenant_of: impl Fn(&Event<T>) -> Option<String> + Send + Sync + 'static) -> Self {
+        Self {
+            tenant_of: Box::new(tenant_of),
+            rate_limit: None,
+            tenant_rate_limits: HashMap::new(),
+            buckets: Default::default(),
+        }
+    }
+
+    /// limit every tenant to `per_second` published events, with bursts up to the same amount.
+    pub fn with_rate_limit(mut self, per_second: u32) -> Self {
+        self.rate_limit = Some(per_second);
+        self
+    }
+
+    /// limit a single tenant to `per_second` published events, overriding `with_rate_limit`.
+    pub fn with_tenant_rate_limit(mut self, tenant: &str, per_second: u32) -> Self {
+        self.tenant_rate_limits
+            .insert(tenant.to_owned(), per_second);
+        self
+    }
+
+    /// return the tenant of an event.
+    pub fn tenant_of(&self, event: &Event<T>) -> Option<String> {
+        (self.tenant_of)(event)
+    }
+
+    /// take a token from the tenant's bucket, failing when it is empty.
+    fn admit(&self, tenant: &str) -> Result<(), PublishError> {
+        let Some(per_second) = self
+            .tenant_rate_limits
+            .get(tenant)
+            .copied()
+            .or(self.rate_limit)
+        else {
+            return Ok(());
+        };
+
+        let now = Instant::now();
+        let capacity = f64::from(per_second);
+        let mut buckets = self.buckets.lock().unwrap_or_else(PoisonError::into_inner);
+        let bucket = buckets.entry(tenant.to_owned()).or_insert(Bucket {
+            tokens: capacity,
+            refilled: now,
+        });
+
+        let refill = now.duration_since(bucket.refilled).as_secs_f64() * capacity;
+        bucket.tokens = (bucket.tokens + refill).min(capacity);
+        bucket.refilled = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(PublishError::RateLimited {
+                tenant: tenant.to_owned(),
+            })
+        }
+    }
+}
+
+impl<T> EventBus<T> {
+    /// set the tenancy layer of the bus, publishing an event of a tenant over its rate limit
+    /// fails with `PublishError::RateLimited`.
+    pub fn with_tenancy(mut self, tenancy: Tenancy<T>) -> Self {
+        self.tenancy = Some(Arc::new(tenancy));
+        self
+    }
+
+    /// check the rate limit of the event's tenant before it is dispatched.
+    pub(crate) fn admit(&self, event: &Event<T>) -> Result<(), PublishError> {
+        match &self.tenancy {
+            Some(tenancy) => match tenancy.tenant_of(event) {
+                Some(tenant) => tenancy.admit(&tenant),
+                None => Ok(()),
+            },
+            None => Ok(()),
+        }
+    }
+}
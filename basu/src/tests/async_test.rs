@@ -1,6 +1,49 @@
-use crate::{async_trait, error::BasuError, event::Event, EventBus, Handle};
+use crate::{
+    actor::{Actor, ActorRef, SupervisorEvent, SupervisorPolicy},
+    aggregate::{Aggregator, Completion},
+    async_trait,
+    backfill::BackfillOptions,
+    batch::BatchConfig,
+    circuit::{CircuitBreaker, CircuitEvent},
+    combinator::{CanaryWeight, HandlerExt, RetryPolicy, ShadowOutcome},
+    command::{CommandBus, HandleCommand},
+    context::{self, Context},
+    error::{BasuError, ErrorPolicy, PublishError},
+    event::Event,
+    executor::{Executor, Job},
+    extract::{self, extract_fn, Ctx, State},
+    flag::{FlagProvider, Flags},
+    handler_fn,
+    join::Join,
+    lazy::LazyEventBus,
+    lifecycle::LifecycleEvent,
+    outcome::{HandleOutcome, HandleSignal},
+    priority::DEFAULT_PRIORITY,
+    query::{CachePolicy, HandleQuery, QueryBus},
+    reload::{ConfigChange, ConfigWatcher},
+    report::HandlerFailure,
+    request::{Gathered, HandleRequest},
+    slow::SlowHandler,
+    stream::LagPolicy,
+    tenancy::Tenancy,
+    validate::ValidationIssue,
+    wildcard::{is_pattern, WILDCARD},
+    window::Window,
+    EventBus, Handle, Handler, HandlerId,
+};
 
-#[derive(Debug)]
+use futures::{stream::FusedStream, SinkExt, StreamExt};
+use std::{
+    any::Any,
+    num::NonZeroU64,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+#[derive(Debug, Clone)]
 struct Data {
     message: String,
 }
@@ -63,3 +106,2723 @@ async fn test() {
     let event_types = eventbus.list().await;
     assert_eq!(event_types.len(), 0);
 }
+
+#[tokio::test]
+async fn named() {
+    let eventbus = EventBus::<Data>::named("payments").with_label("region", "eu");
+    assert_eq!(eventbus.name(), Some("payments"));
+    assert_eq!(
+        eventbus.labels().get("region").map(String::as_str),
+        Some("eu")
+    );
+    assert!(format!("{:?}", eventbus).contains("payments"));
+
+    let eventbus = EventBus::<Data>::new();
+    assert_eq!(eventbus.name(), None);
+    assert!(eventbus.labels().is_empty());
+}
+
+#[tokio::test]
+async fn global() {
+    struct GlobalData;
+    struct GlobalHandler;
+
+    #[async_trait]
+    impl Handle<GlobalData> for GlobalHandler {
+        async fn handle(&self, _event: &Event<GlobalData>) -> Result<(), BasuError> {
+            Ok(())
+        }
+    }
+
+    crate::global::<GlobalData>()
+        .subscribe(ECHO, Box::new(GlobalHandler))
+        .await;
+
+    let count = crate::global::<GlobalData>()
+        .get_handler_count(ECHO)
+        .await
+        .unwrap();
+    assert_eq!(count, 1);
+    assert!(crate::global::<Data>().list().await.is_empty());
+}
+
+struct Counter {
+    count: usize,
+    total: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl Actor<Data> for Counter {
+    async fn handle(&mut self, _event: Event<Data>) -> Result<(), BasuError> {
+        self.count += 1;
+        self.total.store(self.count, Ordering::SeqCst);
+
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn actor() {
+    let eventbus = EventBus::new();
+    let total = Arc::new(AtomicUsize::new(0));
+    let event_types = [ECHO, "ping"];
+    let (actor_ref, handler_ids) = eventbus
+        .spawn_actor(
+            &event_types,
+            Counter {
+                count: 0,
+                total: total.clone(),
+            },
+        )
+        .await;
+
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    eventbus.publish(ECHO, &event).await.unwrap();
+    eventbus.publish("ping", &event).await.unwrap();
+    eventbus.publish(ECHO, &event).await.unwrap();
+
+    for (event_type, handler_id) in event_types.iter().zip(&handler_ids) {
+        eventbus.unsubscribe(event_type, handler_id).await.unwrap();
+    }
+    actor_ref.join().await.unwrap();
+    assert_eq!(total.load(Ordering::SeqCst), 3);
+}
+
+struct Flaky {
+    count: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl Actor<Data> for Flaky {
+    async fn handle(&mut self, event: Event<Data>) -> Result<(), BasuError> {
+        if event.get_data().message == "panic" {
+            panic!("flaky actor");
+        }
+        self.count.fetch_add(1, Ordering::SeqCst);
+
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn supervised_actor() {
+    let eventbus = EventBus::new();
+    let count = Arc::new(AtomicUsize::new(0));
+    let events = Arc::new(Mutex::new(Vec::new()));
+
+    let policy = SupervisorPolicy::new(1)
+        .with_backoff(Duration::from_millis(1), Duration::from_millis(10))
+        .on_event({
+            let events = events.clone();
+            move |event| events.lock().unwrap().push(event)
+        });
+    let actor_ref = ActorRef::spawn_supervised(
+        {
+            let count = count.clone();
+            move || Flaky {
+                count: count.clone(),
+            }
+        },
+        policy,
+    );
+    eventbus.subscribe(ECHO, actor_ref.mailbox()).await;
+
+    let ok = Event::new(Data {
+        message: "ok".to_owned(),
+    });
+    let panic = Event::new(Data {
+        message: "panic".to_owned(),
+    });
+    eventbus.publish(ECHO, &ok).await.unwrap();
+    eventbus.publish(ECHO, &panic).await.unwrap();
+    eventbus.publish(ECHO, &ok).await.unwrap();
+    eventbus.publish(ECHO, &panic).await.unwrap();
+
+    eventbus.clear().await;
+    let result = actor_ref.join().await;
+    assert!(matches!(
+        result,
+        Err(BasuError::ActorEscalated { restarts: 2 })
+    ));
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![
+            SupervisorEvent::Restarted { restarts: 1 },
+            SupervisorEvent::Escalated { restarts: 2 }
+        ]
+    );
+}
+
+struct Double;
+
+#[async_trait]
+impl HandleCommand<u64, u64> for Double {
+    async fn handle(&self, command: u64) -> Result<u64, BasuError> {
+        Ok(command * 2)
+    }
+}
+
+#[tokio::test]
+async fn command() {
+    let commandbus = CommandBus::new();
+    commandbus
+        .register("double", Box::new(Double))
+        .await
+        .unwrap();
+
+    let result = commandbus.register("double", Box::new(Double)).await;
+    assert!(matches!(result, Err(BasuError::HandlerAlreadyRegistered)));
+    assert_eq!(commandbus.list().await, vec!["double".to_owned()]);
+
+    assert_eq!(commandbus.dispatch("double", 21).await.unwrap(), 42);
+
+    commandbus.unregister("double").await.unwrap();
+    let result = commandbus.dispatch("double", 21).await;
+    assert!(matches!(result, Err(BasuError::CommandTypeNotFound)));
+}
+
+struct Square {
+    calls: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl HandleQuery<u64, u64> for Square {
+    async fn handle(&self, query: u64) -> Result<u64, BasuError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        Ok(query * query)
+    }
+}
+
+#[tokio::test]
+async fn query() {
+    let querybus = QueryBus::new();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let policy = CachePolicy::new(Duration::from_secs(60), |query: &u64| query.to_string());
+    querybus
+        .register_cached(
+            "square",
+            Box::new(Square {
+                calls: calls.clone(),
+            }),
+            policy,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(querybus.query("square", 3).await.unwrap(), 9);
+    assert_eq!(querybus.query("square", 3).await.unwrap(), 9);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    assert_eq!(querybus.query("square", 4).await.unwrap(), 16);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    querybus.invalidate("square").await.unwrap();
+    assert_eq!(querybus.query("square", 3).await.unwrap(), 9);
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+    let result = querybus.query("cube", 3).await;
+    assert!(matches!(result, Err(BasuError::QueryTypeNotFound)));
+}
+
+struct Counting(Arc<AtomicUsize>);
+
+#[async_trait]
+impl Handle<Data> for Counting {
+    async fn handle(&self, _event: &Event<Data>) -> Result<(), BasuError> {
+        self.0.fetch_add(1, Ordering::SeqCst);
+
+        Ok(())
+    }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(self)
+    }
+}
+
+#[tokio::test]
+async fn publish_prepared() {
+    let eventbus = EventBus::new();
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let result = eventbus.publish_prepared(ECHO, event.clone()).await;
+    assert!(matches!(result, Err(PublishError::EventTypeNotFound)));
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let handler_id = eventbus
+        .subscribe(ECHO, Box::new(Counting(count.clone())))
+        .await;
+
+    let prepared = eventbus
+        .publish_prepared(ECHO, event.clone())
+        .await
+        .unwrap();
+    assert_eq!(prepared.event_type(), ECHO);
+    prepared.abort();
+    assert_eq!(count.load(Ordering::SeqCst), 0);
+
+    let prepared = eventbus
+        .publish_prepared(ECHO, event.clone())
+        .await
+        .unwrap();
+    prepared.commit().await.unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+
+    eventbus.unsubscribe(ECHO, &handler_id).await.unwrap();
+    let result = eventbus.publish_prepared(ECHO, event).await;
+    assert!(matches!(result, Err(PublishError::NoSubscribers)));
+}
+
+#[tokio::test]
+async fn batch() {
+    let eventbus = Arc::new(EventBus::new());
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe(ECHO, Box::new(Counting(count.clone())))
+        .await;
+
+    let events: Vec<_> = (0..3)
+        .map(|i| {
+            Event::new(Data {
+                message: i.to_string(),
+            })
+        })
+        .collect();
+    eventbus.publish_batch(ECHO, &events).await.unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 3);
+
+    let batcher = eventbus.batcher(
+        ECHO,
+        BatchConfig {
+            max_size: 2,
+            max_delay: Duration::from_millis(5),
+        },
+    );
+    for event in events.iter().chain(events.iter()) {
+        batcher.publish(event.clone()).unwrap();
+    }
+    batcher.close().await.unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 9);
+}
+
+#[tokio::test]
+async fn subscribe_on() {
+    let eventbus = EventBus::new();
+    let main_loop: Arc<Mutex<Vec<Job>>> = Arc::new(Mutex::new(Vec::new()));
+    let executor = Executor::new({
+        let main_loop = main_loop.clone();
+        move |job| main_loop.lock().unwrap().push(job)
+    });
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe_on(ECHO, &executor, Box::new(Counting(count.clone())))
+        .await;
+
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    eventbus.publish(ECHO, &event).await.unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 0);
+
+    let jobs: Vec<_> = main_loop.lock().unwrap().drain(..).collect();
+    for job in jobs {
+        job.await;
+    }
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn frame_events() {
+    let eventbus = EventBus::new();
+    eventbus.buffer_frames(ECHO).await;
+    assert!(eventbus.take_frame_events(ECHO).is_empty());
+
+    for message in ["a", "b"] {
+        let event = Event::new(Data {
+            message: message.to_owned(),
+        });
+        eventbus.publish(ECHO, &event).await.unwrap();
+    }
+
+    let messages: Vec<_> = eventbus
+        .take_frame_events(ECHO)
+        .into_iter()
+        .map(|event| event.data.message)
+        .collect();
+    assert_eq!(messages, vec!["a".to_owned(), "b".to_owned()]);
+    assert!(eventbus.take_frame_events(ECHO).is_empty());
+}
+
+struct Sleepy {
+    delay: Duration,
+    count: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl Handle<Data> for Sleepy {
+    async fn handle(&self, _event: &Event<Data>) -> Result<(), BasuError> {
+        tokio::time::sleep(self.delay).await;
+        self.count.fetch_add(1, Ordering::SeqCst);
+
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn scope() {
+    let eventbus = EventBus::new();
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe(
+            ECHO,
+            Box::new(Sleepy {
+                delay: Duration::from_millis(10),
+                count: count.clone(),
+            }),
+        )
+        .await;
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+
+    let output = eventbus
+        .scope(|scoped| async move {
+            scoped.publish(ECHO, event.clone());
+            scoped.publish(ECHO, event.clone());
+            "done"
+        })
+        .await
+        .unwrap();
+    assert_eq!(output, "done");
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let scope = eventbus.scope(|scoped| async move { scoped.publish(ECHO, event) });
+    let result = tokio::time::timeout(Duration::from_millis(1), scope).await;
+    assert!(result.is_err());
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn lifecycle() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let eventbus = EventBus::new().with_lifecycle_hook({
+        let events = events.clone();
+        move |event: &LifecycleEvent| events.lock().unwrap().push(event.clone())
+    });
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let handler_a_id = eventbus
+        .subscribe(ECHO, Box::new(Counting(count.clone())))
+        .await;
+    let handler_b_id = eventbus
+        .subscribe(ECHO, Box::new(Counting(count.clone())))
+        .await;
+    eventbus.unsubscribe(ECHO, &handler_a_id).await.unwrap();
+    eventbus.unsubscribe(ECHO, &handler_a_id).await.unwrap();
+    eventbus.clear().await;
+
+    let attached = |handler_id: &HandlerId| LifecycleEvent::Attached {
+        event_type: ECHO.to_owned(),
+        handler_id: handler_id.clone(),
+    };
+    let detached = |handler_id: &HandlerId| LifecycleEvent::Detached {
+        event_type: ECHO.to_owned(),
+        handler_id: handler_id.clone(),
+    };
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![
+            attached(&handler_a_id),
+            attached(&handler_b_id),
+            detached(&handler_a_id),
+            detached(&handler_b_id),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn unsubscribe_waits_for_in_flight_handler() {
+    let eventbus = Arc::new(EventBus::new());
+    let count = Arc::new(AtomicUsize::new(0));
+    let handler_id = eventbus
+        .subscribe(
+            ECHO,
+            Box::new(Sleepy {
+                delay: Duration::from_millis(20),
+                count: count.clone(),
+            }),
+        )
+        .await;
+
+    let publish = tokio::spawn({
+        let eventbus = eventbus.clone();
+        async move {
+            let event = Event::new(Data {
+                message: "{data from event}".to_owned(),
+            });
+            eventbus.publish(ECHO, &event).await
+        }
+    });
+    tokio::time::sleep(Duration::from_millis(5)).await;
+
+    eventbus.unsubscribe(ECHO, &handler_id).await.unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    publish.await.unwrap().unwrap();
+}
+
+struct FlagObserver {
+    flag: Arc<AtomicBool>,
+    observed: Arc<Mutex<Vec<bool>>>,
+}
+
+#[async_trait]
+impl Handle<Data> for FlagObserver {
+    async fn handle(&self, _event: &Event<Data>) -> Result<(), BasuError> {
+        let flag = self.flag.load(Ordering::SeqCst);
+        self.observed.lock().unwrap().push(flag);
+
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn fanout_chunks_yield_to_runtime() {
+    let eventbus = EventBus::new().with_fanout_chunk_size(1);
+    let flag = Arc::new(AtomicBool::new(false));
+    let observed = Arc::new(Mutex::new(Vec::new()));
+    for _ in 0..3 {
+        eventbus
+            .subscribe(
+                ECHO,
+                Box::new(FlagObserver {
+                    flag: flag.clone(),
+                    observed: observed.clone(),
+                }),
+            )
+            .await;
+    }
+
+    tokio::spawn({
+        let flag = flag.clone();
+        async move { flag.store(true, Ordering::SeqCst) }
+    });
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    eventbus.publish(ECHO, &event).await.unwrap();
+
+    assert_eq!(*observed.lock().unwrap(), vec![false, true, true]);
+}
+
+struct Failing {
+    failures: usize,
+    calls: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl Handle<Data> for Failing {
+    async fn handle(&self, _event: &Event<Data>) -> Result<(), BasuError> {
+        if self.calls.fetch_add(1, Ordering::SeqCst) < self.failures {
+            return Err(anyhow::anyhow!("failing handler").into());
+        }
+
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn combinators() {
+    let eventbus = EventBus::new();
+    let event = Event::new(Data {
+        message: "keep".to_owned(),
+    });
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let handler: Handler<Data> = Box::new(Failing {
+        failures: 2,
+        calls: calls.clone(),
+    });
+    let handler = handler.with_retry(RetryPolicy::new(3, Duration::from_millis(1)));
+    eventbus.subscribe("retry", handler).await;
+    eventbus.publish("retry", &event).await.unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let handler: Handler<Data> = Box::new(Counting(count.clone()));
+    let handler = handler
+        .filtered(|event| event.data.message == "keep")
+        .tee(Box::new(Counting(count.clone())));
+    eventbus.subscribe("filtered", handler).await;
+    eventbus.publish("filtered", &event).await.unwrap();
+    let skipped = Event::new(Data {
+        message: "skip".to_owned(),
+    });
+    eventbus.publish("filtered", &skipped).await.unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 3);
+
+    let handler: Handler<Data> = Box::new(Failing {
+        failures: usize::MAX,
+        calls: Arc::new(AtomicUsize::new(0)),
+    });
+    let handler = handler.fallback(Box::new(Counting(count.clone())));
+    eventbus.subscribe("fallback", handler).await;
+    eventbus.publish("fallback", &event).await.unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 4);
+
+    let handler: Handler<Data> = Box::new(Sleepy {
+        delay: Duration::from_millis(50),
+        count: count.clone(),
+    });
+    let handler = handler.with_timeout(Duration::from_millis(1));
+    eventbus.subscribe("timeout", handler).await;
+    let result = eventbus.publish("timeout", &event).await;
+    assert!(matches!(
+        result,
+        Err(PublishError::Timeout { succeeded: 0, timed_out }) if matches!(timed_out[..], [HandlerFailure { error: BasuError::HandlerTimeout, .. }])
+    ));
+}
+
+struct Summaries(Arc<Mutex<Vec<usize>>>);
+
+#[async_trait]
+impl Handle<usize> for Summaries {
+    async fn handle(&self, event: &Event<usize>) -> Result<(), BasuError> {
+        self.0.lock().unwrap().push(event.data);
+
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn aggregate_groups_by_key() {
+    let eventbus = EventBus::<Data>::new();
+    let output = Arc::new(EventBus::<usize>::new());
+    let summaries = Arc::new(Mutex::new(Vec::new()));
+    output
+        .subscribe("summary", Box::new(Summaries(summaries.clone())))
+        .await;
+
+    let aggregator = Aggregator::new(
+        |event: &Event<Data>| event.data.message.clone(),
+        || 0,
+        |count: &mut usize, _: &str, _: &Event<Data>| *count += 1,
+    )
+    .with_timeout(Duration::from_millis(20));
+    eventbus
+        .aggregate(&["payment", "shipping"], aggregator, &output, "summary")
+        .await;
+
+    let order = |id: &str| {
+        Event::new(Data {
+            message: id.to_owned(),
+        })
+    };
+    eventbus.publish("payment", &order("a")).await.unwrap();
+    eventbus.publish("payment", &order("b")).await.unwrap();
+    eventbus.publish("payment", &order("a")).await.unwrap();
+    eventbus.publish("shipping", &order("a")).await.unwrap();
+
+    while summaries.lock().unwrap().len() < 2 {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    assert_eq!(*summaries.lock().unwrap(), vec![3, 1]);
+
+    let aggregator = Aggregator::new(
+        |event: &Event<Data>| event.data.message.clone(),
+        || 0,
+        |count: &mut usize, _: &str, _: &Event<Data>| *count += 1,
+    )
+    .complete_when(Completion::Count(2));
+    eventbus
+        .aggregate(&["refund"], aggregator, &output, "summary")
+        .await;
+    eventbus.publish("refund", &order("c")).await.unwrap();
+    eventbus.publish("refund", &order("c")).await.unwrap();
+
+    while summaries.lock().unwrap().len() < 3 {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    assert_eq!(summaries.lock().unwrap()[2], 2);
+}
+
+struct Messages(Arc<Mutex<Vec<String>>>);
+
+#[async_trait]
+impl Handle<Data> for Messages {
+    async fn handle(&self, event: &Event<Data>) -> Result<(), BasuError> {
+        self.0.lock().unwrap().push(event.data.message.clone());
+
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn tumbling_window_reduces_events() {
+    let eventbus = Arc::new(EventBus::<Data>::new());
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    eventbus
+        .subscribe("joined", Box::new(Messages(messages.clone())))
+        .await;
+
+    let handler_id = eventbus
+        .window(
+            "sample",
+            Window::Tumbling(Duration::from_millis(30)),
+            |events| Data {
+                message: events
+                    .iter()
+                    .map(|event| event.data.message.as_str())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            },
+            "joined",
+        )
+        .await
+        .unwrap();
+
+    for message in ["a", "b", "c"] {
+        let event = Event::new(Data {
+            message: message.to_owned(),
+        });
+        eventbus.publish("sample", &event).await.unwrap();
+    }
+
+    while messages.lock().unwrap().is_empty() {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    assert_eq!(*messages.lock().unwrap(), vec!["a,b,c".to_owned()]);
+
+    eventbus.unsubscribe("sample", &handler_id).await.unwrap();
+}
+
+#[tokio::test]
+async fn join_correlates_topics_by_key() {
+    let eventbus = Arc::new(EventBus::<Data>::new());
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    eventbus
+        .subscribe("paid", Box::new(Messages(messages.clone())))
+        .await;
+
+    let join = Join::new(
+        |event: &Event<Data>| event.data.message.split(':').next().unwrap().to_owned(),
+        Duration::from_secs(5),
+        |order, payment| Data {
+            message: format!("{}+{}", order.data.message, payment.data.message),
+        },
+    );
+    eventbus.join("order", "payment", join, "paid").await;
+
+    let event = |message: &str| {
+        Event::new(Data {
+            message: message.to_owned(),
+        })
+    };
+    eventbus.publish("order", &event("1:order")).await.unwrap();
+    eventbus.publish("order", &event("2:order")).await.unwrap();
+    eventbus
+        .publish("payment", &event("2:payment"))
+        .await
+        .unwrap();
+
+    while messages.lock().unwrap().is_empty() {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    assert_eq!(
+        *messages.lock().unwrap(),
+        vec!["2:order+2:payment".to_owned()]
+    );
+}
+
+#[tokio::test]
+async fn shadow_records_outcomes() {
+    let eventbus = EventBus::<Data>::new();
+    let count = Arc::new(AtomicUsize::new(0));
+    let outcomes: Arc<Mutex<Vec<ShadowOutcome>>> = Arc::new(Mutex::new(Vec::new()));
+
+    let handler: Handler<Data> = Box::new(Counting(count.clone()));
+    let recorded = outcomes.clone();
+    let handler = handler.shadow(
+        Box::new(Failing {
+            failures: usize::MAX,
+            calls: Arc::new(AtomicUsize::new(0)),
+        }),
+        move |outcome| recorded.lock().unwrap().push(outcome.clone()),
+    );
+    eventbus.subscribe(ECHO, handler).await;
+
+    let event = Event::new(Data {
+        message: "shadowed".to_owned(),
+    });
+    eventbus.publish(ECHO, &event).await.unwrap();
+
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    let outcomes = outcomes.lock().unwrap();
+    assert_eq!(outcomes.len(), 1);
+    assert!(outcomes[0].primary.is_ok());
+    assert!(outcomes[0].diverged());
+}
+
+#[tokio::test]
+async fn canary_routes_share_of_events() {
+    let eventbus = EventBus::<Data>::new();
+    let stable = Arc::new(AtomicUsize::new(0));
+    let canary = Arc::new(AtomicUsize::new(0));
+    let weight = CanaryWeight::new(30);
+
+    let handler: Handler<Data> = Box::new(Counting(stable.clone()));
+    let handler = handler.canary(Box::new(Counting(canary.clone())), &weight);
+    eventbus.subscribe(ECHO, handler).await;
+
+    let event = Event::new(Data {
+        message: "canary".to_owned(),
+    });
+    for _ in 0..100 {
+        eventbus.publish(ECHO, &event).await.unwrap();
+    }
+    assert_eq!(canary.load(Ordering::SeqCst), 30);
+    assert_eq!(stable.load(Ordering::SeqCst), 70);
+
+    weight.set(100);
+    eventbus.publish(ECHO, &event).await.unwrap();
+    assert_eq!(canary.load(Ordering::SeqCst), 31);
+
+    weight.set(50);
+    let sticky_stable = Arc::new(AtomicUsize::new(0));
+    let sticky_canary = Arc::new(AtomicUsize::new(0));
+    let handler: Handler<Data> = Box::new(Counting(sticky_stable.clone()));
+    let handler = handler.canary_by_key(
+        Box::new(Counting(sticky_canary.clone())),
+        &weight,
+        |event| event.data.message.clone(),
+    );
+    eventbus.subscribe("sticky", handler).await;
+    for _ in 0..10 {
+        eventbus.publish("sticky", &event).await.unwrap();
+    }
+    let routed = (
+        sticky_stable.load(Ordering::SeqCst),
+        sticky_canary.load(Ordering::SeqCst),
+    );
+    assert!(routed == (10, 0) || routed == (0, 10));
+}
+
+struct StaticFlags(Arc<AtomicBool>, Arc<AtomicUsize>);
+
+impl FlagProvider for StaticFlags {
+    fn is_enabled(&self, _: &str, handler_name: &str) -> bool {
+        self.1.fetch_add(1, Ordering::SeqCst);
+        handler_name == "v2" && self.0.load(Ordering::SeqCst)
+    }
+}
+
+#[tokio::test]
+async fn flagged_handler_follows_provider() {
+    let eventbus = EventBus::<Data>::new();
+    let enabled = Arc::new(AtomicBool::new(false));
+    let lookups = Arc::new(AtomicUsize::new(0));
+    let count = Arc::new(AtomicUsize::new(0));
+    let flags = Flags::new(StaticFlags(enabled.clone(), lookups.clone()))
+        .with_ttl(Duration::from_millis(20));
+
+    eventbus
+        .subscribe_flagged(ECHO, "v2", &flags, Box::new(Counting(count.clone())))
+        .await;
+
+    let event = Event::new(Data {
+        message: "flagged".to_owned(),
+    });
+    eventbus.publish(ECHO, &event).await.unwrap();
+    enabled.store(true, Ordering::SeqCst);
+    eventbus.publish(ECHO, &event).await.unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 0);
+    assert_eq!(lookups.load(Ordering::SeqCst), 1);
+
+    tokio::time::sleep(Duration::from_millis(30)).await;
+    eventbus.publish(ECHO, &event).await.unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn reconfigure_applies_to_next_publish() {
+    let eventbus = EventBus::<Data>::new().with_fanout_chunk_size(1);
+    assert_eq!(eventbus.config().fanout_chunk_size(), 1);
+
+    let count = Arc::new(AtomicUsize::new(0));
+    for _ in 0..4 {
+        eventbus
+            .subscribe(ECHO, Box::new(Counting(count.clone())))
+            .await;
+    }
+
+    eventbus
+        .reconfigure(|config| config.set_fanout_chunk_size(0))
+        .unwrap();
+    assert_eq!(eventbus.config().fanout_chunk_size(), 1);
+
+    eventbus
+        .reconfigure(|config| config.set_fanout_chunk_size(2))
+        .unwrap();
+    assert_eq!(eventbus.config().fanout_chunk_size(), 2);
+
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    eventbus.publish(ECHO, &event).await.unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 4);
+}
+
+#[tokio::test]
+async fn config_file_reload() {
+    let path = std::env::temp_dir().join(format!("basu-{}.conf", uuid::Uuid::new_v4()));
+    std::fs::write(&path, "# tuned\nfanout_chunk_size = 8\n").unwrap();
+
+    let eventbus = Arc::new(EventBus::<Data>::new());
+    let changes: Arc<Mutex<Vec<ConfigChange>>> = Arc::new(Mutex::new(Vec::new()));
+    let errors = Arc::new(AtomicUsize::new(0));
+    let (reloaded, failed) = (changes.clone(), errors.clone());
+    let watch = eventbus.watch_config(
+        ConfigWatcher::new(&path)
+            .with_interval(Duration::from_millis(5))
+            .on_reload(move |changes| reloaded.lock().unwrap().extend_from_slice(changes))
+            .on_error(move |_| {
+                failed.fetch_add(1, Ordering::SeqCst);
+            }),
+    );
+
+    while eventbus.config().fanout_chunk_size() != 8 {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    assert_eq!(
+        *changes.lock().unwrap(),
+        vec![ConfigChange {
+            key: "fanout_chunk_size".to_owned(),
+            old: Some("256".to_owned()),
+            new: Some("8".to_owned()),
+        }]
+    );
+
+    std::fs::remove_file(&path).unwrap();
+    while errors.load(Ordering::SeqCst) == 0 {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    drop(watch);
+}
+
+#[tokio::test]
+async fn tenant_rate_limit_and_scope() {
+    let tenancy = Tenancy::new(|event: &Event<Data>| {
+        event
+            .data
+            .message
+            .split_once(':')
+            .map(|(tenant, _)| tenant.to_owned())
+    })
+    .with_rate_limit(2);
+    let eventbus = EventBus::<Data>::new().with_tenancy(tenancy);
+
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe_tenant(ECHO, "acme", Box::new(Counting(count.clone())))
+        .await;
+
+    let event = |message: &str| {
+        Event::new(Data {
+            message: message.to_owned(),
+        })
+    };
+    eventbus.publish(ECHO, &event("acme:1")).await.unwrap();
+    eventbus.publish(ECHO, &event("acme:2")).await.unwrap();
+    let result = eventbus.publish(ECHO, &event("acme:3")).await;
+    assert!(matches!(result, Err(PublishError::RateLimited { tenant }) if tenant == "acme"));
+
+    eventbus.publish(ECHO, &event("globex:1")).await.unwrap();
+    eventbus.publish(ECHO, &event("untenanted")).await.unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn sandboxed_handler_is_disabled_on_overrun() {
+    let eventbus = EventBus::<Data>::new();
+    let count = Arc::new(AtomicUsize::new(0));
+
+    let handler: Handler<Data> = Box::new(Counting(count.clone()));
+    eventbus
+        .subscribe("fast", handler.sandboxed(Duration::from_secs(5)))
+        .await;
+    let handler: Handler<Data> = Box::new(Sleepy {
+        delay: Duration::from_millis(50),
+        count: count.clone(),
+    });
+    eventbus
+        .subscribe("slow", handler.sandboxed(Duration::from_millis(1)))
+        .await;
+
+    let event = Event::new(Data {
+        message: "sandboxed".to_owned(),
+    });
+    eventbus.publish("fast", &event).await.unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+
+    for _ in 0..2 {
+        let result = eventbus.publish("slow", &event).await;
+        assert!(matches!(
+            result,
+            Err(PublishError::PartialFailure { failed, .. }) if matches!(failed[..], [HandlerFailure { error: BasuError::HandlerResourceExceeded, .. }])
+        ));
+    }
+}
+
+#[cfg(feature = "tower")]
+#[derive(Clone)]
+struct CountingService(Arc<AtomicUsize>);
+
+#[cfg(feature = "tower")]
+impl tower_service::Service<Event<Data>> for CountingService {
+    type Response = ();
+    type Error = BasuError;
+    type Future = futures::future::Ready<Result<(), BasuError>>;
+
+    fn poll_ready(
+        &mut self,
+        _cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        std::task::Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, event: Event<Data>) -> Self::Future {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        futures::future::ready(match event.data.message.as_str() {
+            "fail" => Err(BasuError::HandlerTimeout),
+            _ => Ok(()),
+        })
+    }
+}
+
+#[cfg(feature = "tower")]
+#[tokio::test]
+async fn tower_service_adapters() {
+    use tower_service::Service;
+
+    let eventbus = Arc::new(EventBus::<Data>::new());
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe(
+            ECHO,
+            crate::service::service_handler(CountingService(count.clone())),
+        )
+        .await;
+
+    let mut publisher = eventbus.publisher(ECHO);
+    futures::future::poll_fn(|cx| publisher.poll_ready(cx))
+        .await
+        .unwrap();
+    publisher
+        .call(Event::new(Data {
+            message: "ok".to_owned(),
+        }))
+        .await
+        .unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+
+    let result = publisher
+        .call(Event::new(Data {
+            message: "fail".to_owned(),
+        }))
+        .await;
+    assert!(matches!(
+        result,
+        Err(PublishError::PartialFailure { failed, .. }) if matches!(failed[..], [HandlerFailure { error: BasuError::HandlerError(_), .. }])
+    ));
+}
+
+#[tokio::test]
+async fn sink_and_stream_compose() {
+    let eventbus = Arc::new(EventBus::<Data>::new());
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe("forwarded", Box::new(Counting(count.clone())))
+        .await;
+
+    let incoming = eventbus.stream(ECHO, 1).await;
+    let forward = tokio::spawn(incoming.map(Ok).forward(eventbus.sink("forwarded")));
+
+    let event = |message: &str| {
+        Event::new(Data {
+            message: message.to_owned(),
+        })
+    };
+    let mut sink = eventbus.sink(ECHO);
+    sink.send(event("a")).await.unwrap();
+    sink.send(event("b")).await.unwrap();
+    sink.close().await.unwrap();
+    while count.load(Ordering::SeqCst) < 2 {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+
+    let left = eventbus.stream("left", 4).await;
+    let right = eventbus.stream("right", 4).await;
+    eventbus.publish("left", &event("l")).await.unwrap();
+    eventbus.publish("right", &event("r")).await.unwrap();
+    let mut merged = futures::stream::select_all([left, right]);
+    let mut messages = vec![
+        merged.next().await.unwrap().data.message,
+        merged.next().await.unwrap().data.message,
+    ];
+    messages.sort();
+    assert_eq!(messages, vec!["l".to_owned(), "r".to_owned()]);
+
+    let mut ended = eventbus.stream("ended", 1).await;
+    let handler_id = ended.handler_id().clone();
+    eventbus.unsubscribe("ended", &handler_id).await.unwrap();
+    assert!(ended.next().await.is_none());
+    assert!(ended.is_terminated());
+    assert!(ended.next().await.is_none());
+
+    forward.abort();
+}
+
+#[tokio::test]
+async fn publish_error_reports_partial_failure() {
+    let eventbus = EventBus::<Data>::new();
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let result = eventbus.publish(ECHO, &event).await;
+    assert!(matches!(result, Err(PublishError::EventTypeNotFound)));
+
+    let count = Arc::new(AtomicUsize::new(0));
+    for _ in 0..2 {
+        eventbus
+            .subscribe(ECHO, Box::new(Counting(count.clone())))
+            .await;
+    }
+    eventbus
+        .subscribe(
+            ECHO,
+            Box::new(Failing {
+                failures: usize::MAX,
+                calls: Arc::new(AtomicUsize::new(0)),
+            }),
+        )
+        .await;
+
+    let result = eventbus.publish(ECHO, &event).await;
+    assert!(matches!(
+        result,
+        Err(PublishError::PartialFailure { succeeded: 2, failed }) if failed.len() == 1
+    ));
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn publish_report() {
+    let eventbus = EventBus::<Data>::new();
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let result = eventbus.publish_report(ECHO, &event).await;
+    assert!(matches!(result, Err(PublishError::EventTypeNotFound)));
+
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe(ECHO, Box::new(Counting(count.clone())))
+        .await;
+    let failing_id = eventbus
+        .subscribe(
+            ECHO,
+            Box::new(Failing {
+                failures: usize::MAX,
+                calls: Arc::new(AtomicUsize::new(0)),
+            }),
+        )
+        .await;
+
+    let report = eventbus.publish_report(ECHO, &event).await.unwrap();
+    assert_eq!(report.delivered, 1);
+    assert!(!report.is_success());
+    assert!(matches!(
+        &report.failed[..],
+        [HandlerFailure { handler_id, error: BasuError::HandlerError(_), .. }] if *handler_id == failing_id
+    ));
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn subscribe_limited() {
+    let eventbus = Arc::new(EventBus::new());
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let (_handler_id, budget) = eventbus
+        .subscribe_limited(
+            ECHO,
+            NonZeroU64::new(2).unwrap(),
+            Box::new(Counting(count.clone())),
+        )
+        .await;
+    assert_eq!(budget.remaining(), 2);
+
+    eventbus.publish(ECHO, &event).await.unwrap();
+    assert_eq!(budget.remaining(), 1);
+    for _ in 0..2 {
+        eventbus.publish(ECHO, &event).await.unwrap();
+    }
+    assert!(budget.is_exhausted());
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+
+    while eventbus.get_handler_count(ECHO).await.unwrap() > 0 {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+}
+
+#[tokio::test]
+async fn subscribe_until() {
+    let eventbus = Arc::new(EventBus::new());
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe_until(
+            ECHO,
+            Box::new(Counting(count.clone())),
+            Duration::from_millis(30),
+        )
+        .await;
+    eventbus.publish(ECHO, &event).await.unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+
+    while eventbus.get_handler_count(ECHO).await.unwrap() > 0 {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    eventbus.publish(ECHO, &event).await.unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+}
+
+struct UntilStop(Arc<AtomicUsize>);
+
+#[async_trait]
+impl HandleSignal<Data> for UntilStop {
+    async fn handle(&self, event: &Event<Data>) -> Result<HandleOutcome, BasuError> {
+        self.0.fetch_add(1, Ordering::SeqCst);
+
+        Ok(match event.data.message.as_str() {
+            "stop" => HandleOutcome::Unsubscribe,
+            _ => HandleOutcome::Continue,
+        })
+    }
+}
+
+#[tokio::test]
+async fn subscribe_signal() {
+    let eventbus = Arc::new(EventBus::new());
+    let message = |message: &str| {
+        Event::new(Data {
+            message: message.to_owned(),
+        })
+    };
+
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe_signal(ECHO, Box::new(UntilStop(count.clone())))
+        .await;
+    eventbus.publish(ECHO, &message("keep")).await.unwrap();
+    eventbus.publish(ECHO, &message("stop")).await.unwrap();
+    eventbus.publish(ECHO, &message("keep")).await.unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+
+    while eventbus.get_handler_count(ECHO).await.unwrap() > 0 {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+}
+
+#[tokio::test]
+async fn unsubscribe_where() {
+    let eventbus = EventBus::new();
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let kept_id = eventbus
+        .subscribe_named(ECHO, "audit", Box::new(Counting(count.clone())))
+        .await;
+    for topic in [ECHO, "other"] {
+        eventbus
+            .subscribe_named(topic, "tmp-preview", Box::new(Counting(count.clone())))
+            .await;
+    }
+    eventbus
+        .subscribe(ECHO, Box::new(Counting(count.clone())))
+        .await;
+    assert_eq!(eventbus.handler_name(&kept_id).as_deref(), Some("audit"));
+
+    let removed = eventbus
+        .unsubscribe_where(|_event_type, info| {
+            info.name.is_some_and(|name| name.starts_with("tmp-"))
+        })
+        .await;
+    assert_eq!(removed, 2);
+    assert_eq!(eventbus.get_handler_count("other").await.unwrap(), 0);
+
+    eventbus.publish(ECHO, &event).await.unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+
+    eventbus.unsubscribe(ECHO, &kept_id).await.unwrap();
+    assert_eq!(eventbus.handler_name(&kept_id), None);
+}
+
+#[tokio::test]
+async fn clear_event_type() {
+    let eventbus = EventBus::new();
+    let count = Arc::new(AtomicUsize::new(0));
+    for name in ["preview", "preview", "audit"] {
+        eventbus
+            .subscribe_named(ECHO, name, Box::new(Counting(count.clone())))
+            .await;
+    }
+    eventbus
+        .subscribe("other", Box::new(Counting(count.clone())))
+        .await;
+
+    let removed = eventbus
+        .clear_event_type_where(ECHO, |info| info.name == Some("preview"))
+        .await
+        .unwrap();
+    assert_eq!(removed, 2);
+    assert_eq!(eventbus.get_handler_count(ECHO).await.unwrap(), 1);
+
+    assert_eq!(eventbus.clear_event_type(ECHO).await.unwrap(), 1);
+    assert!(matches!(
+        eventbus.get_handler_count(ECHO).await,
+        Err(BasuError::EventTypeNotFOUND)
+    ));
+    assert!(matches!(
+        eventbus.clear_event_type(ECHO).await,
+        Err(BasuError::EventTypeNotFOUND)
+    ));
+    assert_eq!(eventbus.get_handler_count("other").await.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn subscription_existence() {
+    let eventbus = EventBus::new();
+    assert!(!eventbus.has_subscribers(ECHO).await);
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let handler_id = eventbus
+        .subscribe(ECHO, Box::new(Counting(count.clone())))
+        .await;
+    assert!(eventbus.has_subscribers(ECHO).await);
+    assert!(eventbus.contains_handler(ECHO, &handler_id).await);
+    assert!(!eventbus.contains_handler("other", &handler_id).await);
+
+    eventbus.unsubscribe(ECHO, &handler_id).await.unwrap();
+    assert!(!eventbus.has_subscribers(ECHO).await);
+    assert!(!eventbus.contains_handler(ECHO, &handler_id).await);
+}
+
+#[tokio::test]
+async fn publish_lazy() {
+    let eventbus = EventBus::new();
+    let built = AtomicUsize::new(0);
+    let make_event = || {
+        built.fetch_add(1, Ordering::SeqCst);
+        Event::new(Data {
+            message: "{data from event}".to_owned(),
+        })
+    };
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let handler_id = eventbus
+        .subscribe(ECHO, Box::new(Counting(count.clone())))
+        .await;
+    eventbus.publish_lazy(ECHO, make_event).await.unwrap();
+    assert_eq!(built.load(Ordering::SeqCst), 1);
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+
+    eventbus.unsubscribe(ECHO, &handler_id).await.unwrap();
+    eventbus.publish_lazy(ECHO, make_event).await.unwrap();
+    assert_eq!(built.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn slow_handler_hook() {
+    let slow_handlers = Arc::new(Mutex::new(Vec::<SlowHandler>::new()));
+    let hook_slow_handlers = slow_handlers.clone();
+    let eventbus = EventBus::new()
+        .with_slow_handler_threshold(Duration::from_millis(10))
+        .with_topic_slow_handler_threshold("batch", Duration::from_secs(1))
+        .with_slow_handler_hook(move |slow| hook_slow_handlers.lock().unwrap().push(slow.clone()));
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let slow = || Sleepy {
+        delay: Duration::from_millis(20),
+        count: count.clone(),
+    };
+    let slow_id = eventbus
+        .subscribe_named(ECHO, "slow", Box::new(slow()))
+        .await;
+    eventbus
+        .subscribe(ECHO, Box::new(Counting(count.clone())))
+        .await;
+    eventbus.subscribe("batch", Box::new(slow())).await;
+
+    eventbus.publish(ECHO, &event).await.unwrap();
+    eventbus.publish("batch", &event).await.unwrap();
+    {
+        let slow_handlers = slow_handlers.lock().unwrap();
+        assert!(matches!(
+            &slow_handlers[..],
+            [SlowHandler { event_type, handler_id, handler_name: Some(name), elapsed, .. }]
+                if event_type == ECHO && *handler_id == slow_id && name == "slow"
+                    && *elapsed > Duration::from_millis(10)
+        ));
+    }
+
+    eventbus
+        .reconfigure(|config| config.set("slow_handler_threshold_ms", "").unwrap())
+        .unwrap();
+    eventbus.publish(ECHO, &event).await.unwrap();
+    assert_eq!(slow_handlers.lock().unwrap().len(), 1);
+    assert_eq!(
+        eventbus
+            .config()
+            .entries()
+            .get("slow_handler_threshold_ms.batch"),
+        Some(&"1000".to_owned())
+    );
+}
+
+#[tokio::test]
+async fn warmup() {
+    let eventbus = EventBus::<Data>::new();
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+
+    eventbus.warmup(&["order.placed", "order.filled"]).await;
+    let mut event_types = eventbus.list().await;
+    event_types.sort();
+    assert_eq!(event_types, vec!["order.filled", "order.placed"]);
+    assert_eq!(eventbus.get_handler_count("order.placed").await.unwrap(), 0);
+    eventbus.publish("order.filled", &event).await.unwrap();
+}
+
+#[tokio::test]
+async fn subscribe_all() {
+    let eventbus = EventBus::new();
+    let message = |message: &str| {
+        Event::new(Data {
+            message: message.to_owned(),
+        })
+    };
+
+    let audit = Arc::new(Mutex::new(Vec::new()));
+    let audit_id = eventbus
+        .subscribe_all(Box::new(Messages(audit.clone())))
+        .await;
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe(ECHO, Box::new(Counting(count.clone())))
+        .await;
+    assert!(eventbus.has_subscribers("unknown").await);
+
+    eventbus.publish(ECHO, &message("echo")).await.unwrap();
+    eventbus
+        .publish("unknown", &message("unknown"))
+        .await
+        .unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    assert_eq!(*audit.lock().unwrap(), vec!["echo", "unknown"]);
+
+    eventbus.unsubscribe(WILDCARD, &audit_id).await.unwrap();
+    assert!(matches!(
+        eventbus.publish("unknown", &message("unknown")).await,
+        Err(PublishError::EventTypeNotFound)
+    ));
+}
+
+struct Overlap {
+    in_flight: Arc<AtomicUsize>,
+    overlapped: Arc<AtomicBool>,
+}
+
+#[async_trait]
+impl Handle<Data> for Overlap {
+    async fn handle(&self, _event: &Event<Data>) -> Result<(), BasuError> {
+        if self.in_flight.fetch_add(1, Ordering::SeqCst) > 0 {
+            self.overlapped.store(true, Ordering::SeqCst);
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn publish_inline() {
+    let eventbus = EventBus::new();
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let overlapped = Arc::new(AtomicBool::new(false));
+    for _ in 0..3 {
+        eventbus
+            .subscribe(
+                ECHO,
+                Box::new(Overlap {
+                    in_flight: in_flight.clone(),
+                    overlapped: overlapped.clone(),
+                }),
+            )
+            .await;
+    }
+
+    eventbus.publish_inline(ECHO, &event).await.unwrap();
+    assert!(!overlapped.load(Ordering::SeqCst));
+
+    eventbus.publish(ECHO, &event).await.unwrap();
+    assert!(overlapped.load(Ordering::SeqCst));
+}
+
+#[tokio::test]
+async fn hierarchical_patterns() {
+    let eventbus = EventBus::new();
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    assert!(is_pattern("orders.*") && is_pattern("orders.#"));
+    assert!(!is_pattern("orders.created") && !is_pattern(WILDCARD));
+
+    let counts: Vec<_> = ["orders.created", "orders.*", "orders.#", "*.created"]
+        .into_iter()
+        .map(|event_type| (event_type, Arc::new(AtomicUsize::new(0))))
+        .collect();
+    for (event_type, count) in &counts {
+        eventbus
+            .subscribe(event_type, Box::new(Counting(count.clone())))
+            .await;
+    }
+    let received = || {
+        counts
+            .iter()
+            .map(|(_, count)| count.swap(0, Ordering::SeqCst))
+            .collect::<Vec<_>>()
+    };
+
+    eventbus.publish("orders.created", &event).await.unwrap();
+    assert_eq!(received(), vec![1, 1, 1, 1]);
+    eventbus.publish("orders.created.eu", &event).await.unwrap();
+    assert_eq!(received(), vec![0, 0, 1, 0]);
+    eventbus.publish("orders", &event).await.unwrap();
+    assert_eq!(received(), vec![0, 0, 1, 0]);
+    eventbus.publish("payments.created", &event).await.unwrap();
+    assert_eq!(received(), vec![0, 0, 0, 1]);
+
+    eventbus.clear_event_type("orders.#").await.unwrap();
+    assert!(matches!(
+        eventbus.publish("orders", &event).await,
+        Err(PublishError::EventTypeNotFound)
+    ));
+}
+
+struct Length(Option<usize>);
+
+#[async_trait]
+impl HandleRequest<Data, usize> for Length {
+    async fn handle(&self, event: &Event<Data>) -> Result<Option<usize>, BasuError> {
+        Ok(self.0.map(|extra| event.data.message.len() + extra))
+    }
+}
+
+#[tokio::test]
+async fn publish_request() {
+    let eventbus = EventBus::new();
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe("request", Box::new(Counting(count.clone())))
+        .await;
+    eventbus
+        .subscribe_responder("request", Box::new(Length(None)))
+        .await;
+    assert!(matches!(
+        eventbus.publish_request::<usize>("request", &event).await,
+        Err(PublishError::NoReply)
+    ));
+
+    eventbus
+        .subscribe_responder("request", Box::new(Length(Some(0))))
+        .await;
+    let reply: usize = eventbus.publish_request("request", &event).await.unwrap();
+    assert_eq!(reply, 17);
+
+    eventbus
+        .subscribe_responder("request", Box::new(Length(Some(1))))
+        .await;
+    let mut replies: Vec<usize> = eventbus
+        .publish_request_all("request", &event)
+        .await
+        .unwrap();
+    replies.sort_unstable();
+    assert_eq!(replies, vec![17, 18]);
+    assert_eq!(count.load(Ordering::SeqCst), 3);
+
+    eventbus.publish("request", &event).await.unwrap();
+    assert!(eventbus
+        .publish_request_all::<usize>("missing", &event)
+        .await
+        .is_err());
+}
+
+struct Quote(u64, Duration);
+
+#[async_trait]
+impl HandleRequest<Data, u64> for Quote {
+    async fn handle(&self, _event: &Event<Data>) -> Result<Option<u64>, BasuError> {
+        tokio::time::sleep(self.1).await;
+        Ok(Some(self.0))
+    }
+}
+
+#[tokio::test]
+async fn publish_gather() {
+    let eventbus = EventBus::new();
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    eventbus
+        .subscribe_responder("quote", Box::new(Quote(20, Duration::ZERO)))
+        .await;
+    eventbus
+        .subscribe_responder("quote", Box::new(Quote(10, Duration::from_millis(5))))
+        .await;
+    let slow = eventbus
+        .subscribe_responder("quote", Box::new(Quote(5, Duration::from_millis(300))))
+        .await;
+
+    let mut gathered: Gathered<u64> = eventbus
+        .publish_gather("quote", &event, Duration::from_millis(100))
+        .await
+        .unwrap();
+    gathered.replies.sort_unstable();
+    assert_eq!(gathered.replies, vec![10, 20]);
+    assert_eq!(gathered.timed_out, vec![slow]);
+    assert!(gathered.failed.is_empty() && !gathered.is_complete());
+
+    let gathered: Gathered<u64> = eventbus
+        .publish_gather("quote", &event, Duration::from_secs(5))
+        .await
+        .unwrap();
+    assert_eq!(gathered.replies.len(), 3);
+    assert!(gathered.is_complete());
+}
+
+struct Ranked(i32, Arc<Mutex<Vec<i32>>>);
+
+#[async_trait]
+impl Handle<Data> for Ranked {
+    async fn handle(&self, _event: &Event<Data>) -> Result<(), BasuError> {
+        // higher priorities take longer, so only the grouping keeps them first
+        tokio::time::sleep(Duration::from_millis(self.0.max(0) as u64)).await;
+        self.1.lock().unwrap().push(self.0);
+
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn priority_ordering() {
+    let eventbus = EventBus::new();
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let ranks = Arc::new(Mutex::new(Vec::new()));
+    for priority in [0, 20, -5, 10, 20] {
+        eventbus
+            .subscribe_with_priority(
+                "ranked",
+                priority,
+                Box::new(Ranked(priority, ranks.clone())),
+            )
+            .await;
+    }
+    let handler_id = eventbus
+        .subscribe("ranked", Box::new(Ranked(DEFAULT_PRIORITY, ranks.clone())))
+        .await;
+    assert_eq!(eventbus.handler_priority(&handler_id), DEFAULT_PRIORITY);
+
+    eventbus.publish("ranked", &event).await.unwrap();
+    assert_eq!(*ranks.lock().unwrap(), vec![20, 20, 10, 0, 0, -5]);
+}
+
+#[tokio::test]
+async fn backfill() {
+    let eventbus = EventBus::new();
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe("import", Box::new(Counting(count.clone())))
+        .await;
+    eventbus
+        .subscribe(
+            "broken",
+            Box::new(Failing {
+                failures: 1,
+                calls: Arc::new(AtomicUsize::new(0)),
+            }),
+        )
+        .await;
+    let source = || {
+        ["import", "unknown", "broken", "import", "broken", "import"]
+            .into_iter()
+            .map(|event_type| {
+                let event = Event::new(Data {
+                    message: event_type.to_owned(),
+                });
+                (event_type.to_owned(), event)
+            })
+    };
+
+    let reported = Arc::new(Mutex::new(Vec::new()));
+    let options = BackfillOptions::new()
+        .with_rate_limit(200)
+        .with_progress(2, {
+            let reported = reported.clone();
+            move |progress| reported.lock().unwrap().push(progress.total())
+        });
+    let progress = eventbus.backfill(source(), options).await.unwrap();
+    assert_eq!(
+        (progress.published, progress.skipped, progress.failed),
+        (4, 1, 1)
+    );
+    assert!(progress.elapsed >= Duration::from_millis(25));
+    assert_eq!(*reported.lock().unwrap(), vec![2, 4, 6]);
+    assert_eq!(count.load(Ordering::SeqCst), 3);
+
+    eventbus
+        .subscribe(
+            "broken",
+            Box::new(Failing {
+                failures: 1,
+                calls: Arc::new(AtomicUsize::new(0)),
+            }),
+        )
+        .await;
+    let stopped = eventbus
+        .backfill(source(), BackfillOptions::new().with_stop_on_error())
+        .await;
+    assert!(matches!(stopped, Err(PublishError::PartialFailure { .. })));
+    assert_eq!(count.load(Ordering::SeqCst), 4);
+}
+
+#[tokio::test]
+async fn subscription_guard() {
+    let eventbus = Arc::new(EventBus::new());
+    let count = Arc::new(AtomicUsize::new(0));
+    let kept = eventbus
+        .subscribe_guarded(ECHO, Box::new(Counting(count.clone())))
+        .await
+        .detach();
+    let subscription = eventbus
+        .subscribe_guarded(ECHO, Box::new(Counting(count.clone())))
+        .await;
+    assert_eq!(subscription.event_type(), ECHO);
+    assert!(
+        eventbus
+            .contains_handler(ECHO, subscription.handler_id())
+            .await
+    );
+
+    drop(subscription);
+    while eventbus.get_handler_count(ECHO).await.unwrap() > 1 {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    assert!(eventbus.contains_handler(ECHO, &kept).await);
+
+    let subscription = eventbus
+        .subscribe_guarded(ECHO, Box::new(Counting(count.clone())))
+        .await;
+    let handler_id = subscription.handler_id().clone();
+    subscription.unsubscribe().await;
+    assert!(!eventbus.contains_handler(ECHO, &handler_id).await);
+}
+
+#[tokio::test]
+async fn closure_handlers() {
+    let eventbus = EventBus::new();
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    eventbus
+        .subscribe(ECHO, {
+            let messages = messages.clone();
+            Box::new(move |event: &Event<Data>| {
+                let messages = messages.clone();
+                let message = event.data.message.clone();
+                async move {
+                    messages.lock().unwrap().push(message);
+                    Ok(())
+                }
+            })
+        })
+        .await;
+    eventbus
+        .subscribe(
+            ECHO,
+            handler_fn(|event: &Event<Data>| {
+                let failed = event.data.message.is_empty();
+                async move {
+                    match failed {
+                        true => Err(anyhow::anyhow!("empty message").into()),
+                        false => Ok(()),
+                    }
+                }
+            }),
+        )
+        .await;
+
+    eventbus.publish(ECHO, &event).await.unwrap();
+    assert_eq!(*messages.lock().unwrap(), vec![event.data.message.clone()]);
+}
+
+#[tokio::test]
+async fn inspect_handlers() {
+    let eventbus = EventBus::new();
+    let count = Arc::new(AtomicUsize::new(3));
+    let first = eventbus
+        .subscribe(ECHO, Box::new(Counting(count.clone())))
+        .await;
+    let second = eventbus
+        .subscribe("other", Box::new(Counting(count.clone())))
+        .await;
+    eventbus.subscribe(ECHO, Box::new(HandlerA)).await;
+
+    let mut visited = Vec::new();
+    eventbus
+        .inspect_handlers(|event_type, handler_id, counting: &Counting| {
+            visited.push((event_type.to_owned(), handler_id.clone()));
+            counting.0.fetch_add(1, Ordering::SeqCst);
+        })
+        .await;
+    visited.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(
+        visited,
+        vec![(ECHO.to_owned(), first), ("other".to_owned(), second)]
+    );
+    assert_eq!(count.load(Ordering::SeqCst), 5);
+}
+
+#[tokio::test]
+async fn broadcast_bridge() {
+    let eventbus = Arc::new(EventBus::<Data>::new());
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let (mirrored, mut mirror_receiver) = tokio::sync::broadcast::channel(8);
+    eventbus.mirror_to_broadcast(ECHO, mirrored).await;
+    eventbus.publish(ECHO, &event).await.unwrap();
+    assert_eq!(
+        mirror_receiver.recv().await.unwrap().data.message,
+        event.data.message
+    );
+
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe("forwarded", Box::new(Counting(count.clone())))
+        .await;
+    let (forwarded, forward_receiver) = tokio::sync::broadcast::channel(8);
+    let forwarding = eventbus.forward_broadcast("forwarded", forward_receiver);
+    forwarded.send(Arc::new(event.clone())).unwrap();
+    forwarded.send(Arc::new(event)).unwrap();
+    drop(forwarded);
+    forwarding.await.unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn stream_lag_policy() {
+    let eventbus = EventBus::<Data>::new();
+    let event = |message: &str| {
+        Event::new(Data {
+            message: message.to_owned(),
+        })
+    };
+    let mut latest = eventbus
+        .stream_with_policy("latest", 2, LagPolicy::SkipToLatest)
+        .await;
+    let mut disconnected = eventbus
+        .stream_with_policy("disconnected", 1, LagPolicy::Disconnect)
+        .await;
+    for message in ["a", "b", "c"] {
+        eventbus.publish("latest", &event(message)).await.unwrap();
+        eventbus
+            .publish("disconnected", &event(message))
+            .await
+            .unwrap();
+    }
+
+    let lag = latest.lag();
+    assert_eq!((lag.behind, lag.skipped), (2, 1));
+    assert!(lag.oldest_pending.is_some());
+    assert_eq!(latest.next().await.unwrap().data.message, "b");
+    assert_eq!(latest.next().await.unwrap().data.message, "c");
+    assert_eq!(latest.lag().behind, 0);
+
+    assert_eq!(disconnected.next().await.unwrap().data.message, "a");
+    assert!(disconnected.next().await.is_none());
+    assert!(disconnected.is_terminated());
+}
+
+#[tokio::test]
+async fn watch_latest_event() {
+    let eventbus = EventBus::<Data>::new();
+    let event = |message: &str| {
+        Event::new(Data {
+            message: message.to_owned(),
+        })
+    };
+    let mut first = eventbus.watch("price").await;
+    assert!(first.borrow().is_none());
+    assert!(!eventbus.has_subscribers("price").await);
+    assert!(matches!(
+        eventbus.publish("price", &event("1")).await,
+        Err(PublishError::EventTypeNotFound)
+    ));
+    assert_eq!(
+        first.borrow_and_update().as_ref().unwrap().data.message,
+        "1"
+    );
+
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe("price", Box::new(Counting(count.clone())))
+        .await;
+    eventbus.publish("price", &event("2")).await.unwrap();
+    first.changed().await.unwrap();
+    assert_eq!(
+        first.borrow_and_update().as_ref().unwrap().data.message,
+        "2"
+    );
+
+    let second = eventbus.watch("price").await;
+    assert_eq!(second.borrow().as_ref().unwrap().data.message, "2");
+    assert_eq!(eventbus.get_handler_count("price").await.unwrap(), 1);
+
+    eventbus.clear().await;
+    let third = eventbus.watch("price").await;
+    assert_eq!(third.borrow().as_ref().unwrap().data.message, "2");
+    let _ = eventbus.publish("price", &event("3")).await;
+    assert_eq!(third.borrow().as_ref().unwrap().data.message, "3");
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn flush_waits_for_publishes() {
+    let eventbus = Arc::new(EventBus::new());
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe(
+            "write",
+            Box::new(Sleepy {
+                delay: Duration::from_millis(30),
+                count: count.clone(),
+            }),
+        )
+        .await;
+
+    let publishing = tokio::spawn({
+        let eventbus = eventbus.clone();
+        async move { eventbus.publish("write", &event).await }
+    });
+    // let the publish start its handler
+    tokio::task::yield_now().await;
+    assert_eq!(count.load(Ordering::SeqCst), 0);
+
+    eventbus.flush("write").await;
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    publishing.await.unwrap().unwrap();
+    eventbus.flush("unknown").await;
+}
+
+#[tokio::test]
+async fn retained_event_reaches_late_subscribers() {
+    let eventbus = EventBus::<Data>::new();
+    let event = |message: &str| {
+        Event::new(Data {
+            message: message.to_owned(),
+        })
+    };
+    eventbus
+        .publish_retained("config", event("1"))
+        .await
+        .unwrap();
+    eventbus
+        .publish_retained("config", event("2"))
+        .await
+        .unwrap();
+    assert!(eventbus.has_retained("config"));
+
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    eventbus
+        .subscribe("config", Box::new(Messages(messages.clone())))
+        .await;
+    assert_eq!(*messages.lock().unwrap(), vec!["2"]);
+
+    eventbus.publish("config", &event("3")).await.unwrap();
+    assert_eq!(*messages.lock().unwrap(), vec!["2", "3"]);
+
+    eventbus.clear_retained("config");
+    let late = Arc::new(Mutex::new(Vec::new()));
+    eventbus
+        .subscribe("config", Box::new(Messages(late.clone())))
+        .await;
+    assert!(late.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn buffered_publishes_on_commit_only() {
+    let eventbus = EventBus::<Data>::new();
+    let event = |message: &str| {
+        Event::new(Data {
+            message: message.to_owned(),
+        })
+    };
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    eventbus
+        .subscribe("order", Box::new(Messages(messages.clone())))
+        .await;
+
+    let mut buffered = eventbus.buffered();
+    buffered.publish("order", event("rolled back"));
+    drop(buffered);
+    assert!(messages.lock().unwrap().is_empty());
+
+    let mut buffered = eventbus.buffered();
+    buffered.publish("order", event("1"));
+    buffered.publish("unknown", event("2"));
+    buffered.publish("order", event("3"));
+    assert_eq!(buffered.len(), 3);
+    assert!(messages.lock().unwrap().is_empty());
+    assert!(matches!(
+        buffered.commit().await,
+        Err(PublishError::EventTypeNotFound)
+    ));
+    assert_eq!(*messages.lock().unwrap(), vec!["1", "3"]);
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Locale(&'static str);
+
+#[tokio::test]
+async fn context_follows_causation_chain() {
+    let orders = EventBus::<Data>::new();
+    let mail = Arc::new(EventBus::<Data>::new());
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    mail.subscribe(
+        "mail",
+        handler_fn({
+            let seen = seen.clone();
+            move |_event: &Event<Data>| {
+                seen.lock()
+                    .unwrap()
+                    .push((context::get::<Locale>(), context::get::<u32>()));
+                async { Ok(()) }
+            }
+        }),
+    )
+    .await;
+    orders
+        .subscribe(
+            "order",
+            handler_fn({
+                let mail = mail.clone();
+                move |event: &Event<Data>| {
+                    let mail = mail.clone();
+                    let event = Event::new(Data {
+                        message: event.data.message.clone(),
+                    });
+                    async move {
+                        context::set(Locale("fr"))?;
+                        mail.publish("mail", &event).await?;
+                        Ok(())
+                    }
+                }
+            }),
+        )
+        .await;
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+
+    assert!(matches!(
+        context::set(Locale("en")),
+        Err(BasuError::NoContext)
+    ));
+    Context::new()
+        .with(7u32)
+        .unwrap()
+        .scope(orders.publish("order", &event))
+        .await
+        .unwrap();
+    mail.publish("mail", &event).await.unwrap();
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec![(Some(Locale("fr")), Some(7)), (None, None)]
+    );
+}
+
+#[tokio::test]
+async fn replay_delivers_history_before_live_events() {
+    let eventbus = EventBus::<Data>::new()
+        .with_history(2)
+        .with_topic_history("audit", 0);
+    let event = |message: &str| {
+        Event::new(Data {
+            message: message.to_owned(),
+        })
+    };
+    let count = Arc::new(AtomicUsize::new(0));
+    for event_type in ["chat", "audit"] {
+        eventbus
+            .subscribe(event_type, Box::new(Counting(count.clone())))
+            .await;
+    }
+    for message in ["1", "2", "3"] {
+        let _ = eventbus.publish("chat", &event(message)).await;
+    }
+    let _ = eventbus.publish("audit", &event("1")).await;
+    assert_eq!(eventbus.history("chat").len(), 2);
+    assert!(eventbus.history("audit").is_empty());
+
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    eventbus
+        .subscribe_with_replay("chat", 5, Box::new(Messages(messages.clone())))
+        .await;
+    let last = Arc::new(Mutex::new(Vec::new()));
+    eventbus
+        .subscribe_with_replay("chat", 1, Box::new(Messages(last.clone())))
+        .await;
+    eventbus.publish("chat", &event("4")).await.unwrap();
+    assert_eq!(*messages.lock().unwrap(), vec!["2", "3", "4"]);
+    assert_eq!(*last.lock().unwrap(), vec!["3", "4"]);
+
+    eventbus.clear_history("chat");
+    let late = Arc::new(Mutex::new(Vec::new()));
+    eventbus
+        .subscribe_with_replay("chat", 5, Box::new(Messages(late.clone())))
+        .await;
+    assert!(late.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn owner_is_reported_with_failures() {
+    let eventbus = EventBus::<Data>::new();
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let handler_id = eventbus
+        .subscribe_owned(
+            "order",
+            "team-payments",
+            Box::new(Failing {
+                failures: 1,
+                calls: Arc::new(AtomicUsize::new(0)),
+            }),
+        )
+        .await;
+    eventbus.subscribe("order", Box::new(HandlerA)).await;
+    assert_eq!(
+        eventbus.handler_owner(&handler_id).as_deref(),
+        Some("team-payments")
+    );
+
+    let report = eventbus.publish_report("order", &event).await.unwrap();
+    assert!(matches!(
+        &report.failed[..],
+        [HandlerFailure { owner: Some(owner), .. }] if owner == "team-payments"
+    ));
+
+    eventbus.unsubscribe("order", &handler_id).await.unwrap();
+    assert_eq!(eventbus.handler_owner(&handler_id), None);
+}
+
+#[tokio::test]
+async fn retry_backs_off_exponentially() {
+    let policy = RetryPolicy::exponential(4, Duration::from_millis(10))
+        .with_max_backoff(Duration::from_millis(25));
+    assert_eq!(policy.delay(1), Duration::from_millis(10));
+    assert_eq!(policy.delay(2), Duration::from_millis(20));
+    assert_eq!(policy.delay(3), Duration::from_millis(25));
+    let jittered = policy.with_jitter(Duration::from_millis(5)).delay(1);
+    assert!(jittered >= Duration::from_millis(10) && jittered <= Duration::from_millis(15));
+
+    let eventbus = EventBus::new();
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let calls = Arc::new(AtomicUsize::new(0));
+    let handler: Handler<Data> = Box::new(Failing {
+        failures: 2,
+        calls: calls.clone(),
+    });
+    let handler = handler.with_retry(
+        RetryPolicy::exponential(3, Duration::from_millis(1)).with_jitter(Duration::from_millis(1)),
+    );
+    eventbus.subscribe("retry", handler).await;
+
+    eventbus.publish("retry", &event).await.unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn validate_reports_misconfigurations() {
+    let eventbus = EventBus::<Data>::new()
+        .with_declared_topics(["order.created", "order.paid"])
+        .with_topic_slow_handler_threshold("ordr.created", Duration::from_millis(100));
+    eventbus
+        .subscribe("order.created", Box::new(HandlerA))
+        .await;
+    eventbus.subscribe("audit", Box::new(HandlerA)).await;
+
+    let report = eventbus.validate().await;
+    assert_eq!(
+        report.issues,
+        vec![
+            ValidationIssue::NoSubscribers {
+                event_type: "order.paid".to_owned(),
+            },
+            ValidationIssue::Undeclared {
+                event_type: "audit".to_owned(),
+            },
+            ValidationIssue::UnknownTopicSetting {
+                event_type: "ordr.created".to_owned(),
+                setting: "slow_handler_threshold",
+            },
+            ValidationIssue::SlowThresholdWithoutHook,
+        ]
+    );
+
+    eventbus.subscribe("order.*", Box::new(HandlerA)).await;
+    eventbus
+        .unsubscribe_where(|event_type, _info| event_type == "audit")
+        .await;
+    assert_eq!(eventbus.validate().await.issues.len(), 2);
+    assert!(EventBus::<Data>::new().validate().await.is_ok());
+}
+
+#[tokio::test]
+async fn handler_timeout_abandons_stalled_handlers() {
+    let eventbus = EventBus::new().with_handler_timeout(Duration::from_millis(20));
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe(
+            "stalled",
+            Box::new(Sleepy {
+                delay: Duration::from_secs(5),
+                count: count.clone(),
+            }),
+        )
+        .await;
+    eventbus.subscribe("stalled", Box::new(HandlerA)).await;
+
+    let report = eventbus.publish_report("stalled", &event).await.unwrap();
+    assert_eq!(report.delivered, 1);
+    assert!(matches!(
+        report.failed[..],
+        [HandlerFailure {
+            error: BasuError::HandlerTimeout,
+            ..
+        }]
+    ));
+    assert!(report.duration < Duration::from_secs(5));
+    assert_eq!(count.load(Ordering::SeqCst), 0);
+    assert_eq!(
+        eventbus
+            .config()
+            .entries()
+            .get("handler_timeout_ms")
+            .map(String::as_str),
+        Some("20")
+    );
+}
+
+#[tokio::test]
+async fn circuit_breaker_skips_failing_handlers() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let eventbus = EventBus::new()
+        .with_circuit_breaker(CircuitBreaker::new(2, Duration::from_millis(50)))
+        .with_circuit_hook({
+            let events = events.clone();
+            move |event| events.lock().unwrap().push(event.clone())
+        });
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let calls = Arc::new(AtomicUsize::new(0));
+    let handler_id = eventbus
+        .subscribe(
+            "flaky",
+            Box::new(Failing {
+                failures: 3,
+                calls: calls.clone(),
+            }),
+        )
+        .await;
+
+    let _ = eventbus.publish("flaky", &event).await;
+    let _ = eventbus.publish("flaky", &event).await;
+    assert!(eventbus.is_circuit_open(&handler_id));
+    eventbus.publish("flaky", &event).await.unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    let _ = eventbus.publish("flaky", &event).await;
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    eventbus.publish("flaky", &event).await.unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 4);
+    assert!(!eventbus.is_circuit_open(&handler_id));
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![
+            CircuitEvent::Opened {
+                handler_id: handler_id.clone(),
+                failures: 2,
+            },
+            CircuitEvent::Opened {
+                handler_id: handler_id.clone(),
+                failures: 3,
+            },
+            CircuitEvent::Closed { handler_id },
+        ]
+    );
+}
+
+static STATIC_BUS: LazyEventBus<Data> = EventBus::const_new();
+
+fn history_bus() -> EventBus<Data> {
+    EventBus::new().with_history(1)
+}
+
+static STATIC_HISTORY_BUS: LazyEventBus<Data> = LazyEventBus::with_init(history_bus);
+
+#[tokio::test]
+async fn static_bus_is_created_on_first_access() {
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let count = Arc::new(AtomicUsize::new(0));
+    STATIC_BUS
+        .subscribe("static", Box::new(Counting(count.clone())))
+        .await;
+    STATIC_BUS.publish("static", &event).await.unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    assert!(std::ptr::eq(STATIC_BUS.get(), &*STATIC_BUS));
+
+    STATIC_HISTORY_BUS
+        .subscribe("static", Box::new(Counting(count.clone())))
+        .await;
+    let _ = STATIC_HISTORY_BUS.publish("static", &event).await;
+    assert_eq!(STATIC_HISTORY_BUS.history("static").len(), 1);
+}
+
+#[tokio::test]
+async fn error_policy() {
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let eventbus = EventBus::new().with_error_policy(ErrorPolicy::FailFast);
+    let calls = Arc::new(AtomicUsize::new(0));
+    let count = Arc::new(AtomicUsize::new(0));
+    let failing_id = eventbus
+        .subscribe_with_priority(
+            "policy",
+            DEFAULT_PRIORITY + 1,
+            Box::new(Failing {
+                failures: usize::MAX,
+                calls: calls.clone(),
+            }),
+        )
+        .await;
+    eventbus
+        .subscribe("policy", Box::new(Counting(count.clone())))
+        .await;
+
+    let result = eventbus.publish_inline("policy", &event).await;
+    assert!(
+        matches!(result, Err(PublishError::HandlerFailed(failure)) if failure.handler_id == failing_id)
+    );
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(count.load(Ordering::SeqCst), 0);
+
+    eventbus
+        .reconfigure(|config| config.set_error_policy(ErrorPolicy::Ignore))
+        .unwrap();
+    eventbus.publish("policy", &event).await.unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    assert_eq!(eventbus.config().error_policy(), ErrorPolicy::Ignore);
+}
+
+async fn record_message(
+    extract::Data(data): extract::Data<Data>,
+    State(seen): State<Arc<Mutex<Vec<String>>>>,
+    Ctx(context): Ctx,
+) -> Result<(), BasuError> {
+    seen.lock()
+        .unwrap()
+        .push(format!("{} {}", data.message, context.len()));
+    Ok(())
+}
+
+#[tokio::test]
+async fn extract_handler() {
+    let eventbus = EventBus::new();
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    eventbus
+        .subscribe("extract", extract_fn(record_message))
+        .await;
+
+    let result = eventbus.publish("extract", &event).await;
+    assert!(matches!(
+        result,
+        Err(PublishError::PartialFailure { failed, .. }) if matches!(failed[..], [HandlerFailure { error: BasuError::MissingState(_), .. }])
+    ));
+
+    let seen = Arc::new(Mutex::new(Vec::<String>::new()));
+    Context::new()
+        .with(seen.clone())
+        .unwrap()
+        .scope(eventbus.publish("extract", &event))
+        .await
+        .unwrap();
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec!["{data from event} 1".to_owned()]
+    );
+}
+
+#[tokio::test]
+async fn report_skipped_handlers() {
+    let eventbus =
+        EventBus::new().with_circuit_breaker(CircuitBreaker::new(1, Duration::from_secs(60)));
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let calls = Arc::new(AtomicUsize::new(0));
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe(
+            "report",
+            Box::new(Failing {
+                failures: usize::MAX,
+                calls: calls.clone(),
+            }),
+        )
+        .await;
+    eventbus
+        .subscribe("report", Box::new(Counting(count.clone())))
+        .await;
+
+    let report = eventbus.publish_report("report", &event).await.unwrap();
+    assert_eq!(
+        (report.delivered, report.failed.len(), report.skipped),
+        (1, 1, 0)
+    );
+
+    let report = eventbus.publish_report("report", &event).await.unwrap();
+    assert_eq!(
+        (report.delivered, report.failed.len(), report.skipped),
+        (1, 0, 1)
+    );
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn circuit_skips_are_not_succeeded() {
+    let eventbus =
+        EventBus::new().with_circuit_breaker(CircuitBreaker::new(1, Duration::from_secs(60)));
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let calls = Arc::new(AtomicUsize::new(0));
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe(
+            "outage",
+            Box::new(Failing {
+                failures: usize::MAX,
+                calls: calls.clone(),
+            }),
+        )
+        .await;
+    eventbus
+        .subscribe("outage", Box::new(Counting(count.clone())))
+        .await;
+    let _ = eventbus.publish("outage", &event).await;
+
+    let failing_id = eventbus
+        .subscribe(
+            "outage",
+            Box::new(Failing {
+                failures: usize::MAX,
+                calls: calls.clone(),
+            }),
+        )
+        .await;
+    let result = eventbus.publish("outage", &event).await;
+    assert!(matches!(
+        result,
+        Err(PublishError::PartialFailure { succeeded: 1, failed }) if failed.len() == 1 && failed[0].handler_id == failing_id
+    ));
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn fail_fast_counts_skipped_handlers() {
+    let eventbus = EventBus::new().with_error_policy(ErrorPolicy::FailFast);
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let calls = Arc::new(AtomicUsize::new(0));
+    let count = Arc::new(AtomicUsize::new(0));
+    let failing_id = eventbus
+        .subscribe_with_priority(
+            "abort",
+            DEFAULT_PRIORITY + 1,
+            Box::new(Failing {
+                failures: usize::MAX,
+                calls: calls.clone(),
+            }),
+        )
+        .await;
+    for _ in 0..2 {
+        eventbus
+            .subscribe("abort", Box::new(Counting(count.clone())))
+            .await;
+    }
+
+    let report = eventbus.publish_report("abort", &event).await.unwrap();
+    assert_eq!(
+        (report.delivered, report.failed.len(), report.skipped),
+        (0, 1, 2)
+    );
+    assert_eq!(report.failed[0].handler_id, failing_id);
+    assert_eq!(count.load(Ordering::SeqCst), 0);
+
+    eventbus
+        .reconfigure(|config| config.set_error_policy(ErrorPolicy::CollectAll))
+        .unwrap();
+    let report = eventbus.publish_report("abort", &event).await.unwrap();
+    assert_eq!(
+        (report.delivered, report.failed.len(), report.skipped),
+        (2, 1, 0)
+    );
+}
+
+#[tokio::test]
+async fn request_publishes_are_replayed() {
+    let eventbus = EventBus::new().with_history(5);
+    let event = |message: &str| {
+        Event::new(Data {
+            message: message.to_owned(),
+        })
+    };
+    eventbus
+        .subscribe_responder("quote", Box::new(Length(Some(0))))
+        .await;
+    eventbus.publish("quote", &event("1")).await.unwrap();
+    let replies: Vec<usize> = eventbus
+        .publish_request_all("quote", &event("22"))
+        .await
+        .unwrap();
+    assert_eq!(replies, vec![2]);
+    let quotes: Gathered<usize> = eventbus
+        .publish_gather("quote", &event("333"), Duration::from_secs(1))
+        .await
+        .unwrap();
+    assert_eq!(quotes.replies, vec![3]);
+
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    eventbus
+        .subscribe_with_replay("quote", 5, Box::new(Messages(messages.clone())))
+        .await;
+    assert_eq!(*messages.lock().unwrap(), vec!["1", "22", "333"]);
+}
+
+#[tokio::test]
+async fn reports_carry_bus_name() {
+    let slow = Arc::new(Mutex::new(Vec::new()));
+    let eventbus = EventBus::named("payments")
+        .with_label("region", "eu")
+        .with_slow_handler_threshold(Duration::ZERO)
+        .with_slow_handler_hook({
+            let slow = slow.clone();
+            move |report| {
+                slow.lock()
+                    .unwrap()
+                    .push((report.bus.clone(), report.labels.clone()))
+            }
+        });
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    eventbus
+        .subscribe(
+            "named",
+            Box::new(Failing {
+                failures: usize::MAX,
+                calls: Arc::new(AtomicUsize::new(0)),
+            }),
+        )
+        .await;
+
+    let report = eventbus.publish_report("named", &event).await.unwrap();
+    assert_eq!(report.failed[0].bus.as_deref(), Some("payments"));
+    assert_eq!(report.failed[0].labels["region"], "eu");
+    assert_eq!(
+        *slow.lock().unwrap(),
+        vec![(Some("payments".to_owned()), eventbus.labels().clone())]
+    );
+}
+
+#[tokio::test]
+async fn failed_publish_keeps_previous_retained_event() {
+    let eventbus = EventBus::<Data>::new();
+    let event = |message: &str| {
+        Event::new(Data {
+            message: message.to_owned(),
+        })
+    };
+    eventbus
+        .publish_retained("config", event("1"))
+        .await
+        .unwrap();
+    // fails for the retained event on subscribe and for the next publish
+    eventbus
+        .subscribe(
+            "config",
+            Box::new(Failing {
+                failures: 2,
+                calls: Arc::new(AtomicUsize::new(0)),
+            }),
+        )
+        .await;
+    assert!(eventbus
+        .publish_retained("config", event("2"))
+        .await
+        .is_err());
+
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    eventbus
+        .subscribe("config", Box::new(Messages(messages.clone())))
+        .await;
+    assert_eq!(*messages.lock().unwrap(), vec!["1"]);
+
+    eventbus
+        .publish_retained("config", event("3"))
+        .await
+        .unwrap();
+    let late = Arc::new(Mutex::new(Vec::new()));
+    eventbus
+        .subscribe("config", Box::new(Messages(late.clone())))
+        .await;
+    assert_eq!(*late.lock().unwrap(), vec!["3"]);
+}
+
+#[tokio::test]
+async fn zero_window_period_is_rejected() {
+    let eventbus = Arc::new(EventBus::<Data>::new());
+    let result = eventbus
+        .window(
+            "sample",
+            Window::Sliding {
+                size: Duration::from_secs(1),
+                slide: Duration::ZERO,
+            },
+            |events| events[0].data.clone(),
+            "joined",
+        )
+        .await;
+    assert!(matches!(result, Err(BasuError::InvalidConfig(_))));
+    assert!(!eventbus.has_subscribers("sample").await);
+}
+
+#[tokio::test]
+async fn publishes_to_unknown_event_types_are_not_recorded() {
+    let eventbus = EventBus::new().with_history(5);
+    let event = Event::new(Data {
+        message: "lost".to_owned(),
+    });
+    let result = eventbus.publish("missing", &event).await;
+    assert!(matches!(result, Err(PublishError::EventTypeNotFound)));
+    assert!(eventbus.history("missing").is_empty());
+}
+
+#[tokio::test]
+async fn stalled_handlers_fail_publish_with_timeout() {
+    let eventbus = EventBus::new().with_handler_timeout(Duration::from_millis(20));
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe(
+            "stalled",
+            Box::new(Sleepy {
+                delay: Duration::from_secs(5),
+                count: count.clone(),
+            }),
+        )
+        .await;
+    eventbus.subscribe("stalled", Box::new(HandlerA)).await;
+
+    let result = eventbus.publish("stalled", &event).await;
+    assert!(matches!(
+        result,
+        Err(PublishError::Timeout { succeeded: 1, timed_out }) if timed_out.len() == 1
+    ));
+
+    eventbus
+        .subscribe(
+            "stalled",
+            Box::new(Failing {
+                failures: usize::MAX,
+                calls: Arc::new(AtomicUsize::new(0)),
+            }),
+        )
+        .await;
+    let result = eventbus.publish("stalled", &event).await;
+    assert!(matches!(
+        result,
+        Err(PublishError::PartialFailure { succeeded: 1, failed }) if failed.len() == 2
+    ));
+}
@@ -1,6 +1,48 @@
-use crate::{error::BasuError, event::Event, EventBus, Handle};
+use crate::{
+    actor::{Actor, ActorRef, SupervisorEvent, SupervisorPolicy},
+    aggregate::{Aggregator, Completion},
+    backfill::BackfillOptions,
+    batch::BatchConfig,
+    circuit::{CircuitBreaker, CircuitEvent},
+    combinator::{CanaryWeight, HandlerExt, RetryPolicy, ShadowOutcome},
+    command::{CommandBus, HandleCommand},
+    context::{self, Context},
+    dispatch::{DispatchStrategy, Dispatcher, RayonConfig, RayonScheduling},
+    error::{BasuError, ErrorPolicy, PublishError},
+    event::Event,
+    executor::{Executor, Job},
+    extract::{self, extract_fn, Ctx, State},
+    flag::{FlagProvider, Flags},
+    handler_fn,
+    join::Join,
+    lazy::LazyEventBus,
+    lifecycle::LifecycleEvent,
+    outcome::{HandleOutcome, HandleSignal},
+    priority::DEFAULT_PRIORITY,
+    query::{CachePolicy, HandleQuery, QueryBus},
+    reload::{ConfigChange, ConfigWatcher},
+    report::HandlerFailure,
+    request::{Gathered, HandleRequest},
+    slow::SlowHandler,
+    tenancy::Tenancy,
+    validate::ValidationIssue,
+    wildcard::{is_pattern, WILDCARD},
+    window::Window,
+    EventBus, Handle, Handler, HandlerId,
+};
 
-#[derive(Debug)]
+use std::{
+    any::Any,
+    num::NonZeroU64,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    thread::{self, ThreadId},
+    time::Duration,
+};
+
+#[derive(Debug, Clone)]
 struct Data {
     message: String,
 }
@@ -61,3 +103,2446 @@ fn test() {
     let event_types = eventbus.list().unwrap();
     assert_eq!(event_types.len(), 0);
 }
+
+#[test]
+fn named() {
+    let eventbus = EventBus::<Data>::named("payments").with_label("region", "eu");
+    assert_eq!(eventbus.name(), Some("payments"));
+    assert_eq!(
+        eventbus.labels().get("region").map(String::as_str),
+        Some("eu")
+    );
+    assert!(format!("{:?}", eventbus).contains("payments"));
+
+    let eventbus = EventBus::<Data>::new();
+    assert_eq!(eventbus.name(), None);
+    assert!(eventbus.labels().is_empty());
+}
+
+#[test]
+fn global() {
+    struct GlobalData;
+    struct GlobalHandler;
+
+    impl Handle<GlobalData> for GlobalHandler {
+        fn handle(&self, _event: &Event<GlobalData>) -> Result<(), BasuError> {
+            Ok(())
+        }
+    }
+
+    crate::global::<GlobalData>()
+        .subscribe(ECHO, Box::new(GlobalHandler))
+        .unwrap();
+
+    let count = crate::global::<GlobalData>()
+        .get_handler_count(ECHO)
+        .unwrap();
+    assert_eq!(count, 1);
+    assert!(crate::global::<Data>().list().unwrap().is_empty());
+}
+
+struct Counter {
+    count: usize,
+    total: Arc<AtomicUsize>,
+}
+
+impl Actor<Data> for Counter {
+    fn handle(&mut self, _event: Event<Data>) -> Result<(), BasuError> {
+        self.count += 1;
+        self.total.store(self.count, Ordering::SeqCst);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn actor() {
+    let eventbus = EventBus::new();
+    let total = Arc::new(AtomicUsize::new(0));
+    let event_types = [ECHO, "ping"];
+    let (actor_ref, handler_ids) = eventbus
+        .spawn_actor(
+            &event_types,
+            Counter {
+                count: 0,
+                total: total.clone(),
+            },
+        )
+        .unwrap();
+
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    eventbus.publish(ECHO, &event).unwrap();
+    eventbus.publish("ping", &event).unwrap();
+    eventbus.publish(ECHO, &event).unwrap();
+
+    for (event_type, handler_id) in event_types.iter().zip(&handler_ids) {
+        eventbus.unsubscribe(event_type, handler_id).unwrap();
+    }
+    actor_ref.join().unwrap();
+    assert_eq!(total.load(Ordering::SeqCst), 3);
+}
+
+struct Flaky {
+    count: Arc<AtomicUsize>,
+}
+
+impl Actor<Data> for Flaky {
+    fn handle(&mut self, event: Event<Data>) -> Result<(), BasuError> {
+        if event.get_data().message == "panic" {
+            panic!("flaky actor");
+        }
+        self.count.fetch_add(1, Ordering::SeqCst);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn supervised_actor() {
+    let eventbus = EventBus::new();
+    let count = Arc::new(AtomicUsize::new(0));
+    let events = Arc::new(Mutex::new(Vec::new()));
+
+    let policy = SupervisorPolicy::new(1)
+        .with_backoff(Duration::from_millis(1), Duration::from_millis(10))
+        .on_event({
+            let events = events.clone();
+            move |event| events.lock().unwrap().push(event)
+        });
+    let actor_ref = ActorRef::spawn_supervised(
+        {
+            let count = count.clone();
+            move || Flaky {
+                count: count.clone(),
+            }
+        },
+        policy,
+    );
+    eventbus.subscribe(ECHO, actor_ref.mailbox()).unwrap();
+
+    let ok = Event::new(Data {
+        message: "ok".to_owned(),
+    });
+    let panic = Event::new(Data {
+        message: "panic".to_owned(),
+    });
+    eventbus.publish(ECHO, &ok).unwrap();
+    eventbus.publish(ECHO, &panic).unwrap();
+    eventbus.publish(ECHO, &ok).unwrap();
+    eventbus.publish(ECHO, &panic).unwrap();
+
+    eventbus.clear().unwrap();
+    let result = actor_ref.join();
+    assert!(matches!(
+        result,
+        Err(BasuError::ActorEscalated { restarts: 2 })
+    ));
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![
+            SupervisorEvent::Restarted { restarts: 1 },
+            SupervisorEvent::Escalated { restarts: 2 }
+        ]
+    );
+}
+
+struct Double;
+
+impl HandleCommand<u64, u64> for Double {
+    fn handle(&self, command: u64) -> Result<u64, BasuError> {
+        Ok(command * 2)
+    }
+}
+
+#[test]
+fn command() {
+    let commandbus = CommandBus::new();
+    commandbus.register("double", Box::new(Double)).unwrap();
+
+    let result = commandbus.register("double", Box::new(Double));
+    assert!(matches!(result, Err(BasuError::HandlerAlreadyRegistered)));
+    assert_eq!(commandbus.list().unwrap(), vec!["double".to_owned()]);
+
+    assert_eq!(commandbus.dispatch("double", 21).unwrap(), 42);
+
+    commandbus.unregister("double").unwrap();
+    let result = commandbus.dispatch("double", 21);
+    assert!(matches!(result, Err(BasuError::CommandTypeNotFound)));
+}
+
+struct Square {
+    calls: Arc<AtomicUsize>,
+}
+
+impl HandleQuery<u64, u64> for Square {
+    fn handle(&self, query: u64) -> Result<u64, BasuError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        Ok(query * query)
+    }
+}
+
+#[test]
+fn query() {
+    let querybus = QueryBus::new();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let policy = CachePolicy::new(Duration::from_secs(60), |query: &u64| query.to_string());
+    querybus
+        .register_cached(
+            "square",
+            Box::new(Square {
+                calls: calls.clone(),
+            }),
+            policy,
+        )
+        .unwrap();
+
+    assert_eq!(querybus.query("square", 3).unwrap(), 9);
+    assert_eq!(querybus.query("square", 3).unwrap(), 9);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    assert_eq!(querybus.query("square", 4).unwrap(), 16);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    querybus.invalidate("square").unwrap();
+    assert_eq!(querybus.query("square", 3).unwrap(), 9);
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+    let result = querybus.query("cube", 3);
+    assert!(matches!(result, Err(BasuError::QueryTypeNotFound)));
+}
+
+struct Counting(Arc<AtomicUsize>);
+
+impl Handle<Data> for Counting {
+    fn handle(&self, _event: &Event<Data>) -> Result<(), BasuError> {
+        self.0.fetch_add(1, Ordering::SeqCst);
+
+        Ok(())
+    }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(self)
+    }
+}
+
+#[test]
+fn publish_prepared() {
+    let eventbus = EventBus::new();
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let result = eventbus.publish_prepared(ECHO, event.clone());
+    assert!(matches!(result, Err(PublishError::EventTypeNotFound)));
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let handler_id = eventbus
+        .subscribe(ECHO, Box::new(Counting(count.clone())))
+        .unwrap();
+
+    let prepared = eventbus.publish_prepared(ECHO, event.clone()).unwrap();
+    assert_eq!(prepared.event_type(), ECHO);
+    prepared.abort();
+    assert_eq!(count.load(Ordering::SeqCst), 0);
+
+    let prepared = eventbus.publish_prepared(ECHO, event.clone()).unwrap();
+    prepared.commit().unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+
+    eventbus.unsubscribe(ECHO, &handler_id).unwrap();
+    let result = eventbus.publish_prepared(ECHO, event);
+    assert!(matches!(result, Err(PublishError::NoSubscribers)));
+}
+
+#[test]
+fn batch() {
+    let eventbus = Arc::new(EventBus::new());
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe(ECHO, Box::new(Counting(count.clone())))
+        .unwrap();
+
+    let events: Vec<_> = (0..3)
+        .map(|i| {
+            Event::new(Data {
+                message: i.to_string(),
+            })
+        })
+        .collect();
+    eventbus.publish_batch(ECHO, &events).unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 3);
+
+    let batcher = eventbus.batcher(
+        ECHO,
+        BatchConfig {
+            max_size: 2,
+            max_delay: Duration::from_millis(5),
+        },
+    );
+    for event in events.iter().chain(events.iter()) {
+        batcher.publish(event.clone()).unwrap();
+    }
+    batcher.close().unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 9);
+}
+
+struct ThreadRecorder(Arc<Mutex<Vec<ThreadId>>>);
+
+impl Handle<Data> for ThreadRecorder {
+    fn handle(&self, _event: &Event<Data>) -> Result<(), BasuError> {
+        self.0.lock().unwrap().push(thread::current().id());
+
+        Ok(())
+    }
+}
+
+#[test]
+fn dispatch_strategy() {
+    let eventbus = EventBus::new()
+        .with_dispatch_strategy(DispatchStrategy::Pool(2))
+        .with_topic_dispatch_strategy("inline", DispatchStrategy::Inline)
+        .with_topic_dispatch_strategy("threaded", DispatchStrategy::ThreadPerPublish);
+    let threads = Arc::new(Mutex::new(Vec::new()));
+    for event_type in [ECHO, "inline", "threaded"] {
+        eventbus
+            .subscribe(event_type, Box::new(ThreadRecorder(threads.clone())))
+            .unwrap();
+    }
+
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let caller = thread::current().id();
+
+    eventbus.publish("inline", &event).unwrap();
+    assert_eq!(*threads.lock().unwrap(), vec![caller]);
+
+    eventbus.publish("threaded", &event).unwrap();
+    eventbus.publish(ECHO, &event).unwrap();
+    let threads = threads.lock().unwrap();
+    assert_eq!(threads.len(), 3);
+    assert!(threads[1..].iter().all(|thread_id| *thread_id != caller));
+}
+
+#[test]
+fn subscribe_on() {
+    let eventbus = EventBus::new();
+    let main_loop: Arc<Mutex<Vec<Job>>> = Arc::new(Mutex::new(Vec::new()));
+    let executor = Executor::new({
+        let main_loop = main_loop.clone();
+        move |job| main_loop.lock().unwrap().push(job)
+    });
+    let threads = Arc::new(Mutex::new(Vec::new()));
+    eventbus
+        .subscribe_on(ECHO, &executor, Box::new(ThreadRecorder(threads.clone())))
+        .unwrap();
+
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    eventbus.publish(ECHO, &event).unwrap();
+    assert!(threads.lock().unwrap().is_empty());
+
+    let jobs: Vec<_> = main_loop.lock().unwrap().drain(..).collect();
+    for job in jobs {
+        job();
+    }
+    assert_eq!(*threads.lock().unwrap(), vec![thread::current().id()]);
+}
+
+#[test]
+fn frame_events() {
+    let eventbus = EventBus::new();
+    eventbus.buffer_frames(ECHO).unwrap();
+    assert!(eventbus.take_frame_events(ECHO).is_empty());
+
+    for message in ["a", "b"] {
+        let event = Event::new(Data {
+            message: message.to_owned(),
+        });
+        eventbus.publish(ECHO, &event).unwrap();
+    }
+
+    let messages: Vec<_> = eventbus
+        .take_frame_events(ECHO)
+        .into_iter()
+        .map(|event| event.data.message)
+        .collect();
+    assert_eq!(messages, vec!["a".to_owned(), "b".to_owned()]);
+    assert!(eventbus.take_frame_events(ECHO).is_empty());
+}
+
+#[test]
+fn lifecycle() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let eventbus = EventBus::new().with_lifecycle_hook({
+        let events = events.clone();
+        move |event: &LifecycleEvent| events.lock().unwrap().push(event.clone())
+    });
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let handler_a_id = eventbus
+        .subscribe(ECHO, Box::new(Counting(count.clone())))
+        .unwrap();
+    let handler_b_id = eventbus
+        .subscribe(ECHO, Box::new(Counting(count.clone())))
+        .unwrap();
+    eventbus.unsubscribe(ECHO, &handler_a_id).unwrap();
+    eventbus.unsubscribe(ECHO, &handler_a_id).unwrap();
+    eventbus.clear().unwrap();
+
+    let attached = |handler_id: &HandlerId| LifecycleEvent::Attached {
+        event_type: ECHO.to_owned(),
+        handler_id: handler_id.clone(),
+    };
+    let detached = |handler_id: &HandlerId| LifecycleEvent::Detached {
+        event_type: ECHO.to_owned(),
+        handler_id: handler_id.clone(),
+    };
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![
+            attached(&handler_a_id),
+            attached(&handler_b_id),
+            detached(&handler_a_id),
+            detached(&handler_b_id),
+        ]
+    );
+}
+
+struct Slow {
+    started: Arc<AtomicBool>,
+    count: Arc<AtomicUsize>,
+}
+
+impl Handle<Data> for Slow {
+    fn handle(&self, _event: &Event<Data>) -> Result<(), BasuError> {
+        self.started.store(true, Ordering::SeqCst);
+        thread::sleep(Duration::from_millis(20));
+        self.count.fetch_add(1, Ordering::SeqCst);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn unsubscribe_waits_for_in_flight_handler() {
+    let eventbus = Arc::new(EventBus::new());
+    let started = Arc::new(AtomicBool::new(false));
+    let count = Arc::new(AtomicUsize::new(0));
+    let handler_id = eventbus
+        .subscribe(
+            ECHO,
+            Box::new(Slow {
+                started: started.clone(),
+                count: count.clone(),
+            }),
+        )
+        .unwrap();
+
+    let publish = thread::spawn({
+        let eventbus = eventbus.clone();
+        move || {
+            let event = Event::new(Data {
+                message: "{data from event}".to_owned(),
+            });
+            eventbus.publish(ECHO, &event)
+        }
+    });
+    while !started.load(Ordering::SeqCst) {
+        thread::yield_now();
+    }
+
+    eventbus.unsubscribe(ECHO, &handler_id).unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    publish.join().unwrap().unwrap();
+}
+
+struct ThreadNameRecorder(Arc<Mutex<Vec<Option<String>>>>);
+
+impl Handle<Data> for ThreadNameRecorder {
+    fn handle(&self, _event: &Event<Data>) -> Result<(), BasuError> {
+        let name = thread::current().name().map(ToOwned::to_owned);
+        self.0.lock().unwrap().push(name);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn dedicated_rayon_pool() {
+    for scheduling in [
+        RayonScheduling::Join,
+        RayonScheduling::Spawn,
+        RayonScheduling::SpawnFifo,
+    ] {
+        let eventbus =
+            EventBus::new().with_dispatch_strategy(DispatchStrategy::Dedicated(RayonConfig {
+                num_threads: 2,
+                thread_name: Some("basu-dispatch".to_owned()),
+                scheduling,
+            }));
+        let names = Arc::new(Mutex::new(Vec::new()));
+        for _ in 0..4 {
+            eventbus
+                .subscribe(ECHO, Box::new(ThreadNameRecorder(names.clone())))
+                .unwrap();
+        }
+
+        let event = Event::new(Data {
+            message: "{data from event}".to_owned(),
+        });
+        eventbus.publish(ECHO, &event).unwrap();
+
+        let names = names.lock().unwrap();
+        assert_eq!(names.len(), 4);
+        assert!(names
+            .iter()
+            .all(|name| name.as_deref().unwrap().starts_with("basu-dispatch-")));
+    }
+}
+
+struct Failing {
+    failures: usize,
+    calls: Arc<AtomicUsize>,
+}
+
+impl Handle<Data> for Failing {
+    fn handle(&self, _event: &Event<Data>) -> Result<(), BasuError> {
+        if self.calls.fetch_add(1, Ordering::SeqCst) < self.failures {
+            return Err(anyhow::anyhow!("failing handler").into());
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn combinators() {
+    let eventbus = EventBus::new();
+    let event = Event::new(Data {
+        message: "keep".to_owned(),
+    });
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let handler: Handler<Data> = Box::new(Failing {
+        failures: 2,
+        calls: calls.clone(),
+    });
+    let handler = handler.with_retry(RetryPolicy::new(3, Duration::from_millis(1)));
+    eventbus.subscribe("retry", handler).unwrap();
+    eventbus.publish("retry", &event).unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let handler: Handler<Data> = Box::new(Counting(count.clone()));
+    let handler = handler
+        .filtered(|event| event.data.message == "keep")
+        .tee(Box::new(Counting(count.clone())));
+    eventbus.subscribe("filtered", handler).unwrap();
+    eventbus.publish("filtered", &event).unwrap();
+    let skipped = Event::new(Data {
+        message: "skip".to_owned(),
+    });
+    eventbus.publish("filtered", &skipped).unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 3);
+
+    let handler: Handler<Data> = Box::new(Failing {
+        failures: usize::MAX,
+        calls: Arc::new(AtomicUsize::new(0)),
+    });
+    let handler = handler.fallback(Box::new(Counting(count.clone())));
+    eventbus.subscribe("fallback", handler).unwrap();
+    eventbus.publish("fallback", &event).unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 4);
+
+    let handler: Handler<Data> = Box::new(Slow {
+        started: Arc::new(AtomicBool::new(false)),
+        count: count.clone(),
+    });
+    let handler = handler.with_timeout(Duration::from_millis(1));
+    eventbus.subscribe("timeout", handler).unwrap();
+    let result = eventbus.publish("timeout", &event);
+    assert!(matches!(
+        result,
+        Err(PublishError::Timeout { succeeded: 0, timed_out }) if matches!(timed_out[..], [HandlerFailure { error: BasuError::HandlerTimeout, .. }])
+    ));
+}
+
+struct Summaries(Arc<Mutex<Vec<usize>>>);
+
+impl Handle<usize> for Summaries {
+    fn handle(&self, event: &Event<usize>) -> Result<(), BasuError> {
+        self.0.lock().unwrap().push(event.data);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn aggregate_groups_by_key() {
+    let eventbus = EventBus::<Data>::new();
+    let output = Arc::new(EventBus::<usize>::new());
+    let summaries = Arc::new(Mutex::new(Vec::new()));
+    output
+        .subscribe("summary", Box::new(Summaries(summaries.clone())))
+        .unwrap();
+
+    let aggregator = Aggregator::new(
+        |event: &Event<Data>| event.data.message.clone(),
+        || 0,
+        |count: &mut usize, _: &str, _: &Event<Data>| *count += 1,
+    )
+    .with_timeout(Duration::from_millis(20));
+    eventbus
+        .aggregate(&["payment", "shipping"], aggregator, &output, "summary")
+        .unwrap();
+
+    let order = |id: &str| {
+        Event::new(Data {
+            message: id.to_owned(),
+        })
+    };
+    eventbus.publish("payment", &order("a")).unwrap();
+    eventbus.publish("payment", &order("b")).unwrap();
+    eventbus.publish("payment", &order("a")).unwrap();
+    eventbus.publish("shipping", &order("a")).unwrap();
+
+    while summaries.lock().unwrap().len() < 2 {
+        thread::sleep(Duration::from_millis(5));
+    }
+    assert_eq!(*summaries.lock().unwrap(), vec![3, 1]);
+
+    let aggregator = Aggregator::new(
+        |event: &Event<Data>| event.data.message.clone(),
+        || 0,
+        |count: &mut usize, _: &str, _: &Event<Data>| *count += 1,
+    )
+    .complete_when(Completion::Count(2));
+    eventbus
+        .aggregate(&["refund"], aggregator, &output, "summary")
+        .unwrap();
+    eventbus.publish("refund", &order("c")).unwrap();
+    eventbus.publish("refund", &order("c")).unwrap();
+
+    while summaries.lock().unwrap().len() < 3 {
+        thread::sleep(Duration::from_millis(5));
+    }
+    assert_eq!(summaries.lock().unwrap()[2], 2);
+}
+
+struct Messages(Arc<Mutex<Vec<String>>>);
+
+impl Handle<Data> for Messages {
+    fn handle(&self, event: &Event<Data>) -> Result<(), BasuError> {
+        self.0.lock().unwrap().push(event.data.message.clone());
+
+        Ok(())
+    }
+}
+
+#[test]
+fn tumbling_window_reduces_events() {
+    let eventbus = Arc::new(EventBus::<Data>::new());
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    eventbus
+        .subscribe("joined", Box::new(Messages(messages.clone())))
+        .unwrap();
+
+    let handler_id = eventbus
+        .window(
+            "sample",
+            Window::Tumbling(Duration::from_millis(30)),
+            |events| Data {
+                message: events
+                    .iter()
+                    .map(|event| event.data.message.as_str())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            },
+            "joined",
+        )
+        .unwrap();
+
+    for message in ["a", "b", "c"] {
+        let event = Event::new(Data {
+            message: message.to_owned(),
+        });
+        eventbus.publish("sample", &event).unwrap();
+    }
+
+    while messages.lock().unwrap().is_empty() {
+        thread::sleep(Duration::from_millis(5));
+    }
+    assert_eq!(*messages.lock().unwrap(), vec!["a,b,c".to_owned()]);
+
+    eventbus.unsubscribe("sample", &handler_id).unwrap();
+}
+
+#[test]
+fn join_correlates_topics_by_key() {
+    let eventbus = Arc::new(EventBus::<Data>::new());
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    eventbus
+        .subscribe("paid", Box::new(Messages(messages.clone())))
+        .unwrap();
+
+    let join = Join::new(
+        |event: &Event<Data>| event.data.message.split(':').next().unwrap().to_owned(),
+        Duration::from_secs(5),
+        |order, payment| Data {
+            message: format!("{}+{}", order.data.message, payment.data.message),
+        },
+    );
+    eventbus.join("order", "payment", join, "paid").unwrap();
+
+    let event = |message: &str| {
+        Event::new(Data {
+            message: message.to_owned(),
+        })
+    };
+    eventbus.publish("order", &event("1:order")).unwrap();
+    eventbus.publish("order", &event("2:order")).unwrap();
+    eventbus.publish("payment", &event("2:payment")).unwrap();
+
+    while messages.lock().unwrap().is_empty() {
+        thread::sleep(Duration::from_millis(5));
+    }
+    assert_eq!(
+        *messages.lock().unwrap(),
+        vec!["2:order+2:payment".to_owned()]
+    );
+}
+
+#[test]
+fn shadow_records_outcomes() {
+    let eventbus = EventBus::<Data>::new();
+    let count = Arc::new(AtomicUsize::new(0));
+    let outcomes: Arc<Mutex<Vec<ShadowOutcome>>> = Arc::new(Mutex::new(Vec::new()));
+
+    let handler: Handler<Data> = Box::new(Counting(count.clone()));
+    let recorded = outcomes.clone();
+    let handler = handler.shadow(
+        Box::new(Failing {
+            failures: usize::MAX,
+            calls: Arc::new(AtomicUsize::new(0)),
+        }),
+        move |outcome| recorded.lock().unwrap().push(outcome.clone()),
+    );
+    eventbus.subscribe(ECHO, handler).unwrap();
+
+    let event = Event::new(Data {
+        message: "shadowed".to_owned(),
+    });
+    eventbus.publish(ECHO, &event).unwrap();
+
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    let outcomes = outcomes.lock().unwrap();
+    assert_eq!(outcomes.len(), 1);
+    assert!(outcomes[0].primary.is_ok());
+    assert!(outcomes[0].diverged());
+}
+
+#[test]
+fn canary_routes_share_of_events() {
+    let eventbus = EventBus::<Data>::new();
+    let stable = Arc::new(AtomicUsize::new(0));
+    let canary = Arc::new(AtomicUsize::new(0));
+    let weight = CanaryWeight::new(30);
+
+    let handler: Handler<Data> = Box::new(Counting(stable.clone()));
+    let handler = handler.canary(Box::new(Counting(canary.clone())), &weight);
+    eventbus.subscribe(ECHO, handler).unwrap();
+
+    let event = Event::new(Data {
+        message: "canary".to_owned(),
+    });
+    for _ in 0..100 {
+        eventbus.publish(ECHO, &event).unwrap();
+    }
+    assert_eq!(canary.load(Ordering::SeqCst), 30);
+    assert_eq!(stable.load(Ordering::SeqCst), 70);
+
+    weight.set(100);
+    eventbus.publish(ECHO, &event).unwrap();
+    assert_eq!(canary.load(Ordering::SeqCst), 31);
+
+    weight.set(50);
+    let sticky_stable = Arc::new(AtomicUsize::new(0));
+    let sticky_canary = Arc::new(AtomicUsize::new(0));
+    let handler: Handler<Data> = Box::new(Counting(sticky_stable.clone()));
+    let handler = handler.canary_by_key(
+        Box::new(Counting(sticky_canary.clone())),
+        &weight,
+        |event| event.data.message.clone(),
+    );
+    eventbus.subscribe("sticky", handler).unwrap();
+    for _ in 0..10 {
+        eventbus.publish("sticky", &event).unwrap();
+    }
+    let routed = (
+        sticky_stable.load(Ordering::SeqCst),
+        sticky_canary.load(Ordering::SeqCst),
+    );
+    assert!(routed == (10, 0) || routed == (0, 10));
+}
+
+struct StaticFlags(Arc<AtomicBool>, Arc<AtomicUsize>);
+
+impl FlagProvider for StaticFlags {
+    fn is_enabled(&self, _: &str, handler_name: &str) -> bool {
+        self.1.fetch_add(1, Ordering::SeqCst);
+        handler_name == "v2" && self.0.load(Ordering::SeqCst)
+    }
+}
+
+#[test]
+fn flagged_handler_follows_provider() {
+    let eventbus = EventBus::<Data>::new();
+    let enabled = Arc::new(AtomicBool::new(false));
+    let lookups = Arc::new(AtomicUsize::new(0));
+    let count = Arc::new(AtomicUsize::new(0));
+    let flags = Flags::new(StaticFlags(enabled.clone(), lookups.clone()))
+        .with_ttl(Duration::from_millis(20));
+
+    eventbus
+        .subscribe_flagged(ECHO, "v2", &flags, Box::new(Counting(count.clone())))
+        .unwrap();
+
+    let event = Event::new(Data {
+        message: "flagged".to_owned(),
+    });
+    eventbus.publish(ECHO, &event).unwrap();
+    enabled.store(true, Ordering::SeqCst);
+    eventbus.publish(ECHO, &event).unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 0);
+    assert_eq!(lookups.load(Ordering::SeqCst), 1);
+
+    thread::sleep(Duration::from_millis(30));
+    eventbus.publish(ECHO, &event).unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn reconfigure_applies_to_next_publish() {
+    let eventbus = EventBus::new().with_dispatch_strategy(DispatchStrategy::Pool(2));
+    let threads = Arc::new(Mutex::new(Vec::new()));
+    eventbus
+        .subscribe(ECHO, Box::new(ThreadRecorder(threads.clone())))
+        .unwrap();
+
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let caller = thread::current().id();
+    eventbus.publish(ECHO, &event).unwrap();
+    assert_ne!(threads.lock().unwrap()[0], caller);
+
+    eventbus
+        .reconfigure(|config| {
+            config.set_topic_dispatch_strategy(ECHO, Some(DispatchStrategy::Inline))
+        })
+        .unwrap();
+    assert_eq!(
+        eventbus.config().topic_dispatch_strategy(ECHO),
+        Some(&DispatchStrategy::Inline)
+    );
+    eventbus.publish(ECHO, &event).unwrap();
+    assert_eq!(threads.lock().unwrap()[1], caller);
+}
+
+#[test]
+fn config_file_reload() {
+    let path = std::env::temp_dir().join(format!("basu-{}.conf", uuid::Uuid::new_v4()));
+    std::fs::write(&path, "# tuned\ndispatch_strategy = inline\n").unwrap();
+
+    let eventbus = Arc::new(EventBus::<Data>::new());
+    let changes: Arc<Mutex<Vec<ConfigChange>>> = Arc::new(Mutex::new(Vec::new()));
+    let errors = Arc::new(AtomicUsize::new(0));
+    let (reloaded, failed) = (changes.clone(), errors.clone());
+    let watch = eventbus.watch_config(
+        ConfigWatcher::new(&path)
+            .with_interval(Duration::from_millis(5))
+            .on_reload(move |changes| reloaded.lock().unwrap().extend_from_slice(changes))
+            .on_error(move |_| {
+                failed.fetch_add(1, Ordering::SeqCst);
+            }),
+    );
+
+    while eventbus.config().dispatch_strategy() != &DispatchStrategy::Inline {
+        thread::sleep(Duration::from_millis(5));
+    }
+    assert_eq!(
+        *changes.lock().unwrap(),
+        vec![ConfigChange {
+            key: "dispatch_strategy".to_owned(),
+            old: Some("rayon".to_owned()),
+            new: Some("inline".to_owned()),
+        }]
+    );
+
+    std::fs::remove_file(&path).unwrap();
+    while errors.load(Ordering::SeqCst) == 0 {
+        thread::sleep(Duration::from_millis(5));
+    }
+    drop(watch);
+}
+
+#[test]
+fn tenant_rate_limit_and_scope() {
+    let tenancy = Tenancy::new(|event: &Event<Data>| {
+        event
+            .data
+            .message
+            .split_once(':')
+            .map(|(tenant, _)| tenant.to_owned())
+    })
+    .with_rate_limit(2);
+    let eventbus = EventBus::<Data>::new().with_tenancy(tenancy);
+
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe_tenant(ECHO, "acme", Box::new(Counting(count.clone())))
+        .unwrap();
+
+    let event = |message: &str| {
+        Event::new(Data {
+            message: message.to_owned(),
+        })
+    };
+    eventbus.publish(ECHO, &event("acme:1")).unwrap();
+    eventbus.publish(ECHO, &event("acme:2")).unwrap();
+    let result = eventbus.publish(ECHO, &event("acme:3"));
+    assert!(matches!(result, Err(PublishError::RateLimited { tenant }) if tenant == "acme"));
+
+    eventbus.publish(ECHO, &event("globex:1")).unwrap();
+    eventbus.publish(ECHO, &event("untenanted")).unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn fork_hooks_rebuild_dispatch_threads() {
+    let eventbus = EventBus::new()
+        .with_topic_dispatch_strategy("pooled", DispatchStrategy::Pool(2))
+        .with_topic_dispatch_strategy("inline", DispatchStrategy::Inline);
+    let threads = Arc::new(Mutex::new(Vec::new()));
+    for event_type in [ECHO, "pooled", "inline"] {
+        eventbus
+            .subscribe(event_type, Box::new(ThreadRecorder(threads.clone())))
+            .unwrap();
+    }
+
+    eventbus.prepare_fork().unwrap().after_fork_parent();
+    eventbus.prepare_fork().unwrap().after_fork_child().unwrap();
+    assert_eq!(
+        eventbus.config().dispatch_strategy(),
+        &DispatchStrategy::Rayon
+    );
+
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let caller = thread::current().id();
+    for event_type in [ECHO, "pooled", "inline"] {
+        eventbus.publish(event_type, &event).unwrap();
+    }
+    let threads = threads.lock().unwrap();
+    assert_ne!(threads[0], caller);
+    assert_ne!(threads[1], caller);
+    assert_eq!(threads[2], caller);
+}
+
+#[test]
+fn sandboxed_handler_is_disabled_on_overrun() {
+    let eventbus = EventBus::<Data>::new();
+    let count = Arc::new(AtomicUsize::new(0));
+
+    let handler: Handler<Data> = Box::new(Counting(count.clone()));
+    eventbus
+        .subscribe("fast", handler.sandboxed(Duration::from_secs(5)))
+        .unwrap();
+    let handler: Handler<Data> = Box::new(Slow {
+        started: Arc::new(AtomicBool::new(false)),
+        count: count.clone(),
+    });
+    eventbus
+        .subscribe("slow", handler.sandboxed(Duration::from_millis(1)))
+        .unwrap();
+
+    let event = Event::new(Data {
+        message: "sandboxed".to_owned(),
+    });
+    eventbus.publish("fast", &event).unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+
+    for _ in 0..2 {
+        let result = eventbus.publish("slow", &event);
+        assert!(matches!(
+            result,
+            Err(PublishError::PartialFailure { failed, .. }) if matches!(failed[..], [HandlerFailure { error: BasuError::HandlerResourceExceeded, .. }])
+        ));
+    }
+}
+
+#[test]
+fn publish_error_reports_partial_failure() {
+    let eventbus = EventBus::<Data>::new();
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let result = eventbus.publish(ECHO, &event);
+    assert!(matches!(result, Err(PublishError::EventTypeNotFound)));
+
+    let count = Arc::new(AtomicUsize::new(0));
+    for _ in 0..2 {
+        eventbus
+            .subscribe(ECHO, Box::new(Counting(count.clone())))
+            .unwrap();
+    }
+    eventbus
+        .subscribe(
+            ECHO,
+            Box::new(Failing {
+                failures: usize::MAX,
+                calls: Arc::new(AtomicUsize::new(0)),
+            }),
+        )
+        .unwrap();
+
+    let result = eventbus.publish(ECHO, &event);
+    assert!(matches!(
+        result,
+        Err(PublishError::PartialFailure { succeeded: 2, failed }) if failed.len() == 1
+    ));
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn publish_report() {
+    let eventbus = EventBus::<Data>::new();
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let result = eventbus.publish_report(ECHO, &event);
+    assert!(matches!(result, Err(PublishError::EventTypeNotFound)));
+
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe(ECHO, Box::new(Counting(count.clone())))
+        .unwrap();
+    let failing_id = eventbus
+        .subscribe(
+            ECHO,
+            Box::new(Failing {
+                failures: usize::MAX,
+                calls: Arc::new(AtomicUsize::new(0)),
+            }),
+        )
+        .unwrap();
+
+    let report = eventbus.publish_report(ECHO, &event).unwrap();
+    assert_eq!(report.delivered, 1);
+    assert!(!report.is_success());
+    assert!(matches!(
+        &report.failed[..],
+        [HandlerFailure { handler_id, error: BasuError::HandlerError(_), .. }] if *handler_id == failing_id
+    ));
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn subscribe_limited() {
+    let eventbus = Arc::new(EventBus::new());
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let (_handler_id, budget) = eventbus
+        .subscribe_limited(
+            ECHO,
+            NonZeroU64::new(2).unwrap(),
+            Box::new(Counting(count.clone())),
+        )
+        .unwrap();
+    assert_eq!(budget.remaining(), 2);
+
+    eventbus.publish(ECHO, &event).unwrap();
+    assert_eq!(budget.remaining(), 1);
+    for _ in 0..2 {
+        eventbus.publish(ECHO, &event).unwrap();
+    }
+    assert!(budget.is_exhausted());
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+
+    while eventbus.get_handler_count(ECHO).unwrap() > 0 {
+        thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn subscribe_until() {
+    let eventbus = Arc::new(EventBus::new());
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe_until(
+            ECHO,
+            Box::new(Counting(count.clone())),
+            Duration::from_millis(30),
+        )
+        .unwrap();
+    eventbus.publish(ECHO, &event).unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+
+    thread::sleep(Duration::from_millis(40));
+    eventbus.publish(ECHO, &event).unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    while eventbus.get_handler_count(ECHO).unwrap() > 0 {
+        thread::sleep(Duration::from_millis(5));
+    }
+}
+
+struct UntilStop(Arc<AtomicUsize>);
+
+impl HandleSignal<Data> for UntilStop {
+    fn handle(&self, event: &Event<Data>) -> Result<HandleOutcome, BasuError> {
+        self.0.fetch_add(1, Ordering::SeqCst);
+
+        Ok(match event.data.message.as_str() {
+            "stop" => HandleOutcome::Unsubscribe,
+            _ => HandleOutcome::Continue,
+        })
+    }
+}
+
+#[test]
+fn subscribe_signal() {
+    let eventbus = Arc::new(EventBus::new());
+    let message = |message: &str| {
+        Event::new(Data {
+            message: message.to_owned(),
+        })
+    };
+
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe_signal(ECHO, Box::new(UntilStop(count.clone())))
+        .unwrap();
+    eventbus.publish(ECHO, &message("keep")).unwrap();
+    eventbus.publish(ECHO, &message("stop")).unwrap();
+    eventbus.publish(ECHO, &message("keep")).unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+
+    while eventbus.get_handler_count(ECHO).unwrap() > 0 {
+        thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn unsubscribe_where() {
+    let eventbus = EventBus::new();
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let kept_id = eventbus
+        .subscribe_named(ECHO, "audit", Box::new(Counting(count.clone())))
+        .unwrap();
+    for topic in [ECHO, "other"] {
+        eventbus
+            .subscribe_named(topic, "tmp-preview", Box::new(Counting(count.clone())))
+            .unwrap();
+    }
+    eventbus
+        .subscribe(ECHO, Box::new(Counting(count.clone())))
+        .unwrap();
+    assert_eq!(eventbus.handler_name(&kept_id).as_deref(), Some("audit"));
+
+    let removed = eventbus
+        .unsubscribe_where(|_event_type, info| {
+            info.name.is_some_and(|name| name.starts_with("tmp-"))
+        })
+        .unwrap();
+    assert_eq!(removed, 2);
+    assert_eq!(eventbus.get_handler_count("other").unwrap(), 0);
+
+    eventbus.publish(ECHO, &event).unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+
+    eventbus.unsubscribe(ECHO, &kept_id).unwrap();
+    assert_eq!(eventbus.handler_name(&kept_id), None);
+}
+
+#[test]
+fn clear_event_type() {
+    let eventbus = EventBus::new();
+    let count = Arc::new(AtomicUsize::new(0));
+    for name in ["preview", "preview", "audit"] {
+        eventbus
+            .subscribe_named(ECHO, name, Box::new(Counting(count.clone())))
+            .unwrap();
+    }
+    eventbus
+        .subscribe("other", Box::new(Counting(count.clone())))
+        .unwrap();
+
+    let removed = eventbus
+        .clear_event_type_where(ECHO, |info| info.name == Some("preview"))
+        .unwrap();
+    assert_eq!(removed, 2);
+    assert_eq!(eventbus.get_handler_count(ECHO).unwrap(), 1);
+
+    assert_eq!(eventbus.clear_event_type(ECHO).unwrap(), 1);
+    assert!(matches!(
+        eventbus.get_handler_count(ECHO),
+        Err(BasuError::EventTypeNotFOUND)
+    ));
+    assert!(matches!(
+        eventbus.clear_event_type(ECHO),
+        Err(BasuError::EventTypeNotFOUND)
+    ));
+    assert_eq!(eventbus.get_handler_count("other").unwrap(), 1);
+}
+
+#[test]
+fn subscription_existence() {
+    let eventbus = EventBus::new();
+    assert!(!eventbus.has_subscribers(ECHO).unwrap());
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let handler_id = eventbus
+        .subscribe(ECHO, Box::new(Counting(count.clone())))
+        .unwrap();
+    assert!(eventbus.has_subscribers(ECHO).unwrap());
+    assert!(eventbus.contains_handler(ECHO, &handler_id).unwrap());
+    assert!(!eventbus.contains_handler("other", &handler_id).unwrap());
+
+    eventbus.unsubscribe(ECHO, &handler_id).unwrap();
+    assert!(!eventbus.has_subscribers(ECHO).unwrap());
+    assert!(!eventbus.contains_handler(ECHO, &handler_id).unwrap());
+}
+
+#[test]
+fn publish_lazy() {
+    let eventbus = EventBus::new();
+    let built = AtomicUsize::new(0);
+    let make_event = || {
+        built.fetch_add(1, Ordering::SeqCst);
+        Event::new(Data {
+            message: "{data from event}".to_owned(),
+        })
+    };
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let handler_id = eventbus
+        .subscribe(ECHO, Box::new(Counting(count.clone())))
+        .unwrap();
+    eventbus.publish_lazy(ECHO, make_event).unwrap();
+    assert_eq!(built.load(Ordering::SeqCst), 1);
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+
+    eventbus.unsubscribe(ECHO, &handler_id).unwrap();
+    eventbus.publish_lazy(ECHO, make_event).unwrap();
+    assert_eq!(built.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn slow_handler_hook() {
+    let slow_handlers = Arc::new(Mutex::new(Vec::<SlowHandler>::new()));
+    let hook_slow_handlers = slow_handlers.clone();
+    let eventbus = EventBus::new()
+        .with_slow_handler_threshold(Duration::from_millis(10))
+        .with_topic_slow_handler_threshold("batch", Duration::from_secs(1))
+        .with_slow_handler_hook(move |slow| hook_slow_handlers.lock().unwrap().push(slow.clone()));
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let slow = || Slow {
+        started: Arc::new(AtomicBool::new(false)),
+        count: count.clone(),
+    };
+    let slow_id = eventbus
+        .subscribe_named(ECHO, "slow", Box::new(slow()))
+        .unwrap();
+    eventbus
+        .subscribe(ECHO, Box::new(Counting(count.clone())))
+        .unwrap();
+    eventbus.subscribe("batch", Box::new(slow())).unwrap();
+
+    eventbus.publish(ECHO, &event).unwrap();
+    eventbus.publish("batch", &event).unwrap();
+    {
+        let slow_handlers = slow_handlers.lock().unwrap();
+        assert!(matches!(
+            &slow_handlers[..],
+            [SlowHandler { event_type, handler_id, handler_name: Some(name), elapsed, .. }]
+                if event_type == ECHO && *handler_id == slow_id && name == "slow"
+                    && *elapsed > Duration::from_millis(10)
+        ));
+    }
+
+    eventbus
+        .reconfigure(|config| config.set("slow_handler_threshold_ms", "").unwrap())
+        .unwrap();
+    eventbus.publish(ECHO, &event).unwrap();
+    assert_eq!(slow_handlers.lock().unwrap().len(), 1);
+    assert_eq!(
+        eventbus
+            .config()
+            .entries()
+            .get("slow_handler_threshold_ms.batch"),
+        Some(&"1000".to_owned())
+    );
+}
+
+struct Republish(Arc<EventBus<Data>>, Arc<Mutex<Vec<String>>>);
+
+impl Handle<Data> for Republish {
+    fn handle(&self, event: &Event<Data>) -> Result<(), BasuError> {
+        if let Err(err) = self.0.publish("other", event) {
+            self.1.lock().unwrap().push(err.to_string());
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn reentrant_publish() {
+    for strategy in [DispatchStrategy::Inline, DispatchStrategy::Pool(2)] {
+        let eventbus = Arc::new(
+            EventBus::new()
+                .with_dispatch_strategy(strategy)
+                .with_reentrancy_detection(),
+        );
+        let event = Event::new(Data {
+            message: "{data from event}".to_owned(),
+        });
+
+        let errors = Arc::new(Mutex::new(Vec::new()));
+        eventbus
+            .subscribe(ECHO, Box::new(Republish(eventbus.clone(), errors.clone())))
+            .unwrap();
+        eventbus.publish(ECHO, &event).unwrap();
+        assert_eq!(
+            *errors.lock().unwrap(),
+            vec![format!(
+                "publish to `other` from a handler of `{}` on the same bus",
+                ECHO
+            )]
+        );
+
+        let count = Arc::new(AtomicUsize::new(0));
+        eventbus
+            .subscribe("other", Box::new(Counting(count.clone())))
+            .unwrap();
+        eventbus.publish("other", &event).unwrap();
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+}
+
+#[test]
+fn warmup() {
+    let eventbus = EventBus::<Data>::new()
+        .with_topic_dispatch_strategy("order.filled", DispatchStrategy::Pool(2));
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+
+    eventbus.warmup(&["order.placed", "order.filled"]).unwrap();
+    let mut event_types = eventbus.list().unwrap();
+    event_types.sort();
+    assert_eq!(event_types, vec!["order.filled", "order.placed"]);
+    assert_eq!(eventbus.get_handler_count("order.placed").unwrap(), 0);
+    eventbus.publish("order.filled", &event).unwrap();
+}
+
+#[test]
+fn subscribe_all() {
+    let eventbus = EventBus::new();
+    let message = |message: &str| {
+        Event::new(Data {
+            message: message.to_owned(),
+        })
+    };
+
+    let audit = Arc::new(Mutex::new(Vec::new()));
+    let audit_id = eventbus
+        .subscribe_all(Box::new(Messages(audit.clone())))
+        .unwrap();
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe(ECHO, Box::new(Counting(count.clone())))
+        .unwrap();
+    assert!(eventbus.has_subscribers("unknown").unwrap());
+
+    eventbus.publish(ECHO, &message("echo")).unwrap();
+    eventbus.publish("unknown", &message("unknown")).unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    assert_eq!(*audit.lock().unwrap(), vec!["echo", "unknown"]);
+
+    eventbus.unsubscribe(WILDCARD, &audit_id).unwrap();
+    assert!(matches!(
+        eventbus.publish("unknown", &message("unknown")),
+        Err(PublishError::EventTypeNotFound)
+    ));
+}
+
+#[test]
+fn publish_inline() {
+    let eventbus = EventBus::new().with_dispatch_strategy(DispatchStrategy::Pool(2));
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+
+    let threads = Arc::new(Mutex::new(Vec::new()));
+    for _ in 0..3 {
+        eventbus
+            .subscribe(ECHO, Box::new(ThreadRecorder(threads.clone())))
+            .unwrap();
+    }
+
+    eventbus.publish_inline(ECHO, &event).unwrap();
+    assert_eq!(*threads.lock().unwrap(), vec![thread::current().id(); 3]);
+
+    threads.lock().unwrap().clear();
+    eventbus.publish(ECHO, &event).unwrap();
+    assert!(!threads.lock().unwrap().contains(&thread::current().id()));
+}
+
+#[test]
+fn hierarchical_patterns() {
+    let eventbus = EventBus::new();
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    assert!(is_pattern("orders.*") && is_pattern("orders.#"));
+    assert!(!is_pattern("orders.created") && !is_pattern(WILDCARD));
+
+    let counts: Vec<_> = ["orders.created", "orders.*", "orders.#", "*.created"]
+        .into_iter()
+        .map(|event_type| (event_type, Arc::new(AtomicUsize::new(0))))
+        .collect();
+    for (event_type, count) in &counts {
+        eventbus
+            .subscribe(event_type, Box::new(Counting(count.clone())))
+            .unwrap();
+    }
+    let received = || {
+        counts
+            .iter()
+            .map(|(_, count)| count.swap(0, Ordering::SeqCst))
+            .collect::<Vec<_>>()
+    };
+
+    eventbus.publish("orders.created", &event).unwrap();
+    assert_eq!(received(), vec![1, 1, 1, 1]);
+    eventbus.publish("orders.created.eu", &event).unwrap();
+    assert_eq!(received(), vec![0, 0, 1, 0]);
+    eventbus.publish("orders", &event).unwrap();
+    assert_eq!(received(), vec![0, 0, 1, 0]);
+    eventbus.publish("payments.created", &event).unwrap();
+    assert_eq!(received(), vec![0, 0, 0, 1]);
+
+    eventbus.clear_event_type("orders.#").unwrap();
+    assert!(matches!(
+        eventbus.publish("orders", &event),
+        Err(PublishError::EventTypeNotFound)
+    ));
+}
+
+struct Length(Option<usize>);
+
+impl HandleRequest<Data, usize> for Length {
+    fn handle(&self, event: &Event<Data>) -> Result<Option<usize>, BasuError> {
+        Ok(self.0.map(|extra| event.data.message.len() + extra))
+    }
+}
+
+#[test]
+fn publish_request() {
+    let eventbus = EventBus::new();
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe("request", Box::new(Counting(count.clone())))
+        .unwrap();
+    eventbus
+        .subscribe_responder("request", Box::new(Length(None)))
+        .unwrap();
+    assert!(matches!(
+        eventbus.publish_request::<usize>("request", &event),
+        Err(PublishError::NoReply)
+    ));
+
+    eventbus
+        .subscribe_responder("request", Box::new(Length(Some(0))))
+        .unwrap();
+    let reply: usize = eventbus.publish_request("request", &event).unwrap();
+    assert_eq!(reply, 17);
+
+    eventbus
+        .subscribe_responder("request", Box::new(Length(Some(1))))
+        .unwrap();
+    let mut replies: Vec<usize> = eventbus.publish_request_all("request", &event).unwrap();
+    replies.sort_unstable();
+    assert_eq!(replies, vec![17, 18]);
+    assert_eq!(count.load(Ordering::SeqCst), 3);
+
+    eventbus.publish("request", &event).unwrap();
+    assert!(eventbus
+        .publish_request_all::<usize>("missing", &event)
+        .is_err());
+}
+
+struct Quote(u64, Duration);
+
+impl HandleRequest<Data, u64> for Quote {
+    fn handle(&self, _event: &Event<Data>) -> Result<Option<u64>, BasuError> {
+        std::thread::sleep(self.1);
+        Ok(Some(self.0))
+    }
+}
+
+#[test]
+fn publish_gather() {
+    let eventbus = EventBus::new().with_dispatch_strategy(DispatchStrategy::ThreadPerPublish);
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    eventbus
+        .subscribe_responder("quote", Box::new(Quote(20, Duration::ZERO)))
+        .unwrap();
+    eventbus
+        .subscribe_responder("quote", Box::new(Quote(10, Duration::from_millis(5))))
+        .unwrap();
+    let slow = eventbus
+        .subscribe_responder("quote", Box::new(Quote(5, Duration::from_millis(300))))
+        .unwrap();
+
+    let mut gathered: Gathered<u64> = eventbus
+        .publish_gather("quote", &event, Duration::from_millis(100))
+        .unwrap();
+    gathered.replies.sort_unstable();
+    assert_eq!(gathered.replies, vec![10, 20]);
+    assert_eq!(gathered.timed_out, vec![slow]);
+    assert!(gathered.failed.is_empty() && !gathered.is_complete());
+
+    let gathered: Gathered<u64> = eventbus
+        .publish_gather("quote", &event, Duration::from_secs(5))
+        .unwrap();
+    assert_eq!(gathered.replies.len(), 3);
+    assert!(gathered.is_complete());
+}
+
+struct Ranked(i32, Arc<Mutex<Vec<i32>>>);
+
+impl Handle<Data> for Ranked {
+    fn handle(&self, _event: &Event<Data>) -> Result<(), BasuError> {
+        // higher priorities take longer, so only the grouping keeps them first
+        std::thread::sleep(Duration::from_millis(self.0.max(0) as u64));
+        self.1.lock().unwrap().push(self.0);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn priority_ordering() {
+    let eventbus = EventBus::new().with_dispatch_strategy(DispatchStrategy::ThreadPerPublish);
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let ranks = Arc::new(Mutex::new(Vec::new()));
+    for priority in [0, 20, -5, 10, 20] {
+        eventbus
+            .subscribe_with_priority(
+                "ranked",
+                priority,
+                Box::new(Ranked(priority, ranks.clone())),
+            )
+            .unwrap();
+    }
+    let handler_id = eventbus
+        .subscribe("ranked", Box::new(Ranked(DEFAULT_PRIORITY, ranks.clone())))
+        .unwrap();
+    assert_eq!(eventbus.handler_priority(&handler_id), DEFAULT_PRIORITY);
+
+    eventbus.publish("ranked", &event).unwrap();
+    assert_eq!(*ranks.lock().unwrap(), vec![20, 20, 10, 0, 0, -5]);
+}
+
+#[test]
+fn backfill() {
+    let eventbus = EventBus::new();
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe("import", Box::new(Counting(count.clone())))
+        .unwrap();
+    eventbus
+        .subscribe(
+            "broken",
+            Box::new(Failing {
+                failures: 1,
+                calls: Arc::new(AtomicUsize::new(0)),
+            }),
+        )
+        .unwrap();
+    let source = || {
+        ["import", "unknown", "broken", "import", "broken", "import"]
+            .into_iter()
+            .map(|event_type| {
+                let event = Event::new(Data {
+                    message: event_type.to_owned(),
+                });
+                (event_type.to_owned(), event)
+            })
+    };
+
+    let reported = Arc::new(Mutex::new(Vec::new()));
+    let options = BackfillOptions::new()
+        .with_rate_limit(200)
+        .with_progress(2, {
+            let reported = reported.clone();
+            move |progress| reported.lock().unwrap().push(progress.total())
+        });
+    let progress = eventbus.backfill(source(), options).unwrap();
+    assert_eq!(
+        (progress.published, progress.skipped, progress.failed),
+        (4, 1, 1)
+    );
+    assert!(progress.elapsed >= Duration::from_millis(25));
+    assert_eq!(*reported.lock().unwrap(), vec![2, 4, 6]);
+    assert_eq!(count.load(Ordering::SeqCst), 3);
+
+    eventbus
+        .subscribe(
+            "broken",
+            Box::new(Failing {
+                failures: 1,
+                calls: Arc::new(AtomicUsize::new(0)),
+            }),
+        )
+        .unwrap();
+    let stopped = eventbus.backfill(source(), BackfillOptions::new().with_stop_on_error());
+    assert!(matches!(stopped, Err(PublishError::PartialFailure { .. })));
+    assert_eq!(count.load(Ordering::SeqCst), 4);
+}
+
+#[test]
+fn subscription_guard() {
+    let eventbus = Arc::new(EventBus::new());
+    let count = Arc::new(AtomicUsize::new(0));
+    let kept = eventbus
+        .subscribe_guarded(ECHO, Box::new(Counting(count.clone())))
+        .unwrap()
+        .detach();
+    let subscription = eventbus
+        .subscribe_guarded(ECHO, Box::new(Counting(count.clone())))
+        .unwrap();
+    assert_eq!(subscription.event_type(), ECHO);
+    assert!(eventbus
+        .contains_handler(ECHO, subscription.handler_id())
+        .unwrap());
+
+    drop(subscription);
+    assert_eq!(eventbus.get_handler_count(ECHO).unwrap(), 1);
+    assert!(eventbus.contains_handler(ECHO, &kept).unwrap());
+
+    let subscription = eventbus
+        .subscribe_guarded(ECHO, Box::new(Counting(count.clone())))
+        .unwrap();
+    let handler_id = subscription.handler_id().clone();
+    subscription.unsubscribe().unwrap();
+    assert!(!eventbus.contains_handler(ECHO, &handler_id).unwrap());
+}
+
+#[test]
+fn closure_handlers() {
+    let eventbus = EventBus::new();
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    eventbus
+        .subscribe(ECHO, {
+            let messages = messages.clone();
+            Box::new(move |event: &Event<Data>| {
+                messages.lock().unwrap().push(event.data.message.clone());
+                Ok(())
+            })
+        })
+        .unwrap();
+    eventbus
+        .subscribe(
+            ECHO,
+            handler_fn(|event: &Event<Data>| match event.data.message.is_empty() {
+                true => Err(anyhow::anyhow!("empty message").into()),
+                false => Ok(()),
+            }),
+        )
+        .unwrap();
+
+    eventbus.publish(ECHO, &event).unwrap();
+    assert_eq!(*messages.lock().unwrap(), vec![event.data.message.clone()]);
+}
+
+#[test]
+fn inspect_handlers() {
+    let eventbus = EventBus::new();
+    let count = Arc::new(AtomicUsize::new(3));
+    let first = eventbus
+        .subscribe(ECHO, Box::new(Counting(count.clone())))
+        .unwrap();
+    let second = eventbus
+        .subscribe("other", Box::new(Counting(count.clone())))
+        .unwrap();
+    eventbus.subscribe(ECHO, Box::new(HandlerA)).unwrap();
+
+    let mut visited = Vec::new();
+    eventbus
+        .inspect_handlers(|event_type, handler_id, counting: &Counting| {
+            visited.push((event_type.to_owned(), handler_id.clone()));
+            counting.0.fetch_add(1, Ordering::SeqCst);
+        })
+        .unwrap();
+    visited.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(
+        visited,
+        vec![(ECHO.to_owned(), first), ("other".to_owned(), second)]
+    );
+    assert_eq!(count.load(Ordering::SeqCst), 5);
+}
+
+#[test]
+fn subscribe_channel() {
+    let eventbus = EventBus::<Data>::new();
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let (handler_id, receiver) = eventbus.subscribe_channel(ECHO).unwrap();
+    let consumer = std::thread::spawn(move || {
+        receiver
+            .into_iter()
+            .map(|event| event.data.message)
+            .collect::<Vec<_>>()
+    });
+
+    eventbus.publish(ECHO, &event).unwrap();
+    eventbus.publish(ECHO, &event).unwrap();
+    eventbus.unsubscribe(ECHO, &handler_id).unwrap();
+    assert_eq!(
+        consumer.join().unwrap(),
+        vec![event.data.message.clone(); 2]
+    );
+}
+
+#[test]
+fn flush_waits_for_publishes() {
+    let eventbus = Arc::new(EventBus::new());
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let started = Arc::new(AtomicBool::new(false));
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe(
+            "write",
+            Box::new(Slow {
+                started: started.clone(),
+                count: count.clone(),
+            }),
+        )
+        .unwrap();
+
+    let publishing = thread::spawn({
+        let eventbus = eventbus.clone();
+        move || eventbus.publish("write", &event)
+    });
+    while !started.load(Ordering::SeqCst) {
+        thread::yield_now();
+    }
+
+    eventbus.flush("write").unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    publishing.join().unwrap().unwrap();
+    eventbus.flush("unknown").unwrap();
+}
+
+#[test]
+fn retained_event_reaches_late_subscribers() {
+    let eventbus = EventBus::<Data>::new();
+    let event = |message: &str| {
+        Event::new(Data {
+            message: message.to_owned(),
+        })
+    };
+    eventbus.publish_retained("config", event("1")).unwrap();
+    eventbus.publish_retained("config", event("2")).unwrap();
+    assert!(eventbus.has_retained("config"));
+
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    eventbus
+        .subscribe("config", Box::new(Messages(messages.clone())))
+        .unwrap();
+    assert_eq!(*messages.lock().unwrap(), vec!["2"]);
+
+    eventbus.publish("config", &event("3")).unwrap();
+    assert_eq!(*messages.lock().unwrap(), vec!["2", "3"]);
+
+    eventbus.clear_retained("config");
+    let late = Arc::new(Mutex::new(Vec::new()));
+    eventbus
+        .subscribe("config", Box::new(Messages(late.clone())))
+        .unwrap();
+    assert!(late.lock().unwrap().is_empty());
+}
+
+#[test]
+fn buffered_publishes_on_commit_only() {
+    let eventbus = EventBus::<Data>::new();
+    let event = |message: &str| {
+        Event::new(Data {
+            message: message.to_owned(),
+        })
+    };
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    eventbus
+        .subscribe("order", Box::new(Messages(messages.clone())))
+        .unwrap();
+
+    let mut buffered = eventbus.buffered();
+    buffered.publish("order", event("rolled back"));
+    drop(buffered);
+    assert!(messages.lock().unwrap().is_empty());
+
+    let mut buffered = eventbus.buffered();
+    buffered.publish("order", event("1"));
+    buffered.publish("unknown", event("2"));
+    buffered.publish("order", event("3"));
+    assert_eq!(buffered.len(), 3);
+    assert!(messages.lock().unwrap().is_empty());
+    assert!(matches!(
+        buffered.commit(),
+        Err(PublishError::EventTypeNotFound)
+    ));
+    assert_eq!(*messages.lock().unwrap(), vec!["1", "3"]);
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Locale(&'static str);
+
+#[test]
+fn context_follows_causation_chain() {
+    let orders = EventBus::<Data>::new().with_dispatch_strategy(DispatchStrategy::ThreadPerPublish);
+    let mail = Arc::new(EventBus::<Data>::new());
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    mail.subscribe(
+        "mail",
+        handler_fn({
+            let seen = seen.clone();
+            move |_event: &Event<Data>| {
+                seen.lock()
+                    .unwrap()
+                    .push((context::get::<Locale>(), context::get::<u32>()));
+                Ok(())
+            }
+        }),
+    )
+    .unwrap();
+    orders
+        .subscribe(
+            "order",
+            handler_fn({
+                let mail = mail.clone();
+                move |event: &Event<Data>| {
+                    context::set(Locale("fr"))?;
+                    mail.publish("mail", event)?;
+                    Ok(())
+                }
+            }),
+        )
+        .unwrap();
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+
+    assert!(matches!(
+        context::set(Locale("en")),
+        Err(BasuError::NoContext)
+    ));
+    Context::new()
+        .with(7u32)
+        .unwrap()
+        .scope(|| orders.publish("order", &event))
+        .unwrap();
+    mail.publish("mail", &event).unwrap();
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec![(Some(Locale("fr")), Some(7)), (None, None)]
+    );
+}
+
+#[test]
+fn replay_delivers_history_before_live_events() {
+    let eventbus = EventBus::<Data>::new()
+        .with_history(2)
+        .with_topic_history("audit", 0);
+    let event = |message: &str| {
+        Event::new(Data {
+            message: message.to_owned(),
+        })
+    };
+    let count = Arc::new(AtomicUsize::new(0));
+    for event_type in ["chat", "audit"] {
+        eventbus
+            .subscribe(event_type, Box::new(Counting(count.clone())))
+            .unwrap();
+    }
+    for message in ["1", "2", "3"] {
+        let _ = eventbus.publish("chat", &event(message));
+    }
+    let _ = eventbus.publish("audit", &event("1"));
+    assert_eq!(eventbus.history("chat").len(), 2);
+    assert!(eventbus.history("audit").is_empty());
+
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    eventbus
+        .subscribe_with_replay("chat", 5, Box::new(Messages(messages.clone())))
+        .unwrap();
+    let last = Arc::new(Mutex::new(Vec::new()));
+    eventbus
+        .subscribe_with_replay("chat", 1, Box::new(Messages(last.clone())))
+        .unwrap();
+    eventbus.publish("chat", &event("4")).unwrap();
+    assert_eq!(*messages.lock().unwrap(), vec!["2", "3", "4"]);
+    assert_eq!(*last.lock().unwrap(), vec!["3", "4"]);
+
+    eventbus.clear_history("chat");
+    let late = Arc::new(Mutex::new(Vec::new()));
+    eventbus
+        .subscribe_with_replay("chat", 5, Box::new(Messages(late.clone())))
+        .unwrap();
+    assert!(late.lock().unwrap().is_empty());
+}
+
+#[test]
+fn owner_is_reported_with_failures() {
+    let eventbus = EventBus::<Data>::new();
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let handler_id = eventbus
+        .subscribe_owned(
+            "order",
+            "team-payments",
+            Box::new(Failing {
+                failures: 1,
+                calls: Arc::new(AtomicUsize::new(0)),
+            }),
+        )
+        .unwrap();
+    eventbus.subscribe("order", Box::new(HandlerA)).unwrap();
+    assert_eq!(
+        eventbus.handler_owner(&handler_id).as_deref(),
+        Some("team-payments")
+    );
+
+    let report = eventbus.publish_report("order", &event).unwrap();
+    assert!(matches!(
+        &report.failed[..],
+        [HandlerFailure { owner: Some(owner), .. }] if owner == "team-payments"
+    ));
+
+    eventbus.unsubscribe("order", &handler_id).unwrap();
+    assert_eq!(eventbus.handler_owner(&handler_id), None);
+}
+
+#[test]
+fn retry_backs_off_exponentially() {
+    let policy = RetryPolicy::exponential(4, Duration::from_millis(10))
+        .with_max_backoff(Duration::from_millis(25));
+    assert_eq!(policy.delay(1), Duration::from_millis(10));
+    assert_eq!(policy.delay(2), Duration::from_millis(20));
+    assert_eq!(policy.delay(3), Duration::from_millis(25));
+    let jittered = policy.with_jitter(Duration::from_millis(5)).delay(1);
+    assert!(jittered >= Duration::from_millis(10) && jittered <= Duration::from_millis(15));
+
+    let eventbus = EventBus::new();
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let calls = Arc::new(AtomicUsize::new(0));
+    let handler: Handler<Data> = Box::new(Failing {
+        failures: 2,
+        calls: calls.clone(),
+    });
+    let handler = handler.with_retry(
+        RetryPolicy::exponential(3, Duration::from_millis(1)).with_jitter(Duration::from_millis(1)),
+    );
+    eventbus.subscribe("retry", handler).unwrap();
+
+    eventbus.publish("retry", &event).unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn validate_reports_misconfigurations() {
+    let eventbus = EventBus::<Data>::new()
+        .with_declared_topics(["order.created", "order.paid"])
+        .with_topic_slow_handler_threshold("ordr.created", Duration::from_millis(100));
+    eventbus
+        .subscribe("order.created", Box::new(HandlerA))
+        .unwrap();
+    eventbus.subscribe("audit", Box::new(HandlerA)).unwrap();
+
+    let report = eventbus.validate().unwrap();
+    assert_eq!(
+        report.issues,
+        vec![
+            ValidationIssue::NoSubscribers {
+                event_type: "order.paid".to_owned(),
+            },
+            ValidationIssue::Undeclared {
+                event_type: "audit".to_owned(),
+            },
+            ValidationIssue::UnknownTopicSetting {
+                event_type: "ordr.created".to_owned(),
+                setting: "slow_handler_threshold",
+            },
+            ValidationIssue::SlowThresholdWithoutHook,
+        ]
+    );
+
+    eventbus.subscribe("order.*", Box::new(HandlerA)).unwrap();
+    eventbus
+        .unsubscribe_where(|event_type, _info| event_type == "audit")
+        .unwrap();
+    assert_eq!(eventbus.validate().unwrap().issues.len(), 2);
+    assert!(EventBus::<Data>::new().validate().unwrap().is_ok());
+}
+
+#[test]
+fn circuit_breaker_skips_failing_handlers() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let eventbus = EventBus::new()
+        .with_circuit_breaker(CircuitBreaker::new(2, Duration::from_millis(50)))
+        .with_circuit_hook({
+            let events = events.clone();
+            move |event| events.lock().unwrap().push(event.clone())
+        });
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let calls = Arc::new(AtomicUsize::new(0));
+    let handler_id = eventbus
+        .subscribe(
+            "flaky",
+            Box::new(Failing {
+                failures: 3,
+                calls: calls.clone(),
+            }),
+        )
+        .unwrap();
+
+    let _ = eventbus.publish("flaky", &event);
+    let _ = eventbus.publish("flaky", &event);
+    assert!(eventbus.is_circuit_open(&handler_id));
+    eventbus.publish("flaky", &event).unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    thread::sleep(Duration::from_millis(60));
+    let _ = eventbus.publish("flaky", &event);
+    thread::sleep(Duration::from_millis(60));
+    eventbus.publish("flaky", &event).unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 4);
+    assert!(!eventbus.is_circuit_open(&handler_id));
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![
+            CircuitEvent::Opened {
+                handler_id: handler_id.clone(),
+                failures: 2,
+            },
+            CircuitEvent::Opened {
+                handler_id: handler_id.clone(),
+                failures: 3,
+            },
+            CircuitEvent::Closed { handler_id },
+        ]
+    );
+}
+
+static STATIC_BUS: LazyEventBus<Data> = EventBus::const_new();
+
+fn history_bus() -> EventBus<Data> {
+    EventBus::new().with_history(1)
+}
+
+static STATIC_HISTORY_BUS: LazyEventBus<Data> = LazyEventBus::with_init(history_bus);
+
+#[test]
+fn static_bus_is_created_on_first_access() {
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let count = Arc::new(AtomicUsize::new(0));
+    STATIC_BUS
+        .subscribe("static", Box::new(Counting(count.clone())))
+        .unwrap();
+    STATIC_BUS.publish("static", &event).unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    assert!(std::ptr::eq(STATIC_BUS.get(), &*STATIC_BUS));
+
+    STATIC_HISTORY_BUS
+        .subscribe("static", Box::new(Counting(count.clone())))
+        .unwrap();
+    let _ = STATIC_HISTORY_BUS.publish("static", &event);
+    assert_eq!(STATIC_HISTORY_BUS.history("static").len(), 1);
+}
+
+#[test]
+fn error_policy() {
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let eventbus = EventBus::new().with_error_policy(ErrorPolicy::FailFast);
+    let calls = Arc::new(AtomicUsize::new(0));
+    let count = Arc::new(AtomicUsize::new(0));
+    let failing_id = eventbus
+        .subscribe_with_priority(
+            "policy",
+            DEFAULT_PRIORITY + 1,
+            Box::new(Failing {
+                failures: usize::MAX,
+                calls: calls.clone(),
+            }),
+        )
+        .unwrap();
+    eventbus
+        .subscribe("policy", Box::new(Counting(count.clone())))
+        .unwrap();
+
+    let result = eventbus.publish_inline("policy", &event);
+    assert!(
+        matches!(result, Err(PublishError::HandlerFailed(failure)) if failure.handler_id == failing_id)
+    );
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(count.load(Ordering::SeqCst), 0);
+
+    eventbus
+        .reconfigure(|config| config.set_error_policy(ErrorPolicy::Ignore))
+        .unwrap();
+    eventbus.publish("policy", &event).unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    assert_eq!(eventbus.config().error_policy(), ErrorPolicy::Ignore);
+}
+
+fn record_message(
+    extract::Data(data): extract::Data<Data>,
+    State(seen): State<Arc<Mutex<Vec<String>>>>,
+    Ctx(context): Ctx,
+) -> Result<(), BasuError> {
+    seen.lock()
+        .unwrap()
+        .push(format!("{} {}", data.message, context.len()));
+    Ok(())
+}
+
+#[test]
+fn extract_handler() {
+    let eventbus = EventBus::new();
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    eventbus
+        .subscribe("extract", extract_fn(record_message))
+        .unwrap();
+
+    let result = eventbus.publish("extract", &event);
+    assert!(matches!(
+        result,
+        Err(PublishError::PartialFailure { failed, .. }) if matches!(failed[..], [HandlerFailure { error: BasuError::MissingState(_), .. }])
+    ));
+
+    let seen = Arc::new(Mutex::new(Vec::<String>::new()));
+    Context::new()
+        .with(seen.clone())
+        .unwrap()
+        .scope(|| eventbus.publish("extract", &event))
+        .unwrap();
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec!["{data from event} 1".to_owned()]
+    );
+}
+
+#[test]
+fn report_skipped_handlers() {
+    let eventbus =
+        EventBus::new().with_circuit_breaker(CircuitBreaker::new(1, Duration::from_secs(60)));
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let calls = Arc::new(AtomicUsize::new(0));
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe(
+            "report",
+            Box::new(Failing {
+                failures: usize::MAX,
+                calls: calls.clone(),
+            }),
+        )
+        .unwrap();
+    eventbus
+        .subscribe("report", Box::new(Counting(count.clone())))
+        .unwrap();
+
+    let report = eventbus.publish_report("report", &event).unwrap();
+    assert_eq!(
+        (report.delivered, report.failed.len(), report.skipped),
+        (1, 1, 0)
+    );
+
+    let report = eventbus.publish_report("report", &event).unwrap();
+    assert_eq!(
+        (report.delivered, report.failed.len(), report.skipped),
+        (1, 0, 1)
+    );
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn circuit_skips_are_not_succeeded() {
+    let eventbus =
+        EventBus::new().with_circuit_breaker(CircuitBreaker::new(1, Duration::from_secs(60)));
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let calls = Arc::new(AtomicUsize::new(0));
+    let count = Arc::new(AtomicUsize::new(0));
+    eventbus
+        .subscribe(
+            "outage",
+            Box::new(Failing {
+                failures: usize::MAX,
+                calls: calls.clone(),
+            }),
+        )
+        .unwrap();
+    eventbus
+        .subscribe("outage", Box::new(Counting(count.clone())))
+        .unwrap();
+    let _ = eventbus.publish("outage", &event);
+
+    let failing_id = eventbus
+        .subscribe(
+            "outage",
+            Box::new(Failing {
+                failures: usize::MAX,
+                calls: calls.clone(),
+            }),
+        )
+        .unwrap();
+    let result = eventbus.publish("outage", &event);
+    assert!(matches!(
+        result,
+        Err(PublishError::PartialFailure { succeeded: 1, failed }) if failed.len() == 1 && failed[0].handler_id == failing_id
+    ));
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn fail_fast_counts_skipped_handlers() {
+    let eventbus = EventBus::new().with_error_policy(ErrorPolicy::FailFast);
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    let calls = Arc::new(AtomicUsize::new(0));
+    let count = Arc::new(AtomicUsize::new(0));
+    let failing_id = eventbus
+        .subscribe_with_priority(
+            "abort",
+            DEFAULT_PRIORITY + 1,
+            Box::new(Failing {
+                failures: usize::MAX,
+                calls: calls.clone(),
+            }),
+        )
+        .unwrap();
+    for _ in 0..2 {
+        eventbus
+            .subscribe("abort", Box::new(Counting(count.clone())))
+            .unwrap();
+    }
+
+    let report = eventbus.publish_report("abort", &event).unwrap();
+    assert_eq!(
+        (report.delivered, report.failed.len(), report.skipped),
+        (0, 1, 2)
+    );
+    assert_eq!(report.failed[0].handler_id, failing_id);
+    assert_eq!(count.load(Ordering::SeqCst), 0);
+
+    eventbus
+        .reconfigure(|config| config.set_error_policy(ErrorPolicy::CollectAll))
+        .unwrap();
+    let report = eventbus.publish_report("abort", &event).unwrap();
+    assert_eq!(
+        (report.delivered, report.failed.len(), report.skipped),
+        (2, 1, 0)
+    );
+}
+
+#[test]
+fn request_publishes_are_replayed() {
+    let eventbus = EventBus::new().with_history(5);
+    let event = |message: &str| {
+        Event::new(Data {
+            message: message.to_owned(),
+        })
+    };
+    eventbus
+        .subscribe_responder("quote", Box::new(Length(Some(0))))
+        .unwrap();
+    eventbus.publish("quote", &event("1")).unwrap();
+    let replies: Vec<usize> = eventbus.publish_request_all("quote", &event("22")).unwrap();
+    assert_eq!(replies, vec![2]);
+    let quotes: Gathered<usize> = eventbus
+        .publish_gather("quote", &event("333"), Duration::from_secs(1))
+        .unwrap();
+    assert_eq!(quotes.replies, vec![3]);
+
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    eventbus
+        .subscribe_with_replay("quote", 5, Box::new(Messages(messages.clone())))
+        .unwrap();
+    assert_eq!(*messages.lock().unwrap(), vec!["1", "22", "333"]);
+}
+
+#[test]
+fn reports_carry_bus_name() {
+    let slow = Arc::new(Mutex::new(Vec::new()));
+    let eventbus = EventBus::named("payments")
+        .with_label("region", "eu")
+        .with_slow_handler_threshold(Duration::ZERO)
+        .with_slow_handler_hook({
+            let slow = slow.clone();
+            move |report| {
+                slow.lock()
+                    .unwrap()
+                    .push((report.bus.clone(), report.labels.clone()))
+            }
+        });
+    let event = Event::new(Data {
+        message: "{data from event}".to_owned(),
+    });
+    eventbus
+        .subscribe(
+            "named",
+            Box::new(Failing {
+                failures: usize::MAX,
+                calls: Arc::new(AtomicUsize::new(0)),
+            }),
+        )
+        .unwrap();
+
+    let report = eventbus.publish_report("named", &event).unwrap();
+    assert_eq!(report.failed[0].bus.as_deref(), Some("payments"));
+    assert_eq!(report.failed[0].labels["region"], "eu");
+    assert_eq!(
+        *slow.lock().unwrap(),
+        vec![(Some("payments".to_owned()), eventbus.labels().clone())]
+    );
+}
+
+#[test]
+fn failed_publish_keeps_previous_retained_event() {
+    let eventbus = EventBus::<Data>::new();
+    let event = |message: &str| {
+        Event::new(Data {
+            message: message.to_owned(),
+        })
+    };
+    eventbus.publish_retained("config", event("1")).unwrap();
+    // fails for the retained event on subscribe and for the next publish
+    eventbus
+        .subscribe(
+            "config",
+            Box::new(Failing {
+                failures: 2,
+                calls: Arc::new(AtomicUsize::new(0)),
+            }),
+        )
+        .unwrap();
+    assert!(eventbus.publish_retained("config", event("2")).is_err());
+
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    eventbus
+        .subscribe("config", Box::new(Messages(messages.clone())))
+        .unwrap();
+    assert_eq!(*messages.lock().unwrap(), vec!["1"]);
+
+    eventbus.publish_retained("config", event("3")).unwrap();
+    let late = Arc::new(Mutex::new(Vec::new()));
+    eventbus
+        .subscribe("config", Box::new(Messages(late.clone())))
+        .unwrap();
+    assert_eq!(*late.lock().unwrap(), vec!["3"]);
+}
+
+#[test]
+fn zero_window_period_is_rejected() {
+    let eventbus = Arc::new(EventBus::<Data>::new());
+    let result = eventbus.window(
+        "sample",
+        Window::Sliding {
+            size: Duration::from_secs(1),
+            slide: Duration::ZERO,
+        },
+        |events| events[0].data.clone(),
+        "joined",
+    );
+    assert!(matches!(result, Err(BasuError::InvalidConfig(_))));
+    assert!(!eventbus.has_subscribers("sample").unwrap());
+}
+
+#[test]
+fn unrelated_builders_keep_dispatch_pool() {
+    let pool = |eventbus: &EventBus<Data>| match eventbus.dispatcher("sample") {
+        Dispatcher::Pool(pool, _) => pool,
+        _ => panic!("expected a dedicated pool"),
+    };
+    let eventbus = EventBus::<Data>::new().with_dispatch_strategy(DispatchStrategy::Pool(1));
+    let before = pool(&eventbus);
+
+    let eventbus = eventbus
+        .with_slow_handler_threshold(Duration::from_secs(1))
+        .with_error_policy(ErrorPolicy::Ignore);
+    assert!(Arc::ptr_eq(&before, &pool(&eventbus)));
+}
+
+#[test]
+fn publishes_to_unknown_event_types_are_not_recorded() {
+    let eventbus = EventBus::new().with_history(5);
+    let event = Event::new(Data {
+        message: "lost".to_owned(),
+    });
+    let result = eventbus.publish("missing", &event);
+    assert!(matches!(result, Err(PublishError::EventTypeNotFound)));
+    assert!(eventbus.history("missing").is_empty());
+}
@@ -0,0 +1,31 @@
+use super::ValidationReport;
+use crate::EventBus;
+
+use std::collections::BTreeMap;
+
+impl<T> EventBus<T> {
+    /// Check the bus for misconfigurations before the first publish, e.g. at boot:
+    /// declared event types without handlers, handlers of undeclared event types, settings
+    /// of unknown event types and slow handler settings without effect.
+    ///
+    /// ```no_run
+    /// let report = event_bus.validate().await;
+    /// if !report.is_ok() {
+    ///     panic!("invalid event bus: {:?}", report.issues);
+    /// }
+    /// ```
+    ///
+    /// **Note:** Event data isn't checked against schemas, the bus has none.
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn validate(&self) -> ValidationReport {
+        let event_handler_map = self.event_handler_map.lock().await;
+
+        let mut handler_counts = BTreeMap::new();
+        for (event_type, handler_map) in event_handler_map.iter() {
+            handler_counts.insert(event_type.clone(), handler_map.lock().await.len());
+        }
+        drop(event_handler_map);
+
+        self.check(&handler_counts)
+    }
+}
@@ -0,0 +1,35 @@
+use super::ValidationReport;
+use crate::{error::BasuError, EventBus};
+
+use std::collections::BTreeMap;
+
+impl<T> EventBus<T> {
+    /// Check the bus for misconfigurations before the first publish, e.g. at boot:
+    /// declared event types without handlers, handlers of undeclared event types, settings
+    /// of unknown event types and slow handler settings without effect.
+    ///
+    /// ```no_run
+    /// let report = event_bus.validate()?;
+    /// if !report.is_ok() {
+    ///     panic!("invalid event bus: {:?}", report.issues);
+    /// }
+    /// ```
+    ///
+    /// **Note:** Event data isn't checked against schemas, the bus has none.
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn validate(&self) -> Result<ValidationReport, BasuError> {
+        let event_handler_map = self
+            .event_handler_map
+            .lock()
+            .map_err(|_| BasuError::MutexPoisoned)?;
+
+        let mut handler_counts = BTreeMap::new();
+        for (event_type, handler_map) in event_handler_map.iter() {
+            let handler_map = handler_map.lock().map_err(|_| BasuError::MutexPoisoned)?;
+            handler_counts.insert(event_type.clone(), handler_map.len());
+        }
+        drop(event_handler_map);
+
+        Ok(self.check(&handler_counts))
+    }
+}
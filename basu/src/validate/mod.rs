@@ -0,0 +1,130 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+
+use crate::{
+    wildcard::{is_pattern, WILDCARD},
+    EventBus,
+};
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Misconfiguration found by `EventBus::validate`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationIssue {
+    /// Declared event type has no handler, wildcard and pattern handlers included.
+    NoSubscribers {
+        /// declared event type
+        event_type: String,
+    },
+    /// Handlers are subscribed to an event type which wasn't declared.
+    Undeclared {
+        /// subscribed event type
+        event_type: String,
+    },
+    /// Setting of an event type which is neither declared nor subscribed, e.g. a typo.
+    UnknownTopicSetting {
+        /// event type of the setting
+        event_type: String,
+        /// name of the setting
+        setting: &'static str,
+    },
+    /// Slow handler threshold is set but no hook is notified of slow handlers.
+    SlowThresholdWithoutHook,
+    /// Slow handler hooks are set but no threshold ever triggers them.
+    SlowHookWithoutThreshold,
+}
+
+/// Report of `EventBus::validate`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    /// misconfigurations found, empty if the bus is valid
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// whether no misconfiguration was found.
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl<T> EventBus<T> {
+    /// Declare the event types the application publishes, so `validate` reports declared
+    /// event types nobody subscribed to and subscriptions to undeclared ones.
+    ///
+    /// ```no_run
+    /// let event_bus = EventBus::<MyEventData>::new()
+    ///     .with_declared_topics(["order.created", "order.paid"]);
+    /// ```
+    pub fn with_declared_topics<'a>(mut self, topics: impl IntoIterator<Item = &'a str>) -> Self {
+        self.declared_topics
+            .extend(topics.into_iter().map(str::to_owned));
+        self
+    }
+
+    /// check the bus against its declared topics and settings, given the number of handlers
+    /// of each entry of the handler map.
+    fn check(&self, handler_counts: &BTreeMap<String, usize>) -> ValidationReport {
+        let subscribed: BTreeSet<&str> = handler_counts
+            .iter()
+            .filter(|(_, count)| **count > 0)
+            .map(|(event_type, _)| event_type.as_str())
+            .collect();
+        let mut issues = Vec::new();
+
+        for event_type in &self.declared_topics {
+            let covered = subscribed.contains(WILDCARD)
+                || subscribed.contains(event_type.as_str())
+                || self
+                    .matching_patterns(event_type)
+                    .iter()
+                    .any(|pattern| subscribed.contains(pattern.as_str()));
+            if !covered {
+                issues.push(ValidationIssue::NoSubscribers {
+                    event_type: event_type.clone(),
+                });
+            }
+        }
+
+        if !self.declared_topics.is_empty() {
+            for event_type in &subscribed {
+                if *event_type != WILDCARD
+                    && !is_pattern(event_type)
+                    && !self.declared_topics.contains(*event_type)
+                {
+                    issues.push(ValidationIssue::Undeclared {
+                        event_type: event_type.to_string(),
+                    });
+                }
+            }
+        }
+
+        let settings = self.settings();
+        let topic_settings = settings.config.topic_settings().chain(
+            self.history_topics()
+                .map(|event_type| (event_type, "history")),
+        );
+        for (event_type, setting) in topic_settings {
+            if !self.declared_topics.contains(event_type)
+                && !subscribed.contains(event_type.as_str())
+            {
+                issues.push(ValidationIssue::UnknownTopicSetting {
+                    event_type: event_type.clone(),
+                    setting,
+                });
+            }
+        }
+
+        let has_threshold = settings.config.has_slow_handler_threshold();
+        if has_threshold && self.slow_handler_hooks.is_empty() {
+            issues.push(ValidationIssue::SlowThresholdWithoutHook);
+        }
+        if !has_threshold && !self.slow_handler_hooks.is_empty() {
+            issues.push(ValidationIssue::SlowHookWithoutThreshold);
+        }
+
+        ValidationReport { issues }
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{event::Event, EventBus};
+
+use std::{
+    any::Any,
+    collections::HashMap,
+    sync::{Arc, PoisonError, RwLock},
+};
+use tokio::sync::watch;
+
+/// Latest event of a watched event type.
+type LatestSender<T> = watch::Sender<Option<Arc<Event<T>>>>;
+type UpdateFn<T> = Box<dyn Fn(&Event<T>) + Send + Sync>;
+
+/// Latest event of a watched event type, type-erased so the bus stays `Sync` for any
+/// event data.
+pub(crate) struct Latest<T> {
+    /// `LatestSender` of the event type
+    sender: Box<dyn Any + Send + Sync>,
+    /// replace the latest event with a copy of a published event
+    update: UpdateFn<T>,
+}
+
+/// Watched event types with their latest event.
+pub(crate) type Watches<T> = RwLock<HashMap<String, Latest<T>>>;
+
+impl<T> EventBus<T> {
+    /// keep a published event as the latest event of its event type, if the type is watched.
+    pub(crate) fn record_latest(&self, event_type: &str, event: &Event<T>) {
+        if let Some(latest) = self
+            .watches
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(event_type)
+        {
+            (latest.update)(event);
+        }
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> EventBus<T> {
+    /// Watch the most recent event of `event_type`, without handling every event.
+    /// The bus keeps the latest event from the first `watch` of an event type on, later
+    /// watchers see it right away, earlier events are `None`.
+    ///
+    /// ```no_run
+    /// let mut price = event_bus.watch("price").await;
+    /// while price.changed().await.is_ok() {
+    ///     let latest = price.borrow_and_update().clone();
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// **Note:** Watching doesn't subscribe a handler, publishing to an event type without
+    /// subscribers still fails while its watchers see the event. Events of `publish_lazy`
+    /// are only seen when they are built, i.e. when the event type has subscribers.
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn watch(&self, event_type: &str) -> watch::Receiver<Option<Arc<Event<T>>>> {
+        let mut watches = self.watches.write().unwrap_or_else(PoisonError::into_inner);
+
+        if let Some(sender) = watches
+            .get(event_type)
+            .and_then(|latest| latest.sender.downcast_ref::<LatestSender<T>>())
+        {
+            return sender.subscribe();
+        }
+
+        let (sender, receiver) = watch::channel(None);
+        let update = {
+            let sender = sender.clone();
+            move |event: &Event<T>| {
+                sender.send_replace(Some(Arc::new(event.clone())));
+            }
+        };
+        watches.insert(
+            event_type.to_owned(),
+            Latest {
+                sender: Box::new(sender),
+                update: Box::new(update),
+            },
+        );
+
+        receiver
+    }
+}
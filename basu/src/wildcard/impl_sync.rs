@@ -0,0 +1,65 @@
+use super::{Subscribers, WILDCARD};
+use crate::{
+    error::BasuError, error::PublishError, EventBus, Handler, HandlerId, HandlerMap, HashMap,
+};
+
+impl<T: Sync> EventBus<T> {
+    /// Subscribe a handler to every event type, e.g. for logging or auditing.
+    /// It receives each published event alongside the handlers of the event type, and
+    /// is unsubscribed with the `WILDCARD` event type.
+    ///
+    /// ```no_run
+    /// let handler_id = event_bus.subscribe_all(Box::new(AuditLog))?;
+    ///
+    /// event_bus.unsubscribe(WILDCARD, &handler_id)?;
+    /// ```
+    ///
+    /// **Note:** While wildcard handlers are subscribed, publishing to an event type
+    /// nobody subscribed to succeeds instead of failing with `EventTypeNotFound`.
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    pub fn subscribe_all(&self, handler: Handler<T>) -> Result<HandlerId, BasuError> {
+        self.subscribe(WILDCARD, handler)
+    }
+
+    /// lock the handlers of the event type, of the patterns matching it and the wildcard
+    /// handlers.
+    pub(crate) fn subscribers<'a>(
+        &self,
+        event_handler_map: &'a HashMap<String, HandlerMap<T>>,
+        event_type: &str,
+    ) -> Result<Subscribers<'a, T>, PublishError> {
+        let mut handler_maps = Vec::new();
+        if let Some(handler_map) = event_handler_map.get(event_type) {
+            handler_maps.push(
+                handler_map
+                    .lock()
+                    .map_err(|_| PublishError::MutexPoisoned)?,
+            );
+        }
+        for pattern in self.matching_patterns(event_type) {
+            if let Some(handler_map) = event_handler_map.get(&pattern) {
+                handler_maps.push(
+                    handler_map
+                        .lock()
+                        .map_err(|_| PublishError::MutexPoisoned)?,
+                );
+            }
+        }
+
+        if event_type != WILDCARD {
+            if let Some(handler_map) = event_handler_map.get(WILDCARD) {
+                let handler_map = handler_map
+                    .lock()
+                    .map_err(|_| PublishError::MutexPoisoned)?;
+                if !handler_map.is_empty() {
+                    handler_maps.push(handler_map);
+                }
+            }
+        }
+
+        if handler_maps.is_empty() {
+            return Err(PublishError::EventTypeNotFound);
+        }
+        Ok(Subscribers { handler_maps })
+    }
+}
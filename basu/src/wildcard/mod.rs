@@ -0,0 +1,85 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+mod pattern;
+
+pub(crate) use pattern::PatternIndex;
+pub use pattern::{is_pattern, MULTI_LEVEL, SEPARATOR, SINGLE_LEVEL};
+
+use crate::{EventBus, Handler, HandlerId};
+
+#[cfg(feature = "sync")]
+use std::sync::MutexGuard;
+use std::{collections::HashMap, sync::PoisonError};
+#[cfg(feature = "async")]
+use tokio::sync::MutexGuard;
+
+/// Event type of the handlers subscribed with `subscribe_all`.
+pub const WILDCARD: &str = "*";
+
+impl<T> EventBus<T> {
+    /// index a new entry of the handler map, if its event type is a pattern.
+    pub(crate) fn index_event_type(&self, event_type: &str) {
+        if is_pattern(event_type) {
+            self.patterns
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .insert(event_type);
+        }
+    }
+
+    /// remove an entry of the handler map from the index.
+    pub(crate) fn unindex_event_type(&self, event_type: &str) {
+        if is_pattern(event_type) {
+            self.patterns
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .remove(event_type);
+        }
+    }
+
+    pub(crate) fn clear_index(&self) {
+        self.patterns
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+
+    /// patterns of the handler map matching the event type, besides the event type itself.
+    pub(crate) fn matching_patterns(&self, event_type: &str) -> Vec<String> {
+        self.patterns
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .matches(event_type)
+            .into_iter()
+            .filter(|pattern| *pattern != event_type)
+            .map(str::to_owned)
+            .collect()
+    }
+}
+
+/// Locked handler maps receiving a published event.
+pub(crate) struct Subscribers<'a, T> {
+    handler_maps: Vec<MutexGuard<'a, HashMap<HandlerId, Handler<T>>>>,
+}
+
+impl<T> Subscribers<'_, T> {
+    pub(crate) fn handlers(&self) -> Vec<(&HandlerId, &Handler<T>)> {
+        self.handler_maps
+            .iter()
+            .flat_map(|handler_map| handler_map.iter())
+            .collect()
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.handler_maps
+            .iter()
+            .map(|handler_map| handler_map.len())
+            .sum()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
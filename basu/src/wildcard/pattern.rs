@@ -0,0 +1,92 @@
+use std::collections::{HashMap, HashSet};
+
+/// Separator of the segments of hierarchical event types.
+pub const SEPARATOR: char = '.';
+/// Pattern segment matching exactly one segment of an event type.
+pub const SINGLE_LEVEL: &str = "*";
+/// Pattern segment matching the remaining segments of an event type, if any.
+pub const MULTI_LEVEL: &str = "#";
+
+/// whether an event type is a pattern, i.e. has a `*` or a `#` segment.
+/// The bare `*` is the `WILDCARD` of `subscribe_all` and isn't a pattern.
+pub fn is_pattern(event_type: &str) -> bool {
+    event_type != super::WILDCARD
+        && event_type
+            .split(SEPARATOR)
+            .any(|segment| segment == SINGLE_LEVEL || segment == MULTI_LEVEL)
+}
+
+/// Trie of the subscribed patterns, keyed by segment.
+#[derive(Debug, Default)]
+pub(crate) struct PatternIndex {
+    children: HashMap<String, PatternIndex>,
+    pattern: Option<String>,
+}
+
+impl PatternIndex {
+    pub(crate) fn insert(&mut self, pattern: &str) {
+        let node = pattern.split(SEPARATOR).fold(self, |node, segment| {
+            node.children.entry(segment.to_owned()).or_default()
+        });
+        node.pattern = Some(pattern.to_owned());
+    }
+
+    pub(crate) fn remove(&mut self, pattern: &str) {
+        let segments: Vec<_> = pattern.split(SEPARATOR).collect();
+        self.remove_segments(&segments);
+    }
+
+    fn remove_segments(&mut self, segments: &[&str]) {
+        match segments.split_first() {
+            None => self.pattern = None,
+            Some((segment, rest)) => {
+                if let Some(child) = self.children.get_mut(*segment) {
+                    child.remove_segments(rest);
+                    if child.pattern.is_none() && child.children.is_empty() {
+                        self.children.remove(*segment);
+                    }
+                }
+            }
+        }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// patterns matching the event type.
+    pub(crate) fn matches(&self, event_type: &str) -> HashSet<&str> {
+        let segments: Vec<_> = event_type.split(SEPARATOR).collect();
+        let mut matches = HashSet::new();
+        self.collect(&segments, &mut matches);
+        matches
+    }
+
+    fn collect<'a>(&'a self, segments: &[&str], matches: &mut HashSet<&'a str>) {
+        if let Some(pattern) = self
+            .children
+            .get(MULTI_LEVEL)
+            .and_then(|child| child.pattern.as_deref())
+        {
+            matches.insert(pattern);
+        }
+
+        match segments.split_first() {
+            None => {
+                if let Some(pattern) = &self.pattern {
+                    matches.insert(pattern);
+                }
+            }
+            Some((segment, rest)) => {
+                if let Some(child) = self.children.get(*segment) {
+                    child.collect(rest, matches);
+                }
+                if *segment != SINGLE_LEVEL {
+                    if let Some(child) = self.children.get(SINGLE_LEVEL) {
+                        child.collect(rest, matches);
+                    }
+                }
+            }
+        }
+    }
+}
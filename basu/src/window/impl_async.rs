@@ -0,0 +1,73 @@
+use super::{Window, WindowState};
+use crate::{async_trait, error::BasuError, event::Event, EventBus, Handle, HandlerId};
+
+use std::sync::{Arc, Weak};
+use tokio::time::{self, Instant};
+
+/// Handler which buffers the events of a windowed topic.
+struct Collect<T>(Arc<WindowState<T>>);
+
+#[async_trait]
+impl<T: Clone + Send + Sync + 'static> Handle<T> for Collect<T> {
+    async fn handle(&self, event: &Event<T>) -> Result<(), BasuError> {
+        self.0.push(event);
+
+        Ok(())
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> EventBus<T> {
+    /// Subscribe a window over an event type, every window closing with events is reduced
+    /// to one event published to `output_type`.
+    /// Unsubscribing the returned handler id stops the window, publish errors of the
+    /// reduced events are dropped.
+    ///
+    /// ```no_run
+    /// let event_bus = Arc::new(EventBus::<Sample>::new());
+    /// event_bus
+    ///     .window(
+    ///         "metrics.sample",
+    ///         Window::Tumbling(Duration::from_secs(60)),
+    ///         |samples| Sample::mean(samples),
+    ///         "metrics.minute",
+    ///     )
+    ///     .await?;
+    /// ```
+    ///
+    /// It fails with `BasuError::InvalidConfig` when the length of a tumbling window or the
+    /// slide of a sliding window is zero.
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn window(
+        self: &Arc<Self>,
+        event_type: &str,
+        window: Window,
+        reducer: impl Fn(&[Event<T>]) -> T + Send + Sync + 'static,
+        output_type: &str,
+    ) -> Result<HandlerId, BasuError> {
+        let period = window.period()?;
+        let state = Arc::new(WindowState::new(window));
+        let weak_state = Arc::downgrade(&state);
+        let event_bus: Weak<Self> = Arc::downgrade(self);
+        let output_type = output_type.to_owned();
+
+        tokio::spawn(async move {
+            let mut interval = time::interval_at(Instant::now() + period, period);
+            loop {
+                interval.tick().await;
+                let (Some(state), Some(event_bus)) = (weak_state.upgrade(), event_bus.upgrade())
+                else {
+                    break;
+                };
+
+                let events = state.close();
+                drop(state);
+                if !events.is_empty() {
+                    let event = Event::new(reducer(&events));
+                    let _ = event_bus.publish(&output_type, &event).await;
+                }
+            }
+        });
+
+        Ok(self.subscribe(event_type, Box::new(Collect(state))).await)
+    }
+}
@@ -0,0 +1,82 @@
+#[cfg(feature = "async")]
+mod impl_async;
+#[cfg(feature = "sync")]
+mod impl_sync;
+
+use crate::{error::BasuError, event::Event};
+
+use std::{
+    collections::VecDeque,
+    sync::{Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+/// Time window over the events of a topic.
+#[derive(Debug, Clone, Copy)]
+pub enum Window {
+    /// consecutive, non-overlapping windows of the given length
+    Tumbling(Duration),
+    /// windows of `size` emitted every `slide`, overlapping when `slide` is shorter than `size`
+    Sliding {
+        /// length of a window
+        size: Duration,
+        /// interval between emitted windows
+        slide: Duration,
+    },
+}
+
+impl Window {
+    /// return the interval between emitted windows, which must not be zero.
+    fn period(&self) -> Result<Duration, BasuError> {
+        let period = match self {
+            Window::Tumbling(size) => *size,
+            Window::Sliding { slide, .. } => *slide,
+        };
+        if period.is_zero() {
+            return Err(BasuError::InvalidConfig(
+                "window period must not be zero".to_owned(),
+            ));
+        }
+        Ok(period)
+    }
+}
+
+/// Events buffered for the current window.
+struct WindowState<T> {
+    window: Window,
+    events: Mutex<VecDeque<(Instant, Event<T>)>>,
+}
+
+impl<T: Clone> WindowState<T> {
+    fn new(window: Window) -> Self {
+        Self {
+            window,
+            events: Default::default(),
+        }
+    }
+
+    fn push(&self, event: &Event<T>) {
+        self.events
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push_back((Instant::now(), event.clone()));
+    }
+
+    /// take the events of the window closing now.
+    fn close(&self) -> Vec<Event<T>> {
+        let mut events = self.events.lock().unwrap_or_else(PoisonError::into_inner);
+        match self.window {
+            Window::Tumbling(_) => events.drain(..).map(|(_, event)| event).collect(),
+            Window::Sliding { size, .. } => {
+                let now = Instant::now();
+                while events
+                    .front()
+                    .is_some_and(|(at, _)| now.duration_since(*at) > size)
+                {
+                    events.pop_front();
+                }
+                events.iter().map(|(_, event)| event.clone()).collect()
+            }
+        }
+    }
+}